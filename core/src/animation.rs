@@ -0,0 +1,550 @@
+//! Animate values over time.
+//!
+//! An [`Animation`] moves a value towards a target following a [`Curve`],
+//! which can be a duration with an [`Easing`] or a [`Spring`]. Widgets can
+//! keep an [`Animation`] in their state, advance it when they receive a
+//! [`RedrawRequested`] event, and ask for the next frame with
+//! [`Animation::request_redraw`].
+//!
+//! [`RedrawRequested`]: crate::window::Event::RedrawRequested
+use crate::time::{Duration, Instant};
+use crate::window::RedrawRequest;
+use crate::{
+    Background, BorderRadius, Color, Padding, Point, Shell, Size, Vector,
+};
+
+/// The interval between the frames of a running [`Animation`].
+pub const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// A value that moves towards a target over time.
+///
+/// # Example
+/// ```
+/// use iced_core::animation::{Animation, Easing};
+/// use iced_core::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+///
+/// let mut opacity = Animation::new(0.0)
+///     .curve(Duration::from_millis(100))
+///     .easing(Easing::Linear);
+///
+/// opacity.go(1.0, start);
+///
+/// assert_eq!(opacity.value(start + Duration::from_millis(50)), 0.5);
+/// assert_eq!(opacity.value(start + Duration::from_millis(200)), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    delay: Duration,
+    curve: Curve,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            delay: Duration::ZERO,
+            curve: Curve::default(),
+        }
+    }
+
+    /// Sets the [`Curve`] of the [`Animation`].
+    pub fn curve(mut self, curve: impl Into<Curve>) -> Self {
+        self.curve = curve.into();
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Animation`], if its [`Curve`] has a
+    /// duration.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        if let Curve::Timed { duration, .. } = self.curve {
+            self.curve = Curve::Timed { duration, easing };
+        }
+
+        self
+    }
+
+    /// Sets the time the [`Animation`] waits before moving towards a new
+    /// target.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Moves the [`Animation`] towards a new target, starting at the given
+    /// [`Instant`] from its current value.
+    ///
+    /// Nothing happens if the target does not change.
+    pub fn go(&mut self, to: T, now: Instant)
+    where
+        T: PartialEq,
+    {
+        if self.to == to {
+            return;
+        }
+
+        self.from = self.value(now);
+        self.to = to;
+        self.started_at = Some(now);
+    }
+
+    /// Moves the [`Animation`] to the given value right away.
+    pub fn snap(&mut self, value: T) {
+        *self = Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            ..*self
+        };
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        if self.started_at.is_none() {
+            return self.to.clone();
+        }
+
+        self.from.interpolate(&self.to, self.progress(now))
+    }
+
+    /// Returns the target of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns the progress of the [`Animation`] towards its target at the
+    /// given [`Instant`].
+    ///
+    /// The progress goes from `0.0` to `1.0`, but a [`Spring`] may overshoot
+    /// it.
+    pub fn progress(&self, now: Instant) -> f32 {
+        match self.started_at {
+            Some(started_at) => self.curve.progress(
+                now.saturating_duration_since(started_at + self.delay),
+            ),
+            None => 1.0,
+        }
+    }
+
+    /// Returns whether the [`Animation`] is still moving towards its target
+    /// at the given [`Instant`].
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.started_at.is_some_and(|started_at| {
+            now < started_at + self.delay + self.curve.duration()
+        })
+    }
+
+    /// Returns the [`RedrawRequest`] needed to display the next frame of the
+    /// [`Animation`], if it is still running.
+    pub fn redraw_request(&self, now: Instant) -> Option<RedrawRequest> {
+        let start = self.started_at? + self.delay;
+
+        if now < start {
+            Some(RedrawRequest::At(start))
+        } else if self.is_animating(now) {
+            Some(RedrawRequest::At(now + FRAME_INTERVAL))
+        } else {
+            None
+        }
+    }
+
+    /// Requests the redraw needed to display the next frame of the
+    /// [`Animation`], if it is still running.
+    pub fn request_redraw<Message>(
+        &self,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(request) = self.redraw_request(now) {
+            shell.request_redraw(request);
+        }
+    }
+}
+
+/// How an [`Animation`] progresses over time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    /// The [`Animation`] lasts the given duration, following an [`Easing`].
+    Timed {
+        /// The duration of the [`Animation`].
+        duration: Duration,
+        /// The [`Easing`] of the [`Animation`].
+        easing: Easing,
+    },
+    /// The [`Animation`] follows the motion of a [`Spring`].
+    Spring(Spring),
+}
+
+impl Curve {
+    /// Returns the duration of the [`Curve`].
+    pub fn duration(&self) -> Duration {
+        match self {
+            Curve::Timed { duration, .. } => *duration,
+            Curve::Spring(spring) => spring.settling_time(),
+        }
+    }
+
+    /// Returns the progress of the [`Curve`] after the given time.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self {
+            Curve::Timed { duration, easing } => {
+                if elapsed >= *duration {
+                    1.0
+                } else {
+                    easing.apply(elapsed.as_secs_f32() / duration.as_secs_f32())
+                }
+            }
+            Curve::Spring(spring) => {
+                if elapsed >= spring.settling_time() {
+                    1.0
+                } else {
+                    spring.position(elapsed.as_secs_f32())
+                }
+            }
+        }
+    }
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Self::from(Duration::from_millis(150))
+    }
+}
+
+impl From<Duration> for Curve {
+    fn from(duration: Duration) -> Self {
+        Curve::Timed {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+}
+
+impl From<Spring> for Curve {
+    fn from(spring: Spring) -> Self {
+        Curve::Spring(spring)
+    }
+}
+
+/// The rate of change of an [`Animation`] over its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    #[default]
+    EaseOut,
+    /// Starts slow, accelerates and decelerates at the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the given linear progress, between `0.0`
+    /// and `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A damped spring that pulls an [`Animation`] towards its target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the [`Spring`]. Stiffer springs move faster.
+    pub stiffness: f32,
+    /// The damping of the [`Spring`]. Less damped springs oscillate more.
+    pub damping: f32,
+    /// The mass attached to the [`Spring`].
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A [`Spring`] damped just enough to reach its target quickly without
+    /// visibly overshooting it.
+    pub const SMOOTH: Self = Self::new(170.0, 26.0);
+
+    /// An underdamped [`Spring`], which overshoots its target and bounces
+    /// back a few times.
+    pub const BOUNCY: Self = Self::new(180.0, 12.0);
+
+    /// The distance from the target below which a [`Spring`] is at rest, as
+    /// a fraction of the whole motion.
+    const REST: f32 = 0.001;
+
+    /// Creates a new [`Spring`] with the given stiffness and damping, and a
+    /// mass of `1.0`.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    fn frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the position of a [`Spring`] released at `0.0` towards
+    /// `1.0`, after the given time in seconds.
+    fn position(&self, t: f32) -> f32 {
+        let omega = self.frequency();
+        let zeta = self.damping_ratio();
+
+        if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+
+            1.0 - (-zeta * omega * t).exp()
+                * ((omega_d * t).cos()
+                    + zeta * omega / omega_d * (omega_d * t).sin())
+        } else if zeta == 1.0 {
+            1.0 - (-omega * t).exp() * (1.0 + omega * t)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            1.0 - (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+        }
+    }
+
+    /// Returns the time the [`Spring`] takes to come to rest.
+    fn settling_time(&self) -> Duration {
+        let omega = self.frequency();
+        let zeta = self.damping_ratio();
+
+        if !omega.is_finite() || omega <= 0.0 || zeta <= 0.0 {
+            return Duration::ZERO;
+        }
+
+        // An upper bound of the distance to the target that only decreases
+        let distance = |t: f32| {
+            if zeta <= 1.0 {
+                let decay = zeta * omega * t;
+
+                (-decay).exp() * (1.0 + decay)
+            } else {
+                1.0 - self.position(t)
+            }
+        };
+
+        let mut low = 0.0;
+        let mut high = 1.0 / omega;
+
+        while distance(high) > Self::REST {
+            low = high;
+            high *= 2.0;
+        }
+
+        for _ in 0..20 {
+            let middle = (low + high) / 2.0;
+
+            if distance(middle) > Self::REST {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        Duration::from_secs_f32(high)
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::SMOOTH
+    }
+}
+
+/// A value that can be interpolated by an [`Animation`].
+pub trait Interpolate {
+    /// Returns the value between `self` and `other` at the given progress,
+    /// where `0.0` is `self` and `1.0` is `other`.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let mix = |a: f32, b: f32| a.interpolate(&b, t).clamp(0.0, 1.0);
+
+        Color {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+impl Interpolate for Padding {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Padding {
+            top: self.top.interpolate(&other.top, t),
+            right: self.right.interpolate(&other.right, t),
+            bottom: self.bottom.interpolate(&other.bottom, t),
+            left: self.left.interpolate(&other.left, t),
+        }
+    }
+}
+
+impl Interpolate for BorderRadius {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let from: [f32; 4] = (*self).into();
+        let to: [f32; 4] = (*other).into();
+
+        BorderRadius::from([0, 1, 2, 3].map(|i| from[i].interpolate(&to[i], t)))
+    }
+}
+
+impl Interpolate for Background {
+    /// Interpolates two colors; any other [`Background`] changes halfway.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Background::Color(from), Background::Color(to)) => {
+                Background::Color(from.interpolate(to, t))
+            }
+            _ if t < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl Interpolate for Option<Background> {
+    /// Interpolates two backgrounds, fading a missing one as a transparent
+    /// version of the other.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let transparent = |background: &Background| match background {
+            Background::Color(color) => {
+                Background::Color(Color { a: 0.0, ..*color })
+            }
+            Background::Gradient(_) => *background,
+        };
+
+        match (self, other) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, t)),
+            (Some(from), None) => Some(from.interpolate(&transparent(from), t)),
+            (None, Some(to)) => Some(transparent(to).interpolate(to, t)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Interpolate for Option<Color> {
+    /// Interpolates two colors, fading a missing one as a transparent
+    /// version of the other.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let transparent = |color: &Color| Color { a: 0.0, ..*color };
+
+        match (self, other) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, t)),
+            (Some(from), None) => Some(from.interpolate(&transparent(from), t)),
+            (None, Some(to)) => Some(transparent(to).interpolate(to, t)),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animation_retargets_from_current_value() {
+        let start = Instant::now();
+        let half = Duration::from_millis(50);
+
+        let mut animation = Animation::new(0.0)
+            .curve(Duration::from_millis(100))
+            .easing(Easing::Linear);
+
+        animation.go(10.0, start);
+        assert!(animation.is_animating(start + half));
+
+        animation.go(0.0, start + half);
+        assert_eq!(animation.value(start + half), 5.0);
+        assert_eq!(animation.value(start + half * 2), 2.5);
+        assert_eq!(animation.value(start + half * 3), 0.0);
+        assert!(!animation.is_animating(start + half * 3));
+        assert_eq!(animation.redraw_request(start + half * 3), None);
+    }
+
+    #[test]
+    fn springs_come_to_rest() {
+        for spring in [Spring::SMOOTH, Spring::BOUNCY, Spring::new(300.0, 40.0)]
+        {
+            let settling_time = spring.settling_time();
+            let position = spring.position(settling_time.as_secs_f32());
+
+            assert!(settling_time > Duration::ZERO);
+            assert!(settling_time < Duration::from_secs(2));
+            assert!((1.0 - position).abs() <= Spring::REST * 1.01);
+        }
+
+        let overshoot = (1..100)
+            .map(|i| Spring::BOUNCY.position(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+
+        assert!(overshoot > 1.0);
+    }
+}
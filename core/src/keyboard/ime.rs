@@ -0,0 +1,57 @@
+use crate::Rectangle;
+
+/// An input method editor (IME) event.
+///
+/// Input methods are used to compose text that cannot be typed directly with
+/// a keyboard, like Chinese, Japanese, or Korean characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ime {
+    /// The input method was enabled.
+    Enabled,
+
+    /// The text being composed has changed.
+    ///
+    /// An empty `content` means the composition was cleared.
+    Preedit {
+        /// The text being composed.
+        content: String,
+
+        /// The byte range of the cursor inside the `content`, if any.
+        ///
+        /// When both ends of the range are equal, the cursor is a caret.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composition was finished and the given text should be inserted.
+    Commit(String),
+
+    /// The input method was disabled.
+    Disabled,
+}
+
+/// The state of the input method requested by a widget.
+///
+/// The input method of a window is disabled, unless a widget requests it
+/// every time a [`RedrawRequested`] event is processed.
+///
+/// [`RedrawRequested`]: crate::window::Event::RedrawRequested
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// The input method is disabled.
+    #[default]
+    Disabled,
+
+    /// The input method is enabled.
+    Enabled {
+        /// The bounds of the text cursor, used to place the candidate window
+        /// of the input method.
+        cursor: Rectangle,
+    },
+}
+
+impl InputMethod {
+    /// Returns true if the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+}
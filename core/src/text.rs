@@ -129,6 +129,53 @@ impl Hit {
     }
 }
 
+/// Splits the text into words, each one followed by its trailing whitespace.
+///
+/// Every word is returned together with its byte offset.
+pub fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut is_whitespace = false;
+
+    for (i, c) in text.char_indices() {
+        if is_whitespace && !c.is_whitespace() {
+            words.push((start, &text[start..i]));
+            start = i;
+        }
+
+        is_whitespace = c.is_whitespace();
+    }
+
+    if start < text.len() {
+        words.push((start, &text[start..]));
+    }
+
+    words
+}
+
+/// Returns the byte length of the longest prefix of the text that fits in
+/// the maximum width, which is at least one character long.
+///
+/// It is useful to break words that do not fit in a line on their own.
+pub fn fitting_prefix(
+    text: &str,
+    max_width: f32,
+    mut measure: impl FnMut(&str) -> f32,
+) -> usize {
+    let mut boundaries = text.char_indices().map(|(i, _)| i).skip(1);
+    let mut end = boundaries.next().unwrap_or(text.len());
+
+    for boundary in boundaries.chain(std::iter::once(text.len())) {
+        if measure(&text[..boundary]) > max_width {
+            break;
+        }
+
+        end = boundary;
+    }
+
+    end
+}
+
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
@@ -1,14 +1,22 @@
 //! Write some text for your users to read.
 use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
-use crate::mouse;
+use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text;
-use crate::widget::Tree;
-use crate::{Color, Element, Layout, Length, Pixels, Rectangle, Size, Widget};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
 
 use log::debug;
 use std::borrow::Cow;
+use std::cell::{Ref, RefCell};
+use std::ops::Range;
 
 pub use text::{LineHeight, Shaping};
 
@@ -29,6 +37,7 @@ where
     vertical_alignment: alignment::Vertical,
     font: Option<Renderer::Font>,
     shaping: Shaping,
+    is_selectable: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -50,6 +59,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::Advanced,
+            is_selectable: false,
             style: Default::default(),
         }
     }
@@ -118,6 +128,136 @@ where
         self.shaping = shaping;
         self
     }
+
+    /// Sets whether the [`Text`] can be selected and copied.
+    ///
+    /// A selectable [`Text`] can be selected by dragging the mouse over it,
+    /// double-clicking a word or triple-clicking a line. The selection is
+    /// copied to the [`Clipboard`] with the usual shortcut.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
+    /// Wraps the content of a selectable [`Text`] into visual lines.
+    fn lines(&self, renderer: &Renderer, max_width: f32) -> Vec<Line> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        wrap(&self.content, max_width, |text| {
+            renderer.measure_width(text, size, font, self.shaping)
+        })
+    }
+
+    /// Returns the visual lines of a selectable [`Text`], wrapping its content
+    /// again only when it, its size, its shaping strategy or the width of its
+    /// bounds has changed.
+    fn cached_lines<'b>(
+        &self,
+        state: &'b State,
+        renderer: &Renderer,
+        max_width: f32,
+    ) -> Ref<'b, [Line]> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let is_outdated = state.lines.borrow().as_ref().is_none_or(|cache| {
+            cache.max_width != max_width
+                || cache.size != size
+                || cache.shaping != self.shaping
+                || cache.content != self.content
+        });
+
+        if is_outdated {
+            *state.lines.borrow_mut() = Some(Lines {
+                content: self.content.to_string(),
+                max_width,
+                size,
+                shaping: self.shaping,
+                lines: self.lines(renderer, max_width),
+            });
+        }
+
+        Ref::map(state.lines.borrow(), |cache| {
+            cache.as_ref().map_or(&[][..], |cache| &cache.lines[..])
+        })
+    }
+
+    /// Returns the absolute line height of the [`Text`].
+    fn absolute_line_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.line_height.to_absolute(Pixels(size)))
+    }
+
+    /// Returns the position of the given line relative to the bounds of the
+    /// [`Text`], taking its alignment into account.
+    fn line_position(
+        &self,
+        lines: &[Line],
+        index: usize,
+        bounds: Size,
+        line_height: f32,
+    ) -> Point {
+        let height = line_height * lines.len() as f32;
+
+        let x = match self.horizontal_alignment {
+            alignment::Horizontal::Left => 0.0,
+            alignment::Horizontal::Center => {
+                (bounds.width - lines[index].width) / 2.0
+            }
+            alignment::Horizontal::Right => bounds.width - lines[index].width,
+        };
+
+        let y = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => (bounds.height - height) / 2.0,
+            alignment::Vertical::Bottom => bounds.height - height,
+        };
+
+        Point::new(x, y + line_height * index as f32)
+    }
+
+    /// Returns the byte offset of the character nearest to the given
+    /// position, relative to the bounds of the [`Text`].
+    fn hit_test(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> usize {
+        let lines = self.cached_lines(state, renderer, bounds.width);
+        let line_height = self.absolute_line_height(renderer);
+
+        let first = self.line_position(&lines, 0, bounds, line_height);
+        let index =
+            ((position.y - first.y) / line_height).floor().max(0.0) as usize;
+        let index = index.min(lines.len() - 1);
+
+        let line = &lines[index];
+        let content = &self.content[line.range.clone()];
+        let x = position.x
+            - self.line_position(&lines, index, bounds, line_height).x;
+
+        let hit = renderer.hit_test(
+            content,
+            self.size.unwrap_or_else(|| renderer.default_size()),
+            self.line_height,
+            self.font.unwrap_or_else(|| renderer.default_font()),
+            Size::new(f32::INFINITY, line_height),
+            self.shaping,
+            Point::new(x, line_height / 2.0),
+            true,
+        );
+
+        let offset = match hit {
+            Some(hit) => hit.cursor().min(content.len()),
+            None if x <= 0.0 => 0,
+            None => content.len(),
+        };
+
+        line.range.start + floor_char_boundary(content, offset)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Text<'a, Renderer>
@@ -125,6 +265,21 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        // Fonts cannot be compared, so a custom one may have changed
+        if self.font.is_some() {
+            *tree.state.downcast_mut::<State>().lines.get_mut() = None;
+        }
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -144,6 +299,16 @@ where
 
         let bounds = limits.max();
 
+        if self.is_selectable {
+            let lines = self.lines(renderer, bounds.width);
+
+            let width = lines.iter().map(|line| line.width).fold(0.0, f32::max);
+            let height =
+                self.absolute_line_height(renderer) * lines.len() as f32;
+
+            return layout::Node::new(limits.resolve(Size::new(width, height)));
+        }
+
         let (width, height) = renderer.measure(
             &self.content,
             size,
@@ -158,9 +323,142 @@ where
         layout::Node::new(size)
     }
 
-    fn draw(
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_selectable {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    state.selection = None;
+                    state.is_dragging = false;
+                    state.is_active = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let offset =
+                    self.hit_test(state, renderer, bounds.size(), position);
+                let click = mouse::Click::new(position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        let anchor = match state.selection {
+                            Some(selection)
+                                if state.keyboard_modifiers.shift() =>
+                            {
+                                selection.anchor
+                            }
+                            _ => offset,
+                        };
+
+                        state.selection = Some(Selection {
+                            anchor,
+                            head: offset,
+                        });
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        let word = word_at(&self.content, offset);
+
+                        state.selection = Some(Selection::from(word));
+                        state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        let line = line_at(&self.content, offset);
+
+                        state.selection = Some(Selection::from(line));
+                        state.is_dragging = false;
+                    }
+                }
+
+                state.last_click = Some(click);
+                state.is_active = true;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                let position =
+                    Point::new(position.x - bounds.x, position.y - bounds.y);
+
+                if let Some(mut selection) = state.selection {
+                    selection.head =
+                        self.hit_test(state, renderer, bounds.size(), position);
+
+                    state.selection = Some(selection);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::C,
+                ..
+            }) if state.is_active && state.keyboard_modifiers.command() => {
+                if let Some(range) = state
+                    .selection
+                    .and_then(|selection| selection.range(&self.content))
+                {
+                    clipboard.write(self.content[range].to_owned());
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                ..
+            }) => {
+                // The keyboard focus moves to another widget
+                state.is_active = false;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
         &self,
         _state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -168,6 +466,18 @@ where
         _cursor_position: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        if self.is_selectable {
+            self.draw_selectable(
+                tree.state.downcast_ref::<State>(),
+                renderer,
+                theme.appearance(self.style.clone()),
+                style,
+                layout,
+            );
+
+            return;
+        }
+
         draw(
             renderer,
             style,
@@ -228,6 +538,264 @@ where
     }
 }
 
+impl<'a, Renderer> Text<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Draws a selectable [`Text`] line by line, highlighting its selection.
+    fn draw_selectable(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        appearance: Appearance,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+    ) {
+        let bounds = layout.bounds();
+        let lines = self.cached_lines(state, renderer, bounds.width);
+        let line_height = self.absolute_line_height(renderer);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let color = appearance.color.unwrap_or(style.text_color);
+
+        let selection = state
+            .selection
+            .and_then(|selection| selection.range(&self.content));
+
+        for (index, line) in lines.iter().enumerate() {
+            let position =
+                self.line_position(&lines, index, bounds.size(), line_height);
+            let x = bounds.x + position.x;
+            let y = bounds.y + position.y;
+
+            if let Some(selection) = &selection {
+                let start = selection.start.max(line.range.start);
+                let end = selection.end.min(line.range.end);
+
+                if start < end {
+                    let measure = |end: usize| {
+                        renderer.measure_width(
+                            &self.content[line.range.start..end],
+                            size,
+                            font,
+                            self.shaping,
+                        )
+                    };
+
+                    let (left, right) = (measure(start), measure(end));
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x + left,
+                                y,
+                                width: right - left,
+                                height: line_height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.selection.unwrap_or(Color {
+                            a: color.a * 0.3,
+                            ..color
+                        }),
+                    );
+                }
+            }
+
+            renderer.fill_text(crate::Text {
+                content: &self.content[line.range.clone()],
+                size,
+                line_height: self.line_height,
+                bounds: Rectangle {
+                    x,
+                    y,
+                    width: f32::INFINITY,
+                    height: line_height,
+                },
+                color,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: self.shaping,
+            });
+        }
+    }
+}
+
+/// The local state of a [`Text`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    selection: Option<Selection>,
+    is_dragging: bool,
+    /// Whether the last press started inside the [`Text`], making its
+    /// selection the one copied to the [`Clipboard`].
+    is_active: bool,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+    lines: RefCell<Option<Lines>>,
+}
+
+/// The visual lines of a selectable [`Text`], along with the content, bounds
+/// and text properties they were wrapped for.
+#[derive(Debug, Clone)]
+struct Lines {
+    content: String,
+    max_width: f32,
+    size: f32,
+    shaping: Shaping,
+    lines: Vec<Line>,
+}
+
+/// The selected text of a selectable [`Text`], as byte offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    anchor: usize,
+    head: usize,
+}
+
+impl Selection {
+    /// Returns the selected range of the content, if it is not empty.
+    fn range(self, content: &str) -> Option<Range<usize>> {
+        let start = self.anchor.min(self.head).min(content.len());
+        let end = self.anchor.max(self.head).min(content.len());
+
+        let range = floor_char_boundary(content, start)
+            ..floor_char_boundary(content, end);
+
+        (!range.is_empty()).then_some(range)
+    }
+}
+
+impl From<Range<usize>> for Selection {
+    fn from(range: Range<usize>) -> Self {
+        Self {
+            anchor: range.start,
+            head: range.end,
+        }
+    }
+}
+
+/// A visual line of a selectable [`Text`].
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    range: Range<usize>,
+    width: f32,
+}
+
+/// Wraps the content into visual lines that fit the maximum width.
+///
+/// Lines break at whitespace and newlines. A word that does not fit in a
+/// line on its own is broken at any character.
+fn wrap(
+    content: &str,
+    max_width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+
+    for line in content.split('\n') {
+        let line_end = line_start + line.len();
+        let mut start = line_start;
+        let mut end = line_start;
+        let mut width = 0.0;
+
+        for (offset, word) in text::words(line) {
+            let text_end = line_start + offset + word.trim_end().len();
+            let mut text_width = measure(&content[start..text_end]);
+
+            if text_width > max_width && end > start {
+                lines.push(Line {
+                    range: start..end,
+                    width,
+                });
+
+                start = end;
+                text_width = measure(&content[start..text_end]);
+            }
+
+            // Words wider than a whole line are broken at any character
+            while text_width > max_width {
+                let text = &content[start..text_end];
+                let prefix = text::fitting_prefix(text, max_width, &measure);
+
+                lines.push(Line {
+                    range: start..start + prefix,
+                    width: measure(&text[..prefix]),
+                });
+
+                start += prefix;
+                text_width = measure(&content[start..text_end]);
+            }
+
+            end = line_start + offset + word.len();
+            width = text_width;
+        }
+
+        lines.push(Line {
+            range: start..line_end,
+            width,
+        });
+
+        line_start = line_end + 1;
+    }
+
+    lines
+}
+
+/// Returns the range of the word at the given byte offset.
+///
+/// If there is no word at the offset, the range of the character at the
+/// offset is returned instead.
+fn word_at(content: &str, offset: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let Some(c) = content[offset..].chars().next() else {
+        return offset..offset;
+    };
+
+    if !is_word(c) {
+        return offset..offset + c.len_utf8();
+    }
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = content[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(content.len(), |(i, _)| offset + i);
+
+    start..end
+}
+
+/// Returns the range of the line at the given byte offset, without its
+/// line break.
+fn line_at(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
+/// Returns the largest character boundary of the content that is not
+/// greater than the given byte offset.
+fn floor_char_boundary(content: &str, offset: usize) -> usize {
+    (0..=offset.min(content.len()))
+        .rev()
+        .find(|&i| content.is_char_boundary(i))
+        .unwrap_or(0)
+}
+
 /// Draws text using the same logic as the [`Text`] widget.
 ///
 /// Specifically:
@@ -316,6 +884,7 @@ where
             font: self.font,
             style: self.style.clone(),
             shaping: self.shaping,
+            is_selectable: self.is_selectable,
         }
     }
 }
@@ -349,6 +918,18 @@ pub trait StyleSheet {
     fn appearance(&self, style: Self::Style) -> Appearance;
 }
 
+/// Text without a theme, like the one of the [`Null`] renderer, uses the
+/// inherited color.
+///
+/// [`Null`]: crate::renderer::Null
+impl StyleSheet for () {
+    type Style = ();
+
+    fn appearance(&self, _style: Self::Style) -> Appearance {
+        Appearance::default()
+    }
+}
+
 /// The apperance of some text.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Appearance {
@@ -356,4 +937,159 @@ pub struct Appearance {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+
+    /// The [`Color`] of the highlight of the selected text.
+    ///
+    /// The default, `None`, means using a translucent version of the color
+    /// of the text.
+    pub selection: Option<Color>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(content: &str, max_width: f32) -> Vec<&str> {
+        // Every character is 10 pixels wide
+        wrap(content, max_width, |text| {
+            text.chars().count() as f32 * 10.0
+        })
+        .into_iter()
+        .map(|line| &content[line.range])
+        .collect()
+    }
+
+    #[test]
+    fn copies_only_the_active_selection() {
+        use crate::renderer::Null;
+
+        #[derive(Default)]
+        struct Clipboard(Option<String>);
+
+        impl crate::Clipboard for Clipboard {
+            fn read(&self) -> Option<String> {
+                self.0.clone()
+            }
+
+            fn write(&mut self, contents: String) {
+                self.0 = Some(contents);
+            }
+        }
+
+        fn send(
+            text: &mut Text<'_, Null>,
+            tree: &mut Tree,
+            clipboard: &mut Clipboard,
+            event: Event,
+        ) {
+            let node = layout::Node::new(Size::new(100.0, 20.0));
+
+            let _ = Widget::<(), Null>::on_event(
+                text,
+                tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(1.0, 1.0)),
+                &Null,
+                clipboard,
+                &mut Shell::new(&mut Vec::new()),
+            );
+        }
+
+        let mut text = Text::<Null>::new("hello world").selectable(true);
+        let mut tree = Tree::new(&text as &dyn Widget<(), Null>);
+        let mut clipboard = Clipboard::default();
+
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let copy = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::C,
+            modifiers: keyboard::Modifiers::COMMAND,
+        });
+        let tab = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Tab,
+            modifiers: keyboard::Modifiers::default(),
+        });
+
+        send(
+            &mut text,
+            &mut tree,
+            &mut clipboard,
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::COMMAND,
+            )),
+        );
+
+        send(&mut text, &mut tree, &mut clipboard, press.clone());
+        tree.state.downcast_mut::<State>().selection =
+            Some(Selection::from(0..5));
+
+        send(&mut text, &mut tree, &mut clipboard, tab);
+        send(&mut text, &mut tree, &mut clipboard, copy.clone());
+
+        assert_eq!(clipboard.0, None);
+
+        send(&mut text, &mut tree, &mut clipboard, press);
+        tree.state.downcast_mut::<State>().selection =
+            Some(Selection::from(0..5));
+
+        send(&mut text, &mut tree, &mut clipboard, copy);
+
+        assert_eq!(clipboard.0.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn diff_keeps_lines_unless_a_custom_font_is_set() {
+        use crate::renderer::Null;
+        use crate::Font;
+
+        let is_cached = |text: &mut Text<'_, Null>| {
+            let mut tree = Tree::new(&*text as &dyn Widget<(), Null>);
+            let state = tree.state.downcast_ref::<State>();
+            let _ = text.cached_lines(state, &Null, 100.0);
+
+            Widget::<(), Null>::diff(text, &mut tree);
+
+            let state = tree.state.downcast_ref::<State>();
+            let is_cached = state.lines.borrow().is_some();
+
+            is_cached
+        };
+
+        assert!(is_cached(&mut Text::new("cached")));
+        assert!(!is_cached(&mut Text::new("cached").font(Font::MONOSPACE)));
+    }
+
+    #[test]
+    fn wrap_breaks_at_whitespace_and_newlines() {
+        assert_eq!(
+            lines("select and copy\nthis text", 100.0),
+            vec!["select and ", "copy", "this text"]
+        );
+        assert_eq!(lines("", 100.0), vec![""]);
+    }
+
+    #[test]
+    fn wrap_breaks_long_words() {
+        assert_eq!(lines("abcdefgh ij", 30.0), vec!["abc", "def", "gh ", "ij"]);
+    }
+
+    #[test]
+    fn word_and_line_selection() {
+        let content = "copy this_word, please\nnext line";
+
+        assert_eq!(&content[word_at(content, 7)], "this_word");
+        assert_eq!(&content[word_at(content, 14)], ",");
+        assert_eq!(&content[line_at(content, 7)], "copy this_word, please");
+        assert_eq!(&content[line_at(content, 25)], "next line");
+    }
+
+    #[test]
+    fn selection_range_respects_char_boundaries() {
+        let content = "héllo";
+        let selection = Selection { anchor: 4, head: 2 };
+
+        assert_eq!(selection.range(content), Some(1..4));
+        assert_eq!(Selection { anchor: 3, head: 3 }.range(content), None);
+    }
 }
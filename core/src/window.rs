@@ -4,16 +4,36 @@ pub mod icon;
 mod event;
 mod level;
 mod mode;
+mod position;
 mod redraw_request;
+mod settings;
 mod user_attention;
 
 pub use event::Event;
 pub use icon::Icon;
 pub use level::Level;
 pub use mode::Mode;
+pub use position::Position;
 pub use redraw_request::RedrawRequest;
+pub use settings::Settings;
 pub use user_attention::UserAttention;
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// The identifier of a generic window.
 pub struct Id(pub u128);
+
+impl Id {
+    /// The identifier of the main window of an application.
+    pub const MAIN: Self = Id(0);
+
+    /// Creates a new unique window [`Id`].
+    ///
+    /// The identifiers produced never collide with [`Id::MAIN`].
+    pub fn unique() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+        Id(u128::from(NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+    }
+}
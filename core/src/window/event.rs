@@ -32,6 +32,9 @@ pub enum Event {
     /// occurs.
     CloseRequested,
 
+    /// A window was closed.
+    Closed,
+
     /// A window was focused.
     Focused,
 
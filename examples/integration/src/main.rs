@@ -182,6 +182,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Map window event to iced event
                 if let Some(event) = iced_winit::conversion::window_event(
+                    iced_winit::core::window::Id::MAIN,
                     &event,
                     window.scale_factor(),
                     modifiers,
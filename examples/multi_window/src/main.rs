@@ -0,0 +1,103 @@
+use iced::executor;
+use iced::multi_window::Application;
+use iced::subscription;
+use iced::widget::{button, column, container, text};
+use iced::window;
+use iced::{
+    Alignment, Command, Element, Event, Length, Settings, Subscription, Theme,
+};
+
+use std::collections::BTreeMap;
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+#[derive(Default)]
+struct Example {
+    tools: BTreeMap<window::Id, usize>,
+    next_tool: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    OpenTool,
+    CloseTool(window::Id),
+    WindowClosed(window::Id),
+}
+
+impl Application for Example {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        match self.tools.get(&window) {
+            Some(tool) => format!("Tool #{tool} - Iced"),
+            None => String::from("Multi-window - Iced"),
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::OpenTool => {
+                let (id, spawn) = window::spawn(window::Settings {
+                    size: (300, 200),
+                    ..window::Settings::default()
+                });
+
+                self.next_tool += 1;
+                let _ = self.tools.insert(id, self.next_tool);
+
+                spawn
+            }
+            Message::CloseTool(id) => window::close(id),
+            Message::WindowClosed(id) => {
+                let _ = self.tools.remove(&id);
+
+                Command::none()
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Window(id, window::Event::Closed) => {
+                Some(Message::WindowClosed(id))
+            }
+            _ => None,
+        })
+    }
+
+    fn view(&self, window: window::Id) -> Element<Message> {
+        let content = match self.tools.get(&window) {
+            Some(tool) => column![
+                text(format!("This is tool #{tool}")),
+                button("Close")
+                    .padding([10, 20])
+                    .on_press(Message::CloseTool(window)),
+            ],
+            None => column![
+                text(format!("{} tools open", self.tools.len())),
+                button("Open tool")
+                    .padding([10, 20])
+                    .on_press(Message::OpenTool),
+            ],
+        }
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -59,6 +59,25 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Renders the current [`Renderer`] primitives offscreen and returns the
+    /// resulting pixels ordered as `RGBA` in the sRGB color space.
+    ///
+    /// An empty buffer is returned if the [`Viewport`] has no area. The
+    /// default implementation always returns an empty buffer, for compositors
+    /// that do not support taking screenshots.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _overlay: &[T],
+    ) -> Vec<u8> {
+        Vec::new()
+    }
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
@@ -136,6 +136,43 @@ impl<Theme> crate::graphics::Compositor for Compositor<Theme> {
             }
         })
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        renderer.with_primitives(|backend, primitives| match (self, backend) {
+            (
+                Self::TinySkia(_compositor),
+                crate::Backend::TinySkia(backend),
+            ) => iced_tiny_skia::window::compositor::screenshot(
+                backend,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            ),
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), crate::Backend::Wgpu(backend)) => {
+                iced_wgpu::window::compositor::screenshot(
+                    compositor,
+                    backend,
+                    primitives,
+                    viewport,
+                    background_color,
+                    overlay,
+                )
+            }
+            #[allow(unreachable_patterns)]
+            _ => panic!(
+                "The provided renderer is not compatible with the compositor."
+            ),
+        })
+    }
 }
 
 enum Candidate {
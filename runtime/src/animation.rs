@@ -0,0 +1,20 @@
+//! Animate values over time.
+pub use crate::core::animation::*;
+
+use crate::core::time::Instant;
+use crate::futures::Subscription;
+use crate::window;
+
+/// Returns a [`Subscription`] that produces the [`Instant`] of every frame
+/// while `is_animating` is true.
+///
+/// Applications can keep an [`Animation`] in their state, subscribe to its
+/// frames while [`Animation::is_animating`], and read its value at the
+/// last frame in their `view`.
+pub fn frames(is_animating: bool) -> Subscription<Instant> {
+    if is_animating {
+        window::frames().map(|(_id, at)| at)
+    } else {
+        Subscription::none()
+    }
+}
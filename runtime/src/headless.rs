@@ -0,0 +1,871 @@
+//! Drive a [`Program`] without a window, a display, or a GPU.
+//!
+//! A [`Headless`] runner owns a [`Program`] and a renderer and performs the
+//! same work a shell would: it builds the widgets, feeds them [`Event`]s,
+//! updates the [`Program`] with the produced messages, and runs the resulting
+//! [`Command`]s and [`Subscription`]s to completion on a local executor.
+//!
+//! This is mostly useful to write tests that run on machines with no display.
+//!
+//! ```
+//! use iced_runtime::core::widget::text::Text;
+//! use iced_runtime::core::{renderer, window, Element, Size};
+//! use iced_runtime::headless::Headless;
+//! use iced_runtime::{Command, Program};
+//!
+//! #[derive(Debug, Default)]
+//! struct Counter {
+//!     value: i32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! impl Program for Counter {
+//!     type Renderer = renderer::Null;
+//!     type Message = Message;
+//!
+//!     fn update(&mut self, message: Message) -> Command<Message> {
+//!         match message {
+//!             Message::Increment => self.value += 1,
+//!         }
+//!
+//!         Command::none()
+//!     }
+//!
+//!     fn view(&self, _id: window::Id) -> Element<'_, Message, renderer::Null> {
+//!         Text::new(self.value.to_string()).into()
+//!     }
+//! }
+//!
+//! let size = Size::new(800.0, 600.0);
+//! let mut headless = Headless::new(Counter::default(), renderer::Null, size);
+//!
+//! headless.message(Message::Increment);
+//! headless.perform(Command::perform(async {}, |_| Message::Increment));
+//!
+//! assert_eq!(headless.program().value, 2);
+//! ```
+use crate::clipboard;
+use crate::command::{self, Command};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::Renderer as _;
+use crate::core::time::Instant;
+use crate::core::widget;
+use crate::core::widget::operation::{
+    Focusable, Operation, OperationOutputWrapper, OperationWrapper, Outcome,
+    Scrollable, TextInput,
+};
+use crate::core::window;
+use crate::core::{Clipboard, Point, Renderer, Size};
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::executor::{LocalPool, LocalSpawner};
+use crate::futures::futures::task::LocalSpawnExt;
+use crate::futures::futures::Future;
+use crate::futures::{MaybeSend, Runtime, Subscription};
+use crate::user_interface::{self, UserInterface};
+use crate::Program;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Produces the [`Subscription`] of a [`Program`] from its current state.
+type SubscriptionFn<P> =
+    Box<dyn Fn(&P) -> Subscription<<P as Program>::Message>>;
+
+/// A window-less runner of a [`Program`].
+///
+/// Every input method of a [`Headless`] runner processes its input and then
+/// [`settle`]s the [`Program`], so its state can be inspected right away.
+///
+/// Futures are run on a single-threaded executor owned by the runner. Any
+/// future relying on a specific runtime (like `tokio` timers) will need that
+/// runtime to be entered by the test itself.
+///
+/// [`settle`]: Self::settle
+#[allow(missing_debug_implementations)]
+pub struct Headless<P>
+where
+    P: Program + 'static,
+{
+    id: window::Id,
+    program: P,
+    renderer: P::Renderer,
+    theme: <P::Renderer as Renderer>::Theme,
+    style: renderer::Style,
+    size: Size,
+    cursor: mouse::Cursor,
+    clipboard: Memory,
+    cache: user_interface::Cache,
+    executor: Executor,
+    runtime: Runtime<Executor, mpsc::UnboundedSender<P::Message>, P::Message>,
+    receiver: mpsc::UnboundedReceiver<P::Message>,
+    subscription: Option<SubscriptionFn<P>>,
+    queued_events: Vec<Event>,
+    queued_messages: Vec<P::Message>,
+    actions: Vec<command::Action<P::Message>>,
+    mouse_interaction: mouse::Interaction,
+    redraw_request: Option<window::RedrawRequest>,
+}
+
+/// A widget found in a [`Headless`] runner by its [`widget::Id`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Target {
+    /// Whether the widget is focused, if it can be focused.
+    pub is_focused: Option<bool>,
+
+    /// The bounds of the widget, as reported by its accessibility node.
+    #[cfg(feature = "a11y")]
+    pub bounds: Option<crate::core::Rectangle>,
+}
+
+impl<P> Headless<P>
+where
+    P: Program + 'static,
+    P::Message: 'static,
+{
+    /// Creates a new [`Headless`] runner of the given [`Program`] with the
+    /// provided renderer and logical viewport size.
+    pub fn new(program: P, renderer: P::Renderer, size: Size) -> Self
+    where
+        <P::Renderer as Renderer>::Theme: Default,
+    {
+        use iced_futures::Executor as _;
+
+        let executor = Executor::new().expect("Create headless executor");
+        let (sender, receiver) = mpsc::unbounded();
+        let runtime = Runtime::new(executor.clone(), sender);
+
+        let mut headless = Self {
+            id: window::Id::MAIN,
+            program,
+            renderer,
+            theme: Default::default(),
+            style: renderer::Style {
+                text_color: crate::core::Color::BLACK,
+            },
+            size,
+            cursor: mouse::Cursor::Unavailable,
+            clipboard: Memory::default(),
+            cache: user_interface::Cache::default(),
+            executor,
+            runtime,
+            receiver,
+            subscription: None,
+            queued_events: Vec::new(),
+            queued_messages: Vec::new(),
+            actions: Vec::new(),
+            mouse_interaction: mouse::Interaction::Idle,
+            redraw_request: None,
+        };
+
+        headless.redraw();
+        headless
+    }
+
+    /// Sets the theme used to draw the [`Program`].
+    pub fn theme(mut self, theme: <P::Renderer as Renderer>::Theme) -> Self {
+        self.theme = theme;
+        self.redraw();
+        self
+    }
+
+    /// Sets the [`Subscription`] of the [`Program`].
+    ///
+    /// The closure is called again after every update, just like a shell
+    /// would do.
+    pub fn subscription(
+        mut self,
+        subscription: impl Fn(&P) -> Subscription<P::Message> + 'static,
+    ) -> Self {
+        self.subscription = Some(Box::new(subscription));
+        self.track();
+        self.settle();
+        self
+    }
+
+    /// Returns a reference to the [`Program`] of the [`Headless`] runner.
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns a reference to the renderer of the [`Headless`] runner.
+    pub fn renderer(&self) -> &P::Renderer {
+        &self.renderer
+    }
+
+    /// Returns the logical size of the viewport.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the current [`mouse::Interaction`] of the widgets.
+    pub fn mouse_interaction(&self) -> mouse::Interaction {
+        self.mouse_interaction
+    }
+
+    /// Returns the last redraw request produced by the widgets, if any.
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
+
+    /// Returns the current contents of the in-memory clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+
+    /// Replaces the contents of the in-memory clipboard.
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard.write(contents.into());
+    }
+
+    /// Takes the actions that the [`Headless`] runner could not perform by
+    /// itself, like most window and system actions.
+    pub fn take_actions(&mut self) -> Vec<command::Action<P::Message>> {
+        std::mem::take(&mut self.actions)
+    }
+
+    /// Processes the given [`Event`] and returns its [`event::Status`].
+    pub fn event(&mut self, event: Event) -> event::Status {
+        self.events([event]).pop().unwrap_or(event::Status::Ignored)
+    }
+
+    /// Processes the given events in order and returns their statuses.
+    pub fn events(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let statuses = self.process(events.into_iter().collect());
+        self.settle();
+
+        statuses
+    }
+
+    /// Updates the [`Program`] with the given message.
+    pub fn message(&mut self, message: P::Message) {
+        self.queued_messages.push(message);
+        self.settle();
+    }
+
+    /// Runs the given [`Command`] to completion.
+    pub fn perform(&mut self, command: Command<P::Message>) {
+        self.run(command);
+        self.settle();
+    }
+
+    /// Applies the given widget [`Operation`] to the [`Program`].
+    pub fn operate(
+        &mut self,
+        operation: impl widget::Operation<P::Message> + 'static,
+    ) {
+        self.perform(Command::widget(operation));
+    }
+
+    /// Resizes the viewport to the given logical size.
+    pub fn resize(&mut self, size: Size) {
+        let _ = self.event(Event::Window(
+            self.id,
+            window::Event::Resized {
+                width: size.width as u32,
+                height: size.height as u32,
+            },
+        ));
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn move_cursor(&mut self, position: Point) {
+        let _ =
+            self.event(Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: Point) {
+        let _ = self.events([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    /// Clicks the center of the widget with the given [`widget::Id`].
+    ///
+    /// Returns `false` if the widget could not be found.
+    #[cfg(feature = "a11y")]
+    pub fn click_on(&mut self, id: &widget::Id) -> bool {
+        match self.find(id).and_then(|target| target.bounds) {
+            Some(bounds) => {
+                self.click(bounds.center());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Presses and releases the given key.
+    pub fn press_key(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) {
+        let _ = self.events([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ]);
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) {
+        let _ =
+            self.events(text.chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }));
+    }
+
+    /// Finds the widget with the given [`widget::Id`].
+    pub fn find(&mut self, id: &widget::Id) -> Option<Target> {
+        let mut user_interface = build_user_interface(
+            self.id,
+            &self.program,
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+            self.size,
+        );
+
+        let mut find = Find {
+            target: id.clone(),
+            found: None,
+        };
+
+        user_interface.operate(&self.renderer, &mut find);
+
+        #[cfg(feature = "a11y")]
+        {
+            use iced_accessibility::A11yId;
+
+            let tree = user_interface.a11y_nodes(self.cursor);
+            let target = A11yId::Widget(id.clone());
+
+            if let Some(node) = tree
+                .root()
+                .iter()
+                .chain(tree.children())
+                .find(|node| node.id() == &target)
+            {
+                let found = find.found.get_or_insert_with(Target::default);
+
+                found.bounds =
+                    node.node().bounds().map(|rect| crate::core::Rectangle {
+                        x: rect.x0 as f32,
+                        y: rect.y0 as f32,
+                        width: rect.width() as f32,
+                        height: rect.height() as f32,
+                    });
+            }
+        }
+
+        self.cache = user_interface.into_cache();
+
+        find.found
+    }
+
+    /// Runs every pending future, message, and event until the [`Program`]
+    /// becomes idle, and then redraws it.
+    ///
+    /// The widgets receive a [`window::Event::RedrawRequested`] first, and
+    /// after every update, like in a real shell. This way, anything driven by
+    /// time, like animations, can advance.
+    ///
+    /// A [`Subscription`] that never stops producing messages will keep the
+    /// [`Headless`] runner busy forever.
+    pub fn settle(&mut self) {
+        self.track();
+
+        self.queued_events.push(Event::Window(
+            self.id,
+            window::Event::RedrawRequested(Instant::now()),
+        ));
+
+        loop {
+            self.executor.run_until_stalled();
+
+            while let Ok(message) = self.receiver.try_recv() {
+                self.queued_messages.push(message);
+            }
+
+            if !self.queued_events.is_empty() {
+                let events = std::mem::take(&mut self.queued_events);
+                let _ = self.process(events);
+
+                continue;
+            }
+
+            if self.queued_messages.is_empty() {
+                break;
+            }
+
+            for message in std::mem::take(&mut self.queued_messages) {
+                let command = self.program.update(message);
+                self.run(command);
+            }
+
+            self.track();
+
+            self.queued_events.push(Event::Window(
+                self.id,
+                window::Event::RedrawRequested(Instant::now()),
+            ));
+        }
+
+        self.redraw();
+    }
+
+    /// Starts or stops the recipes of the [`Subscription`], if any, to match
+    /// the current state of the [`Program`].
+    fn track(&mut self) {
+        if let Some(subscription) = &self.subscription {
+            self.runtime
+                .track(subscription(&self.program).into_recipes());
+        }
+    }
+
+    fn process(&mut self, events: Vec<Event>) -> Vec<event::Status> {
+        for event in &events {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    self.cursor = mouse::Cursor::Available(*position);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    self.cursor = mouse::Cursor::Unavailable;
+                }
+                Event::Window(_, window::Event::Resized { width, height }) => {
+                    self.size = Size::new(*width as f32, *height as f32);
+                }
+                _ => {}
+            }
+        }
+
+        let mut user_interface = build_user_interface(
+            self.id,
+            &self.program,
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+            self.size,
+        );
+
+        let mut messages = Vec::new();
+
+        let (state, statuses) = user_interface.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut messages,
+        );
+
+        if let user_interface::State::Updated { redraw_request, .. } = state {
+            self.redraw_request = redraw_request;
+        }
+
+        self.cache = user_interface.into_cache();
+        self.queued_messages.append(&mut messages);
+
+        for (event, status) in events.into_iter().zip(statuses.iter()) {
+            self.runtime.broadcast(event, *status);
+        }
+
+        statuses
+    }
+
+    fn run(&mut self, command: Command<P::Message>) {
+        for action in command.actions() {
+            match action {
+                command::Action::Future(future) => {
+                    self.runtime.spawn(future);
+                }
+                command::Action::Clipboard(action) => match action {
+                    clipboard::Action::Read(tagger) => {
+                        self.queued_messages
+                            .push(tagger(self.clipboard.read()));
+                    }
+                    clipboard::Action::Write(contents) => {
+                        self.clipboard.write(contents);
+                    }
+                },
+                command::Action::Widget(operation) => {
+                    self.apply(operation);
+                }
+                command::Action::LoadFont { bytes, tagger } => {
+                    self.renderer.load_font(bytes);
+                    self.queued_messages.push(tagger(Ok(())));
+                }
+                command::Action::Window(
+                    id,
+                    crate::window::Action::Resize { width, height },
+                ) if id == self.id => {
+                    self.queued_events.push(Event::Window(
+                        self.id,
+                        window::Event::Resized { width, height },
+                    ));
+                }
+                action => {
+                    self.actions.push(action);
+                }
+            }
+        }
+    }
+
+    fn apply(&mut self, operation: Box<dyn widget::Operation<P::Message>>) {
+        let mut user_interface = build_user_interface(
+            self.id,
+            &self.program,
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+            self.size,
+        );
+
+        let mut current_operation = Some(OperationWrapper::Message(operation));
+
+        while let Some(mut operation) = current_operation.take() {
+            user_interface.operate(&self.renderer, &mut operation);
+
+            match operation.finish() {
+                Outcome::Some(OperationOutputWrapper::Message(message)) => {
+                    self.queued_messages.push(message);
+                }
+                Outcome::Chain(next) => {
+                    current_operation = Some(OperationWrapper::Wrapper(next));
+                }
+                _ => {}
+            }
+        }
+
+        self.cache = user_interface.into_cache();
+    }
+
+    fn redraw(&mut self) {
+        let mut user_interface = build_user_interface(
+            self.id,
+            &self.program,
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+            self.size,
+        );
+
+        self.renderer.clear();
+
+        self.mouse_interaction = user_interface.draw(
+            &mut self.renderer,
+            &self.theme,
+            &self.style,
+            self.cursor,
+        );
+
+        self.cache = user_interface.into_cache();
+    }
+}
+
+fn build_user_interface<'a, P: Program>(
+    id: window::Id,
+    program: &'a P,
+    cache: user_interface::Cache,
+    renderer: &mut P::Renderer,
+    size: Size,
+) -> UserInterface<'a, P::Message, P::Renderer> {
+    UserInterface::build(program.view(id), size, cache, renderer)
+}
+
+/// An in-memory [`Clipboard`].
+#[derive(Debug, Default)]
+struct Memory {
+    contents: Option<String>,
+}
+
+impl Clipboard for Memory {
+    fn read(&self) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.contents = Some(contents);
+    }
+}
+
+/// A single-threaded executor that runs futures only when asked to.
+#[derive(Debug, Clone)]
+struct Executor {
+    pool: Rc<RefCell<LocalPool>>,
+    spawner: LocalSpawner,
+}
+
+impl Executor {
+    fn run_until_stalled(&self) {
+        self.pool.borrow_mut().run_until_stalled();
+    }
+}
+
+impl iced_futures::Executor for Executor {
+    fn new() -> Result<Self, iced_futures::futures::io::Error> {
+        let pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        Ok(Self {
+            pool: Rc::new(RefCell::new(pool)),
+            spawner,
+        })
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + MaybeSend + 'static) {
+        let _ = self.spawner.spawn_local(future);
+    }
+}
+
+/// Finds a widget by its [`widget::Id`] while traversing the widget tree.
+struct Find {
+    target: widget::Id,
+    found: Option<Target>,
+}
+
+impl Find {
+    fn visit(&mut self, id: Option<&widget::Id>) -> Option<&mut Target> {
+        if id == Some(&self.target) {
+            Some(self.found.get_or_insert_with(Target::default))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Operation<T> for Find {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let _ = self.visit(id);
+
+        operate_on_children(self);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        if let Some(target) = self.visit(id) {
+            target.is_focused = Some(state.is_focused());
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&widget::Id>,
+    ) {
+        let _ = self.visit(id);
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        let _ = self.visit(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layout::{self, Layout};
+    use crate::core::widget::{tree, Tree};
+    use crate::core::{Element, Length, Rectangle, Shell, Widget};
+
+    #[derive(Debug, Default)]
+    struct Counter {
+        value: i32,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum Message {
+        Add(i32),
+        AddLater(i32),
+    }
+
+    impl Program for Counter {
+        type Renderer = renderer::Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::Add(amount) => {
+                    self.value += amount;
+
+                    Command::none()
+                }
+                Message::AddLater(amount) => {
+                    Command::perform(async move { amount }, Message::Add)
+                }
+            }
+        }
+
+        fn view(
+            &self,
+            _id: window::Id,
+        ) -> Element<'_, Message, Self::Renderer> {
+            Element::new(Field {
+                id: widget::Id::new("field"),
+            })
+        }
+    }
+
+    /// A widget that gains focus when clicked and adds one when it does.
+    struct Field {
+        id: widget::Id,
+    }
+
+    #[derive(Default)]
+    struct State {
+        is_focused: bool,
+    }
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            self.is_focused
+        }
+
+        fn focus(&mut self) {
+            self.is_focused = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.is_focused = false;
+        }
+    }
+
+    impl Widget<Message, renderer::Null> for Field {
+        fn width(&self) -> Length {
+            Length::Fixed(50.0)
+        }
+
+        fn height(&self) -> Length {
+            Length::Fixed(20.0)
+        }
+
+        fn tag(&self) -> tree::Tag {
+            tree::Tag::of::<State>()
+        }
+
+        fn state(&self) -> tree::State {
+            tree::State::new(State::default())
+        }
+
+        fn layout(
+            &self,
+            _renderer: &renderer::Null,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(50.0, 20.0))
+        }
+
+        fn draw(
+            &self,
+            _state: &Tree,
+            _renderer: &mut renderer::Null,
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn operate(
+            &self,
+            tree: &mut Tree,
+            _layout: Layout<'_>,
+            _renderer: &renderer::Null,
+            operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+        ) {
+            operation
+                .focusable(tree.state.downcast_mut::<State>(), Some(&self.id));
+        }
+
+        fn on_event(
+            &mut self,
+            tree: &mut Tree,
+            event: Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            _renderer: &renderer::Null,
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) -> event::Status {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+                if cursor.is_over(layout.bounds()) && !state.is_focused {
+                    state.focus();
+                    shell.publish(Message::AddLater(1));
+
+                    return event::Status::Captured;
+                }
+            }
+
+            event::Status::Ignored
+        }
+    }
+
+    #[test]
+    fn it_runs_commands_to_completion() {
+        let mut headless = Headless::new(
+            Counter::default(),
+            renderer::Null,
+            Size::new(100.0, 100.0),
+        );
+
+        headless.message(Message::Add(1));
+        headless.message(Message::AddLater(2));
+        headless.perform(Command::batch([
+            Command::perform(async { 3 }, Message::AddLater),
+            Command::perform(async { 4 }, Message::Add),
+        ]));
+
+        assert_eq!(headless.program().value, 10);
+    }
+
+    #[test]
+    fn it_finds_widgets_by_id() {
+        let mut headless = Headless::new(
+            Counter::default(),
+            renderer::Null,
+            Size::new(100.0, 100.0),
+        );
+
+        let id = widget::Id::new("field");
+
+        assert_eq!(
+            headless.find(&id).and_then(|field| field.is_focused),
+            Some(false)
+        );
+        assert!(headless.find(&widget::Id::new("missing")).is_none());
+
+        headless.click(Point::new(10.0, 10.0));
+
+        assert_eq!(
+            headless.find(&id).and_then(|field| field.is_focused),
+            Some(true)
+        );
+        assert_eq!(headless.program().value, 1);
+    }
+}
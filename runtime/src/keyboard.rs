@@ -1,2 +1,7 @@
 //! Track keyboard events.
+mod keymap;
+mod shortcut;
+
 pub use iced_core::keyboard::*;
+pub use keymap::{Binding, Conflict, Keymap, Scope};
+pub use shortcut::{ParseError, Shortcut, Stroke};
@@ -0,0 +1,346 @@
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::Hasher;
+use crate::futures::subscription::{self, EventStream, Subscription};
+use crate::futures::{BoxStream, MaybeSend};
+use crate::keyboard::{Shortcut, Stroke};
+
+use std::hash::Hash;
+
+/// A set of keyboard shortcuts mapped to messages.
+///
+/// A [`Keymap`] listens to the keyboard once it is turned into a
+/// [`Subscription`]. The bindings are checked in the order they were added,
+/// so the first matching binding wins.
+///
+/// # Example
+/// ```
+/// use iced_runtime::keyboard::{Binding, Keymap, Scope};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     OpenPalette,
+///     ToggleSidebar,
+/// }
+///
+/// let keymap = Keymap::new()
+///     .bind("Ctrl+S".parse().unwrap(), Message::Save)
+///     .push(
+///         Binding::new("Ctrl+Shift+P".parse().unwrap(), Message::OpenPalette)
+///             .scope(Scope::Global)
+///             .description("Open the command palette"),
+///     )
+///     .bind("Ctrl+K Ctrl+B".parse().unwrap(), Message::ToggleSidebar);
+///
+/// assert_eq!(keymap.conflicts().count(), 0);
+///
+/// for binding in keymap.bindings() {
+///     println!("{}", binding.shortcut);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keymap<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+impl<Message> Keymap<Message> {
+    /// Creates a new, empty [`Keymap`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds the given [`Shortcut`] to a message, which is produced when
+    /// the [`Shortcut`] is pressed and no widget captured its keys.
+    pub fn bind(self, shortcut: Shortcut, message: Message) -> Self {
+        self.push(Binding::new(shortcut, message))
+    }
+
+    /// Adds the given [`Binding`] to the [`Keymap`].
+    pub fn push(mut self, binding: Binding<Message>) -> Self {
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Returns the bindings of the [`Keymap`], in the order they were added.
+    ///
+    /// This is useful to list the available shortcuts in a help dialog.
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding<Message>> {
+        self.bindings.iter()
+    }
+
+    /// Returns the bindings that can never be triggered because another
+    /// binding takes precedence over them.
+    ///
+    /// A binding is shadowed by an earlier binding with the same
+    /// [`Shortcut`], or by any binding whose [`Shortcut`] is the beginning of
+    /// its chord.
+    pub fn conflicts(&self) -> impl Iterator<Item = Conflict<'_, Message>> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, first)| {
+                self.bindings[index + 1..].iter().filter_map(move |second| {
+                    let first_strokes = first.shortcut.strokes();
+                    let second_strokes = second.shortcut.strokes();
+
+                    if second_strokes.starts_with(first_strokes) {
+                        Some(Conflict {
+                            binding: first,
+                            shadowed: second,
+                        })
+                    } else if first_strokes.starts_with(second_strokes) {
+                        Some(Conflict {
+                            binding: second,
+                            shadowed: first,
+                        })
+                    } else {
+                        None
+                    }
+                })
+            })
+    }
+
+    /// Processes a new [`Stroke`], returning the message of the binding it
+    /// completes, if any.
+    ///
+    /// The strokes of an unfinished chord are kept in `pending`.
+    fn process(
+        &self,
+        pending: &mut Vec<Stroke>,
+        stroke: Stroke,
+        status: event::Status,
+    ) -> Option<&Message> {
+        if stroke.is_modifier() {
+            return None;
+        }
+
+        let bindings = || {
+            self.bindings.iter().filter(move |binding| {
+                binding.scope == Scope::Global
+                    || status == event::Status::Ignored
+            })
+        };
+
+        pending.push(stroke);
+
+        loop {
+            if let Some(binding) = bindings()
+                .find(|binding| binding.shortcut.strokes() == &pending[..])
+            {
+                pending.clear();
+
+                return Some(&binding.message);
+            }
+
+            if bindings()
+                .any(|binding| binding.shortcut.strokes().starts_with(pending))
+            {
+                return None;
+            }
+
+            if pending.len() == 1 {
+                pending.clear();
+
+                return None;
+            }
+
+            // The chord is broken, but its last stroke may start a new one
+            let _ = pending.drain(..pending.len() - 1);
+        }
+    }
+}
+
+impl<Message> Keymap<Message>
+where
+    Message: Clone + MaybeSend + 'static,
+{
+    /// Turns the [`Keymap`] into a [`Subscription`] that produces the
+    /// message of a binding when its [`Shortcut`] is pressed.
+    ///
+    /// The identity of the [`Subscription`] only depends on the shortcuts
+    /// and scopes of the bindings. If you change their messages while
+    /// keeping the same shortcuts, combine the [`Subscription`] with the
+    /// data of the messages by using [`Subscription::with`].
+    pub fn subscription(self) -> Subscription<Message> {
+        Subscription::from_recipe(Listener { keymap: self })
+    }
+}
+
+impl<Message> Default for Keymap<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Shortcut`] bound to a message in a [`Keymap`].
+#[derive(Debug, Clone)]
+pub struct Binding<Message> {
+    /// The [`Shortcut`] of the [`Binding`].
+    pub shortcut: Shortcut,
+    /// The message produced when the [`Shortcut`] is pressed.
+    pub message: Message,
+    /// The [`Scope`] of the [`Binding`].
+    pub scope: Scope,
+    /// The description of the [`Binding`], if any.
+    pub description: Option<String>,
+}
+
+impl<Message> Binding<Message> {
+    /// Creates a new [`Binding`] of the given [`Shortcut`] to a message.
+    pub fn new(shortcut: Shortcut, message: Message) -> Self {
+        Self {
+            shortcut,
+            message,
+            scope: Scope::default(),
+            description: None,
+        }
+    }
+
+    /// Sets the [`Scope`] of the [`Binding`].
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Sets the description of the [`Binding`], to be shown in a list of
+    /// shortcuts.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// When a [`Binding`] can be triggered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// The [`Binding`] is only triggered by keys that no widget captured.
+    ///
+    /// For instance, keys typed in a focused text input do not trigger it.
+    #[default]
+    Unhandled,
+    /// The [`Binding`] is triggered even by keys captured by a widget.
+    Global,
+}
+
+/// A [`Binding`] of a [`Keymap`] shadowed by another one.
+#[derive(Debug)]
+pub struct Conflict<'a, Message> {
+    /// The [`Binding`] that takes precedence.
+    pub binding: &'a Binding<Message>,
+    /// The [`Binding`] that can never be triggered.
+    pub shadowed: &'a Binding<Message>,
+}
+
+struct Listener<Message> {
+    keymap: Keymap<Message>,
+}
+
+impl<Message> subscription::Recipe for Listener<Message>
+where
+    Message: Clone + MaybeSend + 'static,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        struct Marker;
+
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for binding in &self.keymap.bindings {
+            binding.shortcut.hash(state);
+            binding.scope.hash(state);
+        }
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Message> {
+        use crate::futures::futures::future;
+        use crate::futures::futures::stream::StreamExt;
+
+        let keymap = self.keymap;
+        let mut pending = Vec::new();
+
+        crate::futures::boxed_stream(input.filter_map(
+            move |(event, status)| {
+                let message = match event {
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                    }) => keymap
+                        .process(
+                            &mut pending,
+                            Stroke::new(modifiers, key_code),
+                            status,
+                        )
+                        .cloned(),
+                    _ => None,
+                };
+
+                future::ready(message)
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(
+        keymap: &Keymap<u32>,
+        pending: &mut Vec<Stroke>,
+        stroke: &str,
+        status: event::Status,
+    ) -> Option<u32> {
+        keymap
+            .process(pending, stroke.parse().unwrap(), status)
+            .copied()
+    }
+
+    #[test]
+    fn process_matches_strokes_and_chords() {
+        let keymap = Keymap::new()
+            .bind("Ctrl+S".parse().unwrap(), 1)
+            .bind("Ctrl+K Ctrl+S".parse().unwrap(), 2)
+            .push(
+                Binding::new("Ctrl+P".parse().unwrap(), 3).scope(Scope::Global),
+            );
+
+        let ignored = event::Status::Ignored;
+        let captured = event::Status::Captured;
+        let mut pending = Vec::new();
+
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+S", ignored), Some(1));
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+S", captured), None);
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+P", captured), Some(3));
+
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+K", ignored), None);
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+S", ignored), Some(2));
+        assert!(pending.is_empty());
+
+        // A broken chord lets its last stroke trigger on its own
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+K", ignored), None);
+        assert_eq!(press(&keymap, &mut pending, "Ctrl+P", ignored), Some(3));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn conflicts_lists_shadowed_bindings() {
+        let keymap = Keymap::new()
+            .bind("Ctrl+K Ctrl+S".parse().unwrap(), 1)
+            .bind("Ctrl+K".parse().unwrap(), 2)
+            .bind("Ctrl+P".parse().unwrap(), 3)
+            .bind("Ctrl+P".parse().unwrap(), 4);
+
+        let conflicts: Vec<_> = keymap
+            .conflicts()
+            .map(|conflict| {
+                (conflict.binding.message, conflict.shadowed.message)
+            })
+            .collect();
+
+        assert_eq!(conflicts, [(2, 1), (3, 4)]);
+    }
+}
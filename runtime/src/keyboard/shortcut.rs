@@ -0,0 +1,299 @@
+use crate::core::keyboard::{KeyCode, Modifiers};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A key pressed together with some [`Modifiers`].
+///
+/// A [`Stroke`] can be parsed from strings like `"Ctrl+Shift+P"`. Modifiers
+/// are case-insensitive and can be written as `Ctrl`, `Alt`, `Shift` or
+/// `Super`. `Cmd` stands for [`Modifiers::COMMAND`], which is `Super` on
+/// macOS and `Ctrl` elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stroke {
+    /// The [`Modifiers`] held while the key is pressed.
+    pub modifiers: Modifiers,
+    /// The [`KeyCode`] of the pressed key.
+    pub key_code: KeyCode,
+}
+
+impl Stroke {
+    /// Creates a new [`Stroke`] from the given [`Modifiers`] and [`KeyCode`].
+    pub fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns whether the key of the [`Stroke`] is itself a modifier.
+    pub(crate) fn is_modifier(&self) -> bool {
+        matches!(
+            self.key_code,
+            KeyCode::LShift
+                | KeyCode::RShift
+                | KeyCode::LControl
+                | KeyCode::RControl
+                | KeyCode::LAlt
+                | KeyCode::RAlt
+                | KeyCode::LWin
+                | KeyCode::RWin
+        )
+    }
+}
+
+impl From<KeyCode> for Stroke {
+    fn from(key_code: KeyCode) -> Self {
+        Self::new(Modifiers::empty(), key_code)
+    }
+}
+
+impl fmt::Display for Stroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in MODIFIERS {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        f.write_str(&key_name(self.key_code))
+    }
+}
+
+impl FromStr for Stroke {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('+').map(str::trim);
+        let key = parts.next_back().unwrap_or_default();
+
+        let modifiers =
+            parts.try_fold(Modifiers::empty(), |modifiers, part| {
+                let modifier = match part.to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => Modifiers::CTRL,
+                    "alt" | "option" => Modifiers::ALT,
+                    "shift" => Modifiers::SHIFT,
+                    "super" | "logo" | "meta" | "win" => Modifiers::LOGO,
+                    "cmd" | "command" => Modifiers::COMMAND,
+                    _ => {
+                        return Err(ParseError::UnknownModifier(
+                            part.to_owned(),
+                        ))
+                    }
+                };
+
+                Ok(modifiers | modifier)
+            })?;
+
+        if key.is_empty() {
+            return Err(ParseError::MissingKey(s.to_owned()));
+        }
+
+        let key_code = KEYS
+            .iter()
+            .copied()
+            .find(|&key_code| key_name(key_code).eq_ignore_ascii_case(key))
+            .or_else(|| alias(key))
+            .ok_or_else(|| ParseError::UnknownKey(key.to_owned()))?;
+
+        Ok(Self::new(modifiers, key_code))
+    }
+}
+
+/// A sequence of one or more [`Stroke`]s that triggers an action.
+///
+/// A [`Shortcut`] with more than one [`Stroke`] is a chord: its strokes have
+/// to be pressed one after the other. Chords are parsed from strokes
+/// separated by spaces, like `"Ctrl+K Ctrl+S"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    strokes: Vec<Stroke>,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with the given strokes.
+    ///
+    /// An empty [`Shortcut`] never triggers.
+    pub fn new(strokes: impl IntoIterator<Item = Stroke>) -> Self {
+        Self {
+            strokes: strokes.into_iter().collect(),
+        }
+    }
+
+    /// Returns the strokes of the [`Shortcut`].
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    /// Returns whether the [`Shortcut`] is a chord of several strokes.
+    pub fn is_chord(&self) -> bool {
+        self.strokes.len() > 1
+    }
+}
+
+impl From<Stroke> for Shortcut {
+    fn from(stroke: Stroke) -> Self {
+        Self {
+            strokes: vec![stroke],
+        }
+    }
+}
+
+impl From<KeyCode> for Shortcut {
+    fn from(key_code: KeyCode) -> Self {
+        Self::from(Stroke::from(key_code))
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, stroke) in self.strokes.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{stroke}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let strokes = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if strokes.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Self { strokes })
+    }
+}
+
+/// An error produced when parsing a [`Stroke`] or a [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The shortcut does not contain any stroke.
+    #[error("the shortcut is empty")]
+    Empty,
+    /// A stroke only contains modifiers.
+    #[error("the stroke \"{0}\" has no key")]
+    MissingKey(String),
+    /// A modifier is not recognized.
+    #[error("unknown modifier \"{0}\"")]
+    UnknownModifier(String),
+    /// A key is not recognized.
+    #[error("unknown key \"{0}\"")]
+    UnknownKey(String),
+}
+
+/// The modifiers of a [`Stroke`], in the order they are displayed.
+const MODIFIERS: [(Modifiers, &str); 4] = [
+    (Modifiers::CTRL, "Ctrl"),
+    (Modifiers::ALT, "Alt"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::LOGO, "Super"),
+];
+
+/// The keys that can be parsed by their name.
+#[rustfmt::skip]
+const KEYS: &[KeyCode] = &[
+    KeyCode::Key0, KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4,
+    KeyCode::Key5, KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+    KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F,
+    KeyCode::G, KeyCode::H, KeyCode::I, KeyCode::J, KeyCode::K, KeyCode::L,
+    KeyCode::M, KeyCode::N, KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R,
+    KeyCode::S, KeyCode::T, KeyCode::U, KeyCode::V, KeyCode::W, KeyCode::X,
+    KeyCode::Y, KeyCode::Z,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5,
+    KeyCode::F6, KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10,
+    KeyCode::F11, KeyCode::F12, KeyCode::F13, KeyCode::F14, KeyCode::F15,
+    KeyCode::F16, KeyCode::F17, KeyCode::F18, KeyCode::F19, KeyCode::F20,
+    KeyCode::F21, KeyCode::F22, KeyCode::F23, KeyCode::F24,
+    KeyCode::Escape, KeyCode::Tab, KeyCode::Space, KeyCode::Enter,
+    KeyCode::Backspace, KeyCode::Insert, KeyCode::Delete, KeyCode::Home,
+    KeyCode::End, KeyCode::PageUp, KeyCode::PageDown, KeyCode::Left,
+    KeyCode::Up, KeyCode::Right, KeyCode::Down, KeyCode::Snapshot,
+    KeyCode::Pause,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3,
+    KeyCode::Numpad4, KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7,
+    KeyCode::Numpad8, KeyCode::Numpad9, KeyCode::NumpadAdd,
+    KeyCode::NumpadSubtract, KeyCode::NumpadMultiply, KeyCode::NumpadDivide,
+    KeyCode::NumpadDecimal, KeyCode::NumpadEnter,
+    KeyCode::Plus, KeyCode::Minus, KeyCode::Equals, KeyCode::Comma,
+    KeyCode::Period, KeyCode::Slash, KeyCode::Backslash, KeyCode::Semicolon,
+    KeyCode::Apostrophe, KeyCode::Grave, KeyCode::LBracket, KeyCode::RBracket,
+    KeyCode::Copy, KeyCode::Cut, KeyCode::Paste,
+];
+
+/// Returns the name of a key, which is the name of its [`KeyCode`] without
+/// the `Key` prefix of digits.
+fn key_name(key_code: KeyCode) -> String {
+    let name = format!("{key_code:?}");
+
+    match name.strip_prefix("Key") {
+        Some(digit) => digit.to_owned(),
+        None => name,
+    }
+}
+
+/// Returns the key with the given alternative name, if any.
+fn alias(name: &str) -> Option<KeyCode> {
+    let key_code = match name.to_ascii_lowercase().as_str() {
+        "esc" => KeyCode::Escape,
+        "return" => KeyCode::Enter,
+        "del" => KeyCode::Delete,
+        "ins" => KeyCode::Insert,
+        "pgup" => KeyCode::PageUp,
+        "pgdn" | "pgdown" => KeyCode::PageDown,
+        "printscreen" => KeyCode::Snapshot,
+        _ => return None,
+    };
+
+    Some(key_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcut_parses_strokes_and_chords() {
+        let shortcut: Shortcut = "ctrl+shift+p".parse().unwrap();
+        assert_eq!(
+            shortcut.strokes(),
+            [Stroke::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::P)]
+        );
+        assert_eq!(shortcut.to_string(), "Ctrl+Shift+P");
+
+        let chord: Shortcut = "Ctrl+K  Ctrl+1".parse().unwrap();
+        assert!(chord.is_chord());
+        assert_eq!(chord.to_string(), "Ctrl+K Ctrl+1");
+
+        let escape: Shortcut = "Esc".parse().unwrap();
+        assert_eq!(escape, Shortcut::from(KeyCode::Escape));
+    }
+
+    #[test]
+    fn shortcut_rejects_invalid_strings() {
+        assert_eq!("  ".parse::<Shortcut>(), Err(ParseError::Empty));
+        assert_eq!(
+            "Ctrl+".parse::<Shortcut>(),
+            Err(ParseError::MissingKey("Ctrl+".to_owned()))
+        );
+        assert_eq!(
+            "Hyper+A".parse::<Shortcut>(),
+            Err(ParseError::UnknownModifier("Hyper".to_owned()))
+        );
+        assert_eq!(
+            "Ctrl+Foo".parse::<Shortcut>(),
+            Err(ParseError::UnknownKey("Foo".to_owned()))
+        );
+    }
+}
@@ -43,9 +43,11 @@
 )]
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod animation;
 pub mod clipboard;
 pub mod command;
 pub mod font;
+pub mod headless;
 pub mod keyboard;
 pub mod program;
 pub mod system;
@@ -2,6 +2,7 @@
 use iced_core::widget::{Operation, OperationOutputWrapper};
 
 use crate::core::event::{self, Event};
+use crate::core::keyboard::InputMethod;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -186,6 +187,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay =
             ManuallyDrop::new(self.root.as_widget_mut().overlay(
@@ -225,6 +227,10 @@ where
                     _ => {}
                 }
 
+                if shell.input_method().is_enabled() {
+                    input_method = shell.input_method();
+                }
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -314,6 +320,10 @@ where
                     _ => {}
                 }
 
+                if shell.input_method().is_enabled() {
+                    input_method = shell.input_method();
+                }
+
                 shell.revalidate_layout(|| {
                     self.base = renderer.layout(
                         &self.root,
@@ -335,7 +345,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -621,5 +634,8 @@ pub enum State {
     Updated {
         /// The [`Instant`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] state requested by the widgets.
+        input_method: InputMethod,
     },
 }
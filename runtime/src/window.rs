@@ -1,12 +1,15 @@
 //! Build window-based GUI applications.
 mod action;
 
+pub mod screenshot;
+
 pub use crate::core::window::Id;
 pub use action::Action;
+pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
 use crate::core::time::Instant;
-use crate::core::window::{Event, Icon, Level, Mode, UserAttention};
+use crate::core::window::{Event, Icon, Level, Mode, Settings, UserAttention};
 use crate::futures::subscription::{self, Subscription};
 
 /// Subscribes to the frames of the window of the running application.
@@ -26,56 +29,79 @@ pub fn frames() -> Subscription<(Id, Instant)> {
     })
 }
 
-/// Closes the current window and exits the application.
-pub fn close<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::Close))
+/// Opens a new window with the given [`Settings`].
+///
+/// The [`Id`] of the new window is returned right away, so it can be used to
+/// route its view and events. Opening several windows needs a shell with
+/// multi-window support.
+pub fn spawn<Message>(settings: impl Into<Settings>) -> (Id, Command<Message>) {
+    let id = Id::unique();
+
+    (
+        id,
+        Command::single(command::Action::Window(
+            id,
+            Action::Spawn(settings.into()),
+        )),
+    )
+}
+
+/// Closes the window.
+///
+/// The application exits once all of its windows are closed.
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::Close))
 }
 
 /// Begins dragging the window while the left mouse button is held.
-pub fn drag<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::Drag))
+pub fn drag<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::Drag))
 }
 
 /// Resizes the window to the given logical dimensions.
-pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Resize { width, height }))
+pub fn resize<Message>(id: Id, width: u32, height: u32) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        Action::Resize { width, height },
+    ))
 }
 
 /// Maximizes the window.
-pub fn maximize<Message>(maximized: bool) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Maximize(maximized)))
+pub fn maximize<Message>(id: Id, maximized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::Maximize(maximized)))
 }
 
 /// Minimes the window.
-pub fn minimize<Message>(minimized: bool) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Minimize(minimized)))
+pub fn minimize<Message>(id: Id, minimized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::Minimize(minimized)))
 }
 
 /// Moves a window to the given logical coordinates.
-pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Move { x, y }))
+pub fn move_to<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::Move { x, y }))
 }
 
 /// Changes the [`Mode`] of the window.
-pub fn change_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeMode(mode)))
+pub fn change_mode<Message>(id: Id, mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::ChangeMode(mode)))
 }
 
 /// Fetches the current [`Mode`] of the window.
 pub fn fetch_mode<Message>(
+    id: Id,
     f: impl FnOnce(Mode) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::FetchMode(Box::new(f))))
+    Command::single(command::Action::Window(id, Action::FetchMode(Box::new(f))))
 }
 
 /// Toggles the window to maximized or back.
-pub fn toggle_maximize<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::ToggleMaximize))
+pub fn toggle_maximize<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::ToggleMaximize))
 }
 
 /// Toggles the window decorations.
-pub fn toggle_decorations<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::ToggleDecorations))
+pub fn toggle_decorations<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::ToggleDecorations))
 }
 
 /// Request user attention to the window, this has no effect if the application
@@ -85,11 +111,13 @@ pub fn toggle_decorations<Message>() -> Command<Message> {
 /// Providing `None` will unset the request for user attention. Unsetting the request for
 /// user attention might not be done automatically by the WM when the window receives input.
 pub fn request_user_attention<Message>(
+    id: Id,
     user_attention: Option<UserAttention>,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::RequestUserAttention(
-        user_attention,
-    )))
+    Command::single(command::Action::Window(
+        id,
+        Action::RequestUserAttention(user_attention),
+    ))
 }
 
 /// Brings the window to the front and sets input focus. Has no effect if the window is
@@ -98,23 +126,35 @@ pub fn request_user_attention<Message>(
 /// This [`Command`] steals input focus from other applications. Do not use this method unless
 /// you are certain that's what the user wants. Focus stealing can cause an extremely disruptive
 /// user experience.
-pub fn gain_focus<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::GainFocus))
+pub fn gain_focus<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::GainFocus))
 }
 
 /// Changes the window [`Level`].
-pub fn change_level<Message>(level: Level) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeLevel(level)))
+pub fn change_level<Message>(id: Id, level: Level) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::ChangeLevel(level)))
 }
 
 /// Fetches an identifier unique to the window.
 pub fn fetch_id<Message>(
+    id: Id,
     f: impl FnOnce(u64) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::FetchId(Box::new(f))))
+    Command::single(command::Action::Window(id, Action::FetchId(Box::new(f))))
 }
 
 /// Changes the [`Icon`] of the window.
-pub fn change_icon<Message>(icon: Icon) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeIcon(icon)))
+pub fn change_icon<Message>(id: Id, icon: Icon) -> Command<Message> {
+    Command::single(command::Action::Window(id, Action::ChangeIcon(icon)))
+}
+
+/// Captures a [`Screenshot`] of the last frame rendered in the window.
+pub fn screenshot<Message>(
+    id: Id,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        Action::Screenshot(Box::new(f)),
+    ))
 }
@@ -1,11 +1,18 @@
-use crate::core::window::{Icon, Level, Mode, UserAttention};
+use crate::core::window::{Icon, Level, Mode, Settings, UserAttention};
 use crate::futures::MaybeSend;
+use crate::window::Screenshot;
 
 use std::fmt;
 
 /// An operation to be performed on some window.
 pub enum Action<T> {
-    /// Close the current window and exits the application.
+    /// Open a new window with the given [`Settings`].
+    ///
+    /// The new window takes the identifier the [`Action`] is targeted at.
+    Spawn(Settings),
+    /// Close the window.
+    ///
+    /// The application exits once all of its windows are closed.
     Close,
     /// Move the window with the left mouse button until the button is
     /// released.
@@ -89,6 +96,8 @@ pub enum Action<T> {
     /// - **X11:** Has no universal guidelines for icon sizes, so you're at the whims of the WM. That
     ///   said, it's usually in the same ballpark as on Windows.
     ChangeIcon(Icon),
+    /// Screenshot the viewport of the window.
+    Screenshot(Box<dyn FnOnce(Screenshot) -> T + 'static>),
 }
 
 impl<T> Action<T> {
@@ -101,6 +110,7 @@ impl<T> Action<T> {
         T: 'static,
     {
         match self {
+            Self::Spawn(settings) => Action::Spawn(settings),
             Self::Close => Action::Close,
             Self::Drag => Action::Drag,
             Self::Resize { width, height } => Action::Resize { width, height },
@@ -118,6 +128,11 @@ impl<T> Action<T> {
             Self::ChangeLevel(level) => Action::ChangeLevel(level),
             Self::FetchId(o) => Action::FetchId(Box::new(move |s| f(o(s)))),
             Self::ChangeIcon(icon) => Action::ChangeIcon(icon),
+            Self::Screenshot(tag) => {
+                Action::Screenshot(Box::new(move |screenshot| {
+                    f(tag(screenshot))
+                }))
+            }
         }
     }
 }
@@ -125,6 +140,7 @@ impl<T> Action<T> {
 impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Spawn(settings) => write!(f, "Action::Spawn({settings:?})"),
             Self::Close => write!(f, "Action::Close"),
             Self::Drag => write!(f, "Action::Drag"),
             Self::Resize { width, height } => write!(
@@ -155,6 +171,7 @@ impl<T> fmt::Debug for Action<T> {
             Self::ChangeIcon(_icon) => {
                 write!(f, "Action::ChangeIcon(icon)")
             }
+            Self::Screenshot(_) => write!(f, "Action::Screenshot"),
         }
     }
 }
@@ -0,0 +1,58 @@
+//! Take screenshots of a window.
+use crate::core::Size;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Data of a screenshot, captured with `window::screenshot()`.
+///
+/// The `bytes` of this screenshot will always be ordered as `RGBA` in the
+/// sRGB color space.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The bytes of the [`Screenshot`].
+    pub bytes: Arc<Vec<u8>>,
+    /// The size of the [`Screenshot`] in physical pixels.
+    pub size: Size<u32>,
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Screenshot")
+            .field("bytes", &self.bytes.len())
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`].
+    pub fn new(bytes: Vec<u8>, size: Size<u32>) -> Self {
+        Self {
+            bytes: Arc::new(bytes),
+            size,
+        }
+    }
+
+    /// Returns the color of the pixel at the given physical coordinates, as
+    /// `[r, g, b, a]`.
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+
+        let index = (y as usize * self.size.width as usize + x as usize) * 4;
+
+        self.bytes
+            .get(index..index + 4)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+    }
+}
+
+impl AsRef<[u8]> for Screenshot {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
@@ -62,7 +62,10 @@ use iced_runtime::{
             wayland::{data_device::DndIcon, popup},
         },
     },
-    core::{mouse::Interaction, Color, Point, Renderer, Size},
+    core::{
+        keyboard::InputMethod, mouse::Interaction, Color, Point, Rectangle,
+        Renderer, Size,
+    },
     system, user_interface,
     window::Id as SurfaceId,
     Command, Debug, Program, UserInterface,
@@ -91,6 +94,8 @@ pub enum Event<Message> {
     DataDevice(platform_specific::wayland::data_device::Action<Message>),
     /// request sctk to set the cursor of the active pointer
     SetCursor(Interaction),
+    /// request sctk to set the input method of the active seat
+    SetInputMethod(InputMethod),
     /// Application Message
     Message(Message),
 }
@@ -385,6 +390,7 @@ where
     let natural_scroll = application.natural_scroll();
 
     let mut mouse_interaction = Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut sctk_events: Vec<SctkEvent> = Vec::new();
     #[cfg(feature = "a11y")]
     let mut a11y_events: Vec<crate::sctk_event::ActionRequestEvent> =
@@ -464,7 +470,8 @@ where
                         }
                         KeyboardEventVariant::Press(_)
                         | KeyboardEventVariant::Release(_)
-                        | KeyboardEventVariant::Repeat(_) => {}
+                        | KeyboardEventVariant::Repeat(_)
+                        | KeyboardEventVariant::Ime(_) => {}
                         KeyboardEventVariant::Modifiers(mods) => {
                             if let Some(state) = kbd_surface_id
                                 .as_ref()
@@ -1107,6 +1114,69 @@ where
                             ),
                         );
 
+                        if let Some(user_interface) =
+                            interfaces.get_mut(&surface_id.inner())
+                        {
+                            let (interface_state, _) = user_interface.update(
+                                &[redraw_event.clone()],
+                                state.cursor(),
+                                &mut renderer,
+                                &mut simple_clipboard,
+                                &mut messages,
+                            );
+
+                            // Keep any rebuild or earlier redraw requested by
+                            // the event pass of this surface
+                            state.interface_state = match (
+                                state.interface_state,
+                                interface_state,
+                            ) {
+                                (user_interface::State::Outdated, _)
+                                | (_, user_interface::State::Outdated) => {
+                                    user_interface::State::Outdated
+                                }
+                                (
+                                    user_interface::State::Updated {
+                                        redraw_request: previous,
+                                        ..
+                                    },
+                                    user_interface::State::Updated {
+                                        redraw_request,
+                                        input_method,
+                                    },
+                                ) => user_interface::State::Updated {
+                                    redraw_request: match (
+                                        previous,
+                                        redraw_request,
+                                    ) {
+                                        (Some(a), Some(b)) => Some(a.min(b)),
+                                        (a, b) => a.or(b),
+                                    },
+                                    input_method,
+                                },
+                            };
+                        }
+
+                        // Only the surface with keyboard focus may drive the
+                        // input method of the active seat
+                        if kbd_surface_id.as_ref() == Some(object_id) {
+                            if let user_interface::State::Updated {
+                                input_method: new_input_method,
+                                ..
+                            } = state.interface_state
+                            {
+                                let new_input_method =
+                                    state.scale_input_method(new_input_method);
+
+                                if new_input_method != input_method {
+                                    input_method = new_input_method;
+                                    ev_proxy.send_event(Event::SetInputMethod(
+                                        input_method,
+                                    ));
+                                }
+                            }
+                        }
+
                         runtime.broadcast(redraw_event, Status::Ignored);
 
                         ev_proxy.send_event(Event::SctkEvent(
@@ -1118,6 +1188,7 @@ where
                                 .start_send(match state.interface_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => {
                                     match redraw_request {
                                     crate::core::window::RedrawRequest::NextFrame => {
@@ -1660,6 +1731,29 @@ where
             .unwrap_or(mouse::Cursor::Unavailable)
     }
 
+    /// Converts the text cursor of the given [`InputMethod`] to the
+    /// coordinates of the surface of the [`State`].
+    pub fn scale_input_method(
+        &self,
+        input_method: InputMethod,
+    ) -> InputMethod {
+        match input_method {
+            InputMethod::Enabled { cursor } => {
+                let scale_factor = self.application_scale_factor as f32;
+
+                InputMethod::Enabled {
+                    cursor: Rectangle {
+                        x: cursor.x * scale_factor,
+                        y: cursor.y * scale_factor,
+                        width: cursor.width * scale_factor,
+                        height: cursor.height * scale_factor,
+                    },
+                }
+            }
+            InputMethod::Disabled => InputMethod::Disabled,
+        }
+    }
+
     /// Returns the current keyboard modifiers of the [`State`].
     pub fn modifiers(&self) -> Modifiers {
         self.modifiers
@@ -12,6 +12,7 @@ use crate::{
     dpi::LogicalSize,
     handlers::{
         wp_fractional_scaling::FractionalScalingManager,
+        wp_text_input::{self, TextInputManager},
         wp_viewporter::ViewporterState,
     },
     sctk_event::{
@@ -162,6 +163,14 @@ where
                 }
             };
 
+        let text_input_manager = match TextInputManager::new(&globals, &qh) {
+            Ok(m) => Some(m),
+            Err(e) => {
+                error!("Failed to initialize text input manager: {}", e);
+                None
+            }
+        };
+
         Ok(Self {
             event_loop,
             wayland_dispatcher,
@@ -203,6 +212,7 @@ where
                 dnd_offer: None,
                 selection_offer: None,
                 fractional_scaling_manager,
+                text_input_manager,
                 viewporter_state,
                 compositor_updates: Default::default(),
             },
@@ -745,6 +755,15 @@ where
                         }
 
                     }
+                    Event::SetInputMethod(input_method) => {
+                        if let Some(seat) = self.state.seats.get_mut(0) {
+                            seat.input_method = input_method;
+
+                            if let Some(text_input) = seat.text_input.as_ref() {
+                                wp_text_input::update_text_input(text_input, input_method);
+                            }
+                        }
+                    }
                     Event::Window(action) => match action {
                         platform_specific::wayland::window::Action::Window { builder, _phantom } => {
                             #[cfg(feature = "a11y")]
@@ -9,7 +9,7 @@ use crate::{
     dpi::LogicalSize,
     handlers::{
         wp_fractional_scaling::FractionalScalingManager,
-        wp_viewporter::ViewporterState,
+        wp_text_input::TextInputManager, wp_viewporter::ViewporterState,
     },
     sctk_event::{
         LayerSurfaceEventVariant, PopupEventVariant, SctkEvent,
@@ -27,7 +27,7 @@ use iced_runtime::{
             window::SctkWindowSettings,
         },
     },
-    keyboard::Modifiers,
+    keyboard::{InputMethod, Modifiers},
     window,
 };
 use sctk::{
@@ -75,6 +75,7 @@ use sctk::{
 };
 use wayland_protocols::wp::{
     fractional_scale::v1::client::wp_fractional_scale_v1::WpFractionalScaleV1,
+    text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3,
     viewporter::client::wp_viewport::WpViewport,
 };
 
@@ -91,6 +92,8 @@ pub(crate) struct SctkSeat {
     pub(crate) _modifiers: Modifiers,
     pub(crate) data_device: DataDevice,
     pub(crate) icon: Option<CursorIcon>,
+    pub(crate) text_input: Option<ZwpTextInputV3>,
+    pub(crate) input_method: InputMethod,
 }
 
 #[derive(Debug, Clone)]
@@ -326,6 +329,7 @@ pub struct SctkState<T> {
     /// Viewporter state on the given window.
     pub viewporter_state: Option<ViewporterState<T>>,
     pub(crate) fractional_scaling_manager: Option<FractionalScalingManager<T>>,
+    pub(crate) text_input_manager: Option<TextInputManager<T>>,
     pub(crate) registry_state: RegistryState,
     pub(crate) seat_state: SeatState,
     pub(crate) output_state: OutputState,
@@ -5,6 +5,7 @@ pub mod output;
 pub mod seat;
 pub mod shell;
 pub mod wp_fractional_scaling;
+pub mod wp_text_input;
 pub mod wp_viewporter;
 
 use sctk::{
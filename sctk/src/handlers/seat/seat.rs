@@ -2,7 +2,7 @@ use crate::{
     event_loop::{state::SctkSeat, state::SctkState},
     sctk_event::{KeyboardEventVariant, SctkEvent, SeatEventVariant},
 };
-use iced_runtime::keyboard::Modifiers;
+use iced_runtime::keyboard::{InputMethod, Modifiers};
 use sctk::{
     delegate_seat,
     reexports::client::{protocol::wl_keyboard::WlKeyboard, Proxy},
@@ -42,6 +42,8 @@ where
             last_ptr_press: None,
             last_kbd_press: None,
             icon: None,
+            text_input: None,
+            input_method: InputMethod::Disabled,
         });
     }
 
@@ -69,6 +71,8 @@ where
                     last_ptr_press: None,
                     last_kbd_press: None,
                     icon: None,
+                    text_input: None,
+                    input_method: InputMethod::Disabled,
                 });
                 self.seats.last_mut().unwrap()
             }
@@ -98,6 +102,11 @@ where
                         id: seat.clone(),
                     });
                     my_seat.kbd.replace(kbd);
+
+                    my_seat.text_input = self
+                        .text_input_manager
+                        .as_ref()
+                        .map(|m| m.text_input(&seat, qh));
                 }
             }
             sctk::seat::Capability::Pointer => {
@@ -152,6 +161,9 @@ where
                         id: seat.clone(),
                     });
                 }
+                if let Some(text_input) = my_seat.text_input.take() {
+                    text_input.destroy();
+                }
             }
             sctk::seat::Capability::Pointer => {
                 if let Some(ptr) = my_seat.ptr.take() {
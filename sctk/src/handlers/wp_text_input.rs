@@ -0,0 +1,201 @@
+//! Handling of the text-input-v3 protocol, used by input methods.
+
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use iced_runtime::keyboard::{Ime, InputMethod};
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::Dispatch;
+use sctk::reexports::client::{
+    delegate_dispatch, Connection, Proxy, QueueHandle,
+};
+use wayland_protocols::wp::text_input::zv3::client::zwp_text_input_manager_v3::ZwpTextInputManagerV3;
+use wayland_protocols::wp::text_input::zv3::client::zwp_text_input_v3::{
+    ContentHint, ContentPurpose, Event as TextInputEvent, ZwpTextInputV3,
+};
+
+use sctk::globals::GlobalData;
+
+use crate::event_loop::state::SctkState;
+use crate::sctk_event::{KeyboardEventVariant, SctkEvent};
+
+/// Text input manager.
+#[derive(Debug)]
+pub struct TextInputManager<T> {
+    manager: ZwpTextInputManagerV3,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: 'static> TextInputManager<T> {
+    /// Create new text input manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self {
+            manager,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Get the text input for the given seat.
+    pub fn text_input(
+        &self,
+        seat: &WlSeat,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> ZwpTextInputV3 {
+        self.manager.get_text_input(
+            seat,
+            queue_handle,
+            TextInputData::default(),
+        )
+    }
+}
+
+/// The state of a text input, accumulated until the compositor is done
+/// sending a batch of events.
+#[derive(Debug, Default)]
+pub struct TextInputData {
+    inner: Mutex<TextInputDataInner>,
+}
+
+#[derive(Debug, Default)]
+struct TextInputDataInner {
+    surface: Option<WlSurface>,
+    pending_preedit: Option<(String, Option<(usize, usize)>)>,
+    pending_commit: Option<String>,
+}
+
+/// Applies the requested [`InputMethod`] to the given text input.
+pub fn update_text_input(
+    text_input: &ZwpTextInputV3,
+    input_method: InputMethod,
+) {
+    match input_method {
+        InputMethod::Enabled { cursor } => {
+            text_input.enable();
+            text_input
+                .set_content_type(ContentHint::None, ContentPurpose::Normal);
+            text_input.set_cursor_rectangle(
+                cursor.x as i32,
+                cursor.y as i32,
+                cursor.width.ceil() as i32,
+                cursor.height.ceil() as i32,
+            );
+        }
+        InputMethod::Disabled => {
+            text_input.disable();
+        }
+    }
+
+    text_input.commit();
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputManagerV3, GlobalData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        _: &mut SctkState<T>,
+        _: &ZwpTextInputManagerV3,
+        _: <ZwpTextInputManagerV3 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        // No events.
+    }
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputV3, TextInputData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        state: &mut SctkState<T>,
+        text_input: &ZwpTextInputV3,
+        event: <ZwpTextInputV3 as Proxy>::Event,
+        data: &TextInputData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        let seat = match state
+            .seats
+            .iter()
+            .find(|s| s.text_input.as_ref() == Some(text_input))
+        {
+            Some(s) => s,
+            None => return,
+        };
+        let mut inner = data.inner.lock().unwrap();
+
+        match event {
+            TextInputEvent::Enter { surface } => {
+                inner.surface = Some(surface);
+
+                if seat.input_method.is_enabled() {
+                    update_text_input(text_input, seat.input_method);
+                }
+            }
+            TextInputEvent::Leave { .. } => {
+                inner.surface = None;
+
+                text_input.disable();
+                text_input.commit();
+            }
+            TextInputEvent::PreeditString {
+                text,
+                cursor_begin,
+                cursor_end,
+            } => {
+                let cursor = if cursor_begin < 0 || cursor_end < 0 {
+                    None
+                } else {
+                    Some((cursor_begin as usize, cursor_end as usize))
+                };
+
+                inner.pending_preedit =
+                    Some((text.unwrap_or_default(), cursor));
+            }
+            TextInputEvent::CommitString { text } => {
+                inner.pending_commit = Some(text.unwrap_or_default());
+            }
+            TextInputEvent::Done { .. } => {
+                let (kbd_id, seat_id) = match (&seat.kbd, &inner.surface) {
+                    (Some(kbd), Some(_)) => (kbd.clone(), seat.seat.clone()),
+                    _ => return,
+                };
+
+                // The pending preedit replaces the current one, if any, and
+                // it must be cleared before any text is committed.
+                let mut events = vec![Ime::Preedit {
+                    content: String::new(),
+                    cursor: None,
+                }];
+
+                if let Some(text) = inner.pending_commit.take() {
+                    events.push(Ime::Commit(text));
+                }
+
+                if let Some((content, cursor)) = inner.pending_preedit.take() {
+                    if !content.is_empty() {
+                        events.push(Ime::Preedit { content, cursor });
+                    }
+                }
+
+                state.sctk_events.extend(events.into_iter().map(|ime| {
+                    SctkEvent::KeyboardEvent {
+                        variant: KeyboardEventVariant::Ime(ime),
+                        kbd_id: kbd_id.clone(),
+                        seat_id: seat_id.clone(),
+                    }
+                }));
+            }
+            _ => {}
+        }
+    }
+}
+
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputManagerV3: GlobalData] => TextInputManager<T>);
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputV3: TextInputData] => TextInputManager<T>);
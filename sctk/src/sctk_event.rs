@@ -282,6 +282,7 @@ pub enum KeyboardEventVariant {
     Repeat(KeyEvent),
     Release(KeyEvent),
     Modifiers(Modifiers),
+    Ime(keyboard::Ime),
 }
 
 #[derive(Debug, Clone)]
@@ -628,6 +629,11 @@ impl SctkEvent {
                         )),
                     )]
                 }
+                KeyboardEventVariant::Ime(ime) => {
+                    vec![iced_runtime::core::Event::Keyboard(
+                        keyboard::Event::Ime(ime),
+                    )]
+                }
             },
             SctkEvent::WindowEvent {
                 variant,
@@ -195,6 +195,9 @@ pub mod application;
 #[cfg(feature = "winit")]
 mod sandbox;
 
+#[cfg(all(feature = "winit", feature = "multi-window"))]
+pub mod multi_window;
+
 /// wayland application
 #[cfg(feature = "wayland")]
 pub mod wayland;
@@ -218,6 +221,11 @@ pub use crate::core::{
 };
 pub use crate::runtime::Command;
 
+pub mod animation {
+    //! Animate values over time.
+    pub use crate::runtime::animation::*;
+}
+
 pub mod clipboard {
     //! Access the clipboard.
     pub use crate::runtime::clipboard::{read, write};
@@ -249,6 +257,9 @@ pub mod font {
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::{Event, KeyCode, Modifiers};
+    pub use crate::runtime::keyboard::{
+        Binding, Conflict, Keymap, ParseError, Scope, Shortcut, Stroke,
+    };
 }
 
 pub mod mouse {
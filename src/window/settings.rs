@@ -1,3 +1,4 @@
+use crate::core;
 use crate::window::{Icon, Level, Position};
 
 pub use iced_winit::settings::PlatformSpecific;
@@ -61,7 +62,7 @@ impl From<Settings> for iced_winit::settings::Window {
     fn from(settings: Settings) -> Self {
         Self {
             size: settings.size,
-            position: iced_winit::Position::from(settings.position),
+            position: settings.position,
             min_size: settings.min_size,
             max_size: settings.max_size,
             visible: settings.visible,
@@ -74,3 +75,20 @@ impl From<Settings> for iced_winit::settings::Window {
         }
     }
 }
+
+impl From<Settings> for core::window::Settings {
+    fn from(settings: Settings) -> Self {
+        Self {
+            size: settings.size,
+            position: settings.position,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            level: settings.level,
+            icon: settings.icon,
+        }
+    }
+}
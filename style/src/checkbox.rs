@@ -1,4 +1,5 @@
 //! Change the appearance of a checkbox.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a checkbox.
@@ -18,6 +19,21 @@ pub struct Appearance {
     pub text_color: Option<Color>,
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            icon_color: self.icon_color.interpolate(&other.icon_color, t),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, t),
+            border_width: self.border_width.interpolate(&other.border_width, t),
+            border_color: self.border_color.interpolate(&other.border_color, t),
+            text_color: self.text_color.interpolate(&other.text_color, t),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
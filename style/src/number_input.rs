@@ -0,0 +1,40 @@
+//! Change the appearance of a number input.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of the step buttons of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the button.
+    pub background: Option<Background>,
+    /// The [`Color`] of the icon of the button.
+    pub icon_color: Color,
+    /// The border radius of the button.
+    pub border_radius: BorderRadius,
+    /// The border width of the button.
+    pub border_width: f32,
+    /// The border [`Color`] of the button.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a number input.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a step button.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a step button.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the pressed [`Appearance`] of a step button.
+    fn pressed(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a step button that cannot step any
+    /// further.
+    fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the border drawn around a number input
+    /// whose text is not a valid value.
+    fn invalid_border_color(&self, style: &Self::Style) -> Color;
+}
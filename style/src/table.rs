@@ -0,0 +1,38 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the rows of the table.
+    pub text_color: Color,
+    /// The [`Background`] of the header of the table.
+    pub header_background: Background,
+    /// The text [`Color`] of the header of the table.
+    pub header_text_color: Color,
+    /// The [`Background`] of the rows of the table, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row of the table, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row of the table, if any.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row of the table.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of a selected row of the table.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the dividers between the columns of the table.
+    pub divider_color: Color,
+    /// The [`Color`] of a divider that is hovered or dragged.
+    pub active_divider_color: Color,
+    /// The width of the dividers between the columns of the table.
+    pub divider_width: f32,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,51 @@
+//! Change the appearance of a tab bar.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tab, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+    /// The [`Background`] of the close button of the tab when hovered, if
+    /// any.
+    pub close_hovered_background: Option<Background>,
+    /// The border radius of the tab.
+    pub border_radius: BorderRadius,
+    /// The border width of the tab.
+    pub border_width: f32,
+    /// The border [`Color`] of the tab.
+    pub border_color: Color,
+}
+
+/// The appearance of a tab bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Bar {
+    /// The [`Background`] of the tab bar, if any.
+    pub background: Option<Background>,
+    /// The [`Color`] of the line below the tab bar.
+    pub border_color: Color,
+    /// The width of the line below the tab bar.
+    pub border_width: f32,
+    /// The [`Color`] of the indicator showing where a dragged tab will be
+    /// dropped.
+    pub drop_indicator: Color,
+}
+
+/// A set of rules that dictate the style of a tab bar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Bar`] appearance of a tab bar.
+    fn bar(&self, style: &Self::Style) -> Bar;
+
+    /// Produces the active [`Appearance`] of a tab.
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a tab.
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+}
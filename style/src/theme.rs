@@ -10,6 +10,8 @@ use crate::checkbox;
 use crate::container;
 use crate::core::widget::text;
 use crate::menu;
+use crate::modal;
+use crate::number_input;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
@@ -18,8 +20,13 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
+use crate::tabs;
+use crate::text_editor;
 use crate::text_input;
+use crate::toast;
 use crate::toggler;
+use crate::tree_view;
 
 use iced_core::{Background, Color, Vector};
 
@@ -528,6 +535,115 @@ impl From<PickList> for Menu {
     }
 }
 
+/// The style of a modal.
+#[derive(Default)]
+pub enum Modal {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn modal::StyleSheet<Style = Theme>>),
+}
+
+impl modal::StyleSheet for Theme {
+    type Style = Modal;
+
+    fn appearance(&self, style: &Self::Style) -> modal::Appearance {
+        match style {
+            Modal::Default => modal::Appearance {
+                backdrop: Color {
+                    a: 0.8,
+                    ..Color::BLACK
+                }
+                .into(),
+            },
+            Modal::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a number input.
+#[derive(Default)]
+pub enum NumberInput {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn number_input::StyleSheet<Style = Theme>>),
+}
+
+impl number_input::StyleSheet for Theme {
+    type Style = NumberInput;
+
+    fn active(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            background: Some(palette.background.weak.color.into()),
+            icon_color: palette.background.weak.text,
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            background: Some(palette.background.strong.color.into()),
+            icon_color: palette.background.strong.text,
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            background: Some(palette.primary.weak.color.into()),
+            icon_color: palette.primary.weak.text,
+            ..self.active(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        number_input::Appearance {
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                ..active.icon_color
+            },
+            ..active
+        }
+    }
+
+    fn invalid_border_color(&self, style: &Self::Style) -> Color {
+        if let NumberInput::Custom(custom) = style {
+            return custom.invalid_border_color(self);
+        }
+
+        self.extended_palette().danger.base.color
+    }
+}
+
 /// The style of a pick list.
 #[derive(Clone, Default)]
 pub enum PickList {
@@ -705,6 +821,207 @@ impl toggler::StyleSheet for Theme {
     }
 }
 
+/// The style of a toast.
+#[derive(Default)]
+pub enum Toast {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn toast::StyleSheet<Style = Theme>>),
+}
+
+impl toast::StyleSheet for Theme {
+    type Style = Toast;
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        severity: toast::Severity,
+    ) -> toast::Appearance {
+        match style {
+            Toast::Default => {
+                let palette = self.extended_palette();
+
+                let accent_color = match severity {
+                    toast::Severity::Info => palette.primary.base.color,
+                    toast::Severity::Success => palette.success.base.color,
+                    toast::Severity::Warning => {
+                        Color::from_rgb8(0xE5, 0xA5, 0x0A)
+                    }
+                    toast::Severity::Error => palette.danger.base.color,
+                };
+
+                toast::Appearance {
+                    background: palette.background.base.color.into(),
+                    accent_color,
+                    text_color: palette.background.base.text,
+                    close_color: palette.background.strong.color,
+                    border_radius: 4.0.into(),
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                }
+            }
+            Toast::Custom(custom) => custom.active(self, severity),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        severity: toast::Severity,
+    ) -> toast::Appearance {
+        match style {
+            Toast::Default => {
+                let active = self.active(style, severity);
+
+                toast::Appearance {
+                    close_color: active.text_color,
+                    ..active
+                }
+            }
+            Toast::Custom(custom) => custom.hovered(self, severity),
+        }
+    }
+}
+
+/// The style of a tab bar.
+#[derive(Default)]
+pub enum Tabs {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tabs::StyleSheet<Style = Theme>>),
+}
+
+impl tabs::StyleSheet for Theme {
+    type Style = Tabs;
+
+    fn bar(&self, style: &Self::Style) -> tabs::Bar {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                tabs::Bar {
+                    background: Some(palette.background.weak.color.into()),
+                    border_color: palette.background.strong.color,
+                    border_width: 1.0,
+                    drop_indicator: palette.primary.strong.color,
+                }
+            }
+            Tabs::Custom(custom) => custom.bar(self),
+        }
+    }
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> tabs::Appearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                let (background, text_color) = if is_selected {
+                    (
+                        Some(palette.background.base.color.into()),
+                        palette.background.base.text,
+                    )
+                } else {
+                    (None, palette.background.weak.text)
+                };
+
+                tabs::Appearance {
+                    background,
+                    text_color,
+                    close_color: palette.background.strong.color,
+                    close_hovered_background: Some(
+                        palette.background.strong.color.into(),
+                    ),
+                    border_radius: [4.0, 4.0, 0.0, 0.0].into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }
+            }
+            Tabs::Custom(custom) => custom.active(self, is_selected),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> tabs::Appearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+                let active = self.active(style, is_selected);
+
+                tabs::Appearance {
+                    background: active
+                        .background
+                        .or(Some(palette.background.strong.color.into())),
+                    close_color: palette.background.base.text,
+                    ..active
+                }
+            }
+            Tabs::Custom(custom) => custom.hovered(self, is_selected),
+        }
+    }
+}
+
+/// The style of a tree view.
+#[derive(Default)]
+pub enum TreeView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tree_view::StyleSheet<Style = Theme>>),
+}
+
+impl tree_view::StyleSheet for Theme {
+    type Style = TreeView;
+
+    fn active(&self, style: &Self::Style) -> tree_view::Appearance {
+        match style {
+            TreeView::Default => {
+                let palette = self.extended_palette();
+
+                tree_view::Appearance {
+                    text_color: palette.background.base.text,
+                    arrow_color: palette.background.strong.text,
+                    guide_color: Some(palette.background.strong.color),
+                    guide_width: 1.0,
+                    hovered_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    selected_background: palette.background.strong.color.into(),
+                    selected_text_color: palette.background.strong.text,
+                    border_radius: 2.0.into(),
+                }
+            }
+            TreeView::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> tree_view::Appearance {
+        match style {
+            TreeView::Default => {
+                let palette = self.extended_palette();
+
+                tree_view::Appearance {
+                    selected_background: palette.primary.weak.color.into(),
+                    selected_text_color: palette.primary.weak.text,
+                    ..self.active(style)
+                }
+            }
+            TreeView::Custom(custom) => custom.focused(self),
+        }
+    }
+}
+
 /// The style of a pane grid.
 #[derive(Default)]
 pub enum PaneGrid {
@@ -901,6 +1218,69 @@ impl svg::StyleSheet for fn(&Theme) -> svg::Appearance {
     }
 }
 
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> table::Appearance + 'static> From<T> for Table {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                table::Appearance {
+                    text_color: palette.background.base.text,
+                    header_background: palette.background.weak.color.into(),
+                    header_text_color: palette.background.weak.text,
+                    row_background: None,
+                    alternate_row_background: Some(
+                        Color {
+                            a: 0.3,
+                            ..palette.background.weak.color
+                        }
+                        .into(),
+                    ),
+                    hovered_row_background: Some(
+                        Color {
+                            a: 0.3,
+                            ..palette.primary.weak.color
+                        }
+                        .into(),
+                    ),
+                    selected_row_background: palette.primary.weak.color.into(),
+                    selected_text_color: palette.primary.weak.text,
+                    divider_color: palette.background.strong.color,
+                    active_divider_color: palette.primary.strong.color,
+                    divider_width: 1.0,
+                }
+            }
+            Table::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> table::Appearance> table::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a scrollable.
 #[derive(Default)]
 pub enum Scrollable {
@@ -1035,9 +1415,17 @@ impl text::StyleSheet for Theme {
     type Style = Text;
 
     fn appearance(&self, style: Self::Style) -> text::Appearance {
+        let selection = Some(self.extended_palette().primary.weak.color);
+
         match style {
-            Text::Default => Default::default(),
-            Text::Color(c) => text::Appearance { color: Some(c) },
+            Text::Default => text::Appearance {
+                color: None,
+                selection,
+            },
+            Text::Color(c) => text::Appearance {
+                color: Some(c),
+                selection,
+            },
         }
     }
 }
@@ -1157,3 +1545,115 @@ impl text_input::StyleSheet for Theme {
         self.placeholder_color(style)
     }
 }
+
+/// The style of a text editor.
+#[derive(Default)]
+pub enum TextEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn text_editor::StyleSheet<Style = Theme>>),
+}
+
+impl text_editor::StyleSheet for Theme {
+    type Style = TextEditor;
+
+    fn active(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.base.text,
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.primary.strong.color,
+        }
+    }
+
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.placeholder_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.background.strong.color
+    }
+
+    fn value_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.value_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.background.base.text
+    }
+
+    fn selection_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.selection_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.primary.weak.color
+    }
+
+    fn disabled(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.weak.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+        }
+    }
+
+    fn disabled_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.disabled_color(self);
+        }
+
+        self.placeholder_color(style)
+    }
+}
@@ -0,0 +1,64 @@
+//! Change the appearance of a toast.
+use iced_core::{Background, BorderRadius, Color};
+
+/// How important a toast is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Severity {
+    /// The toast informs about something.
+    #[default]
+    Info,
+    /// The toast reports that something succeeded.
+    Success,
+    /// The toast warns about something.
+    Warning,
+    /// The toast reports that something failed.
+    Error,
+}
+
+impl Severity {
+    /// All the severities, from the least to the most important.
+    pub const ALL: &'static [Self] =
+        &[Self::Info, Self::Success, Self::Warning, Self::Error];
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Info => "Info",
+            Self::Success => "Success",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        })
+    }
+}
+
+/// The appearance of a toast.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the toast.
+    pub background: Background,
+    /// The [`Color`] of the stripe along the left side of the toast.
+    pub accent_color: Color,
+    /// The text [`Color`] of the toast.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the toast.
+    pub close_color: Color,
+    /// The border radius of the toast.
+    pub border_radius: BorderRadius,
+    /// The border width of the toast.
+    pub border_width: f32,
+    /// The border [`Color`] of the toast.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a toast.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a toast.
+    fn active(&self, style: &Self::Style, severity: Severity) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a toast.
+    fn hovered(&self, style: &Self::Style, severity: Severity) -> Appearance;
+}
@@ -0,0 +1,35 @@
+//! Change the appearance of a tree view.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the tree view.
+    pub text_color: Color,
+    /// The [`Color`] of the disclosure arrows of the tree view.
+    pub arrow_color: Color,
+    /// The [`Color`] of the indentation guides of the tree view, if any.
+    pub guide_color: Option<Color>,
+    /// The width of the indentation guides of the tree view.
+    pub guide_width: f32,
+    /// The [`Background`] of a hovered node of the tree view, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected node of the tree view.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected node of the tree view.
+    pub selected_text_color: Color,
+    /// The border radius of the background of a node of the tree view.
+    pub border_radius: BorderRadius,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a tree view.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a focused tree view.
+    fn focused(&self, style: &Self::Style) -> Appearance;
+}
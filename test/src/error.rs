@@ -0,0 +1,68 @@
+use crate::core::Size;
+
+use std::io;
+use std::path::PathBuf;
+
+/// An error that occurred while comparing a snapshot with its reference.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The snapshot or its reference could not be read or written.
+    #[error("the snapshot could not be read or written: {0}")]
+    Io(#[from] io::Error),
+
+    /// The reference image could not be decoded.
+    #[error("the reference image could not be decoded: {0}")]
+    Decoding(#[from] png::DecodingError),
+
+    /// The snapshot could not be encoded.
+    #[error("the snapshot could not be encoded: {0}")]
+    Encoding(#[from] png::EncodingError),
+
+    /// The reference image is not an 8-bit `RGBA` image.
+    #[error("the reference image is not an 8-bit RGBA image")]
+    UnsupportedFormat,
+
+    /// The reference image does not exist.
+    #[error(
+        "the reference {} does not exist; see {} and set \
+        ICED_UPDATE_SNAPSHOTS=1 to save it",
+        reference.display(),
+        snapshot.display()
+    )]
+    MissingReference {
+        /// The path where the reference was expected.
+        reference: PathBuf,
+        /// The path where the snapshot was written.
+        snapshot: PathBuf,
+    },
+
+    /// The snapshot and its reference have different sizes.
+    #[error(
+        "the snapshot is {actual:?}, but the reference is {expected:?}; \
+        see {}",
+        snapshot.display()
+    )]
+    SizeMismatch {
+        /// The size of the reference.
+        expected: Size<u32>,
+        /// The size of the snapshot.
+        actual: Size<u32>,
+        /// The path where the snapshot was written.
+        snapshot: PathBuf,
+    },
+
+    /// Some pixels of the snapshot differ from its reference more than the
+    /// allowed tolerance.
+    #[error(
+        "{pixels} pixels differ from the reference; see {}",
+        diff.display()
+    )]
+    Mismatch {
+        /// The amount of pixels that differ.
+        pixels: usize,
+        /// The path where the snapshot was written.
+        snapshot: PathBuf,
+        /// The path where the diff image was written.
+        diff: PathBuf,
+    },
+}
@@ -0,0 +1,74 @@
+//! Test the appearance of your widgets and themes with snapshots.
+//!
+//! `iced_test` renders an [`Element`] with the [`tiny-skia`] software
+//! renderer, so it does not need a GPU or even a display. The resulting
+//! [`Snapshot`] can then be compared with a reference PNG image stored next
+//! to your tests:
+//!
+//! ```no_run
+//! # use iced_test::core::{Element, Size};
+//! # use iced_test::style::Theme;
+//! # fn view<'a>() -> Element<'a, (), iced_test::Renderer<Theme>> {
+//! #     unimplemented!()
+//! # }
+//! use iced_test::{Snapshot, Tolerance};
+//!
+//! let mut renderer = iced_test::renderer();
+//!
+//! let snapshot = Snapshot::render(
+//!     &mut renderer,
+//!     view(),
+//!     &Theme::Dark,
+//!     Size::new(200.0, 100.0),
+//!     2.0,
+//! );
+//!
+//! snapshot
+//!     .verify("tests/snapshots/button.png", Tolerance::default())
+//!     .unwrap();
+//! ```
+//!
+//! A missing reference makes the verification fail. Set the
+//! `ICED_UPDATE_SNAPSHOTS` environment variable to `1` to create missing
+//! references or overwrite existing ones.
+//!
+//! Text is shaped with the fonts available in the system. Load your own fonts
+//! in the [`Renderer`] to obtain the same results on every machine.
+//!
+//! [`Element`]: crate::core::Element
+//! [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    clippy::extra_unused_lifetimes,
+    clippy::from_over_into,
+    clippy::needless_borrow,
+    clippy::new_without_default,
+    clippy::useless_conversion
+)]
+#![forbid(unsafe_code, rust_2018_idioms)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod error;
+mod snapshot;
+
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+pub use iced_style as style;
+pub use iced_tiny_skia as tiny_skia;
+
+pub use error::Error;
+pub use snapshot::{Snapshot, Tolerance};
+
+/// The renderer used to draw snapshots.
+pub type Renderer<Theme> = iced_tiny_skia::Renderer<Theme>;
+
+/// Creates a new [`Renderer`] with the default settings.
+pub fn renderer<Theme>() -> Renderer<Theme> {
+    Renderer::new(iced_tiny_skia::Backend::new(
+        iced_tiny_skia::Settings::default(),
+    ))
+}
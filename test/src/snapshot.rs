@@ -0,0 +1,340 @@
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::{Element, Size};
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window::Screenshot;
+use crate::style::application;
+use crate::tiny_skia::graphics::Viewport;
+use crate::{Error, Renderer};
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// An image of an [`Element`], ordered as `RGBA`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    bytes: Vec<u8>,
+    size: Size<u32>,
+}
+
+/// The differences allowed between a [`Snapshot`] and its reference.
+///
+/// By default, snapshots must match their reference exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tolerance {
+    /// The maximum difference allowed in any channel of a pixel.
+    pub channel: u8,
+
+    /// The amount of pixels that may exceed the `channel` tolerance.
+    pub pixels: usize,
+}
+
+impl Snapshot {
+    /// Renders the given [`Element`] with the provided logical size and scale
+    /// factor.
+    ///
+    /// The background and text colors are obtained from the `Theme`, just
+    /// like a shell would do.
+    pub fn render<'a, Message, Theme>(
+        renderer: &mut Renderer<Theme>,
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        theme: &Theme,
+        size: Size,
+        scale_factor: f64,
+    ) -> Self
+    where
+        Theme: application::StyleSheet,
+    {
+        let physical_size = Size::new(
+            (f64::from(size.width) * scale_factor).round() as u32,
+            (f64::from(size.height) * scale_factor).round() as u32,
+        );
+
+        let viewport =
+            Viewport::with_physical_size(physical_size, scale_factor);
+
+        let appearance = theme.appearance(&Theme::Style::default());
+
+        renderer.clear();
+
+        let mut user_interface = UserInterface::build(
+            element,
+            viewport.logical_size(),
+            user_interface::Cache::default(),
+            renderer,
+        );
+
+        let _ = user_interface.draw(
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            mouse::Cursor::Unavailable,
+        );
+
+        let overlay: [&str; 0] = [];
+
+        let bytes = renderer.with_primitives(|backend, primitives| {
+            iced_tiny_skia::window::compositor::screenshot(
+                backend,
+                primitives,
+                &viewport,
+                appearance.background_color,
+                &overlay,
+            )
+        });
+
+        Self {
+            bytes,
+            size: physical_size,
+        }
+    }
+
+    /// Creates a [`Snapshot`] from its `RGBA` bytes and physical size.
+    ///
+    /// # Panics
+    /// Panics if the amount of bytes does not match the size.
+    pub fn from_rgba(bytes: Vec<u8>, size: Size<u32>) -> Self {
+        assert_eq!(
+            bytes.len(),
+            size.width as usize * size.height as usize * 4,
+            "The bytes of a snapshot must match its size"
+        );
+
+        Self { bytes, size }
+    }
+
+    /// Loads a [`Snapshot`] from the PNG image in the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let decoder = png::Decoder::new(fs::File::open(path)?);
+        let mut reader = decoder.read_info()?;
+
+        let mut bytes = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut bytes)?;
+
+        if info.color_type != png::ColorType::Rgba
+            || info.bit_depth != png::BitDepth::Eight
+        {
+            return Err(Error::UnsupportedFormat);
+        }
+
+        bytes.truncate(info.buffer_size());
+
+        Ok(Self {
+            bytes,
+            size: Size::new(info.width, info.height),
+        })
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image in the given path, creating any
+    /// missing directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let file = io::BufWriter::new(fs::File::create(path)?);
+
+        let mut encoder =
+            png::Encoder::new(file, self.size.width, self.size.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Returns the `RGBA` bytes of the [`Snapshot`].
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the physical size of the [`Snapshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Compares the [`Snapshot`] with the reference PNG image in the given
+    /// path.
+    ///
+    /// If the `ICED_UPDATE_SNAPSHOTS` environment variable is set to `1`, the
+    /// [`Snapshot`] is saved as the new reference instead.
+    ///
+    /// On failure, the [`Snapshot`] is saved next to the reference with an
+    /// `.actual.png` extension and, if both have the same size, an image
+    /// highlighting the different pixels is saved with a `.diff.png`
+    /// extension. A missing reference is a failure too.
+    pub fn verify(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: Tolerance,
+    ) -> Result<(), Error> {
+        let update = env::var_os("ICED_UPDATE_SNAPSHOTS")
+            .is_some_and(|update| update == "1");
+
+        self.compare(path.as_ref(), tolerance, update)
+    }
+
+    fn compare(
+        &self,
+        path: &Path,
+        tolerance: Tolerance,
+        update: bool,
+    ) -> Result<(), Error> {
+        let actual = path.with_extension("actual.png");
+        let diff = path.with_extension("diff.png");
+
+        if update {
+            return self.save(path);
+        }
+
+        if !path.exists() {
+            self.save(&actual)?;
+
+            return Err(Error::MissingReference {
+                reference: path.to_path_buf(),
+                snapshot: actual,
+            });
+        }
+
+        let reference = Self::load(path)?;
+
+        if reference.size != self.size {
+            self.save(&actual)?;
+
+            return Err(Error::SizeMismatch {
+                expected: reference.size,
+                actual: self.size,
+                snapshot: actual,
+            });
+        }
+
+        let (pixels, image) = self.diff(&reference, tolerance.channel);
+
+        if pixels > tolerance.pixels {
+            self.save(&actual)?;
+            image.save(&diff)?;
+
+            return Err(Error::Mismatch {
+                pixels,
+                snapshot: actual,
+                diff,
+            });
+        }
+
+        // Outputs of previous failures are stale now
+        let _ = fs::remove_file(actual);
+        let _ = fs::remove_file(diff);
+
+        Ok(())
+    }
+
+    /// Counts the pixels that differ from the reference by more than the
+    /// given channel tolerance, and produces an image highlighting them.
+    fn diff(&self, reference: &Self, tolerance: u8) -> (usize, Self) {
+        const HIGHLIGHT: [u8; 4] = [255, 0, 255, 255];
+
+        let mut different = 0;
+        let mut bytes = Vec::with_capacity(self.bytes.len());
+
+        for (actual, expected) in self
+            .bytes
+            .chunks_exact(4)
+            .zip(reference.bytes.chunks_exact(4))
+        {
+            let is_different = actual
+                .iter()
+                .zip(expected)
+                .any(|(a, b)| a.abs_diff(*b) > tolerance);
+
+            if is_different {
+                different += 1;
+                bytes.extend_from_slice(&HIGHLIGHT);
+            } else {
+                // Matching pixels are washed out, so differences stand out
+                let luma = (u32::from(expected[0]) * 3
+                    + u32::from(expected[1]) * 6
+                    + u32::from(expected[2]))
+                    / 10;
+                let value = (191 + luma / 4) as u8;
+
+                bytes.extend_from_slice(&[value, value, value, 255]);
+            }
+        }
+
+        (
+            different,
+            Self {
+                bytes,
+                size: self.size,
+            },
+        )
+    }
+}
+
+impl From<Screenshot> for Snapshot {
+    fn from(screenshot: Screenshot) -> Self {
+        Self::from_rgba(screenshot.bytes.to_vec(), screenshot.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(color: [u8; 4]) -> Snapshot {
+        Snapshot::from_rgba(color.repeat(4), Size::new(2, 2))
+    }
+
+    #[test]
+    fn diff_respects_channel_tolerance() {
+        let reference = solid([100, 100, 100, 255]);
+        let snapshot = solid([102, 100, 99, 255]);
+
+        assert_eq!(snapshot.diff(&reference, 2).0, 0);
+        assert_eq!(snapshot.diff(&reference, 1).0, 4);
+    }
+
+    #[test]
+    fn diff_highlights_different_pixels() {
+        let reference = solid([0, 0, 0, 255]);
+        let mut snapshot = reference.clone();
+        snapshot.bytes[4..8].copy_from_slice(&[255, 255, 255, 255]);
+
+        let (pixels, image) = snapshot.diff(&reference, 0);
+
+        assert_eq!(pixels, 1);
+        assert_eq!(&image.bytes()[4..8], &[255, 0, 255, 255]);
+        assert_eq!(&image.bytes()[0..4], &[191, 191, 191, 255]);
+    }
+
+    #[test]
+    fn missing_reference_fails_unless_updating() {
+        let directory = env::temp_dir()
+            .join(format!("iced_test-missing-{}", std::process::id()));
+        let reference = directory.join("missing.png");
+        let snapshot = solid([0, 0, 0, 255]);
+
+        let result = snapshot.compare(&reference, Tolerance::default(), false);
+
+        assert!(matches!(result, Err(Error::MissingReference { .. })));
+        assert!(!reference.exists());
+        assert!(reference.with_extension("actual.png").exists());
+
+        snapshot
+            .compare(&reference, Tolerance::default(), true)
+            .unwrap();
+
+        assert_eq!(Snapshot::load(&reference).unwrap(), snapshot);
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
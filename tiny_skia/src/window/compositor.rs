@@ -1,4 +1,4 @@
-use crate::core::{Color, Rectangle};
+use crate::core::{Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information, SurfaceError};
 use crate::graphics::damage;
 use crate::graphics::{Error, Primitive, Viewport};
@@ -91,6 +91,19 @@ impl<Theme> crate::graphics::Compositor for Compositor<Theme> {
             )
         })
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        renderer.with_primitives(|backend, primitives| {
+            screenshot(backend, primitives, viewport, background_color, overlay)
+        })
+    }
 }
 
 pub fn new<Theme>(settings: Settings) -> (Compositor<Theme>, Backend) {
@@ -156,3 +169,68 @@ pub fn present<T: AsRef<str>>(
 
     Ok(())
 }
+
+/// Renders the given primitives offscreen and returns the resulting pixels
+/// ordered as `RGBA`.
+///
+/// No window is needed, which makes it suitable for headless environments.
+pub fn screenshot<T: AsRef<str>>(
+    backend: &mut Backend,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Vec<u8> {
+    let physical_size = viewport.physical_size();
+
+    if physical_size.width == 0 || physical_size.height == 0 {
+        return Vec::new();
+    }
+
+    let length =
+        physical_size.width as usize * physical_size.height as usize * 4;
+
+    let mut buffer = vec![0; length];
+
+    let mut pixels = tiny_skia::PixmapMut::from_bytes(
+        &mut buffer,
+        physical_size.width,
+        physical_size.height,
+    )
+    .expect("Create offscreen pixel map");
+
+    let mut clip_mask =
+        tiny_skia::Mask::new(physical_size.width, physical_size.height)
+            .expect("Create clip mask");
+
+    backend.draw(
+        &mut pixels,
+        &mut clip_mask,
+        primitives,
+        viewport,
+        &[Rectangle::with_size(Size::new(
+            physical_size.width as f32,
+            physical_size.height as f32,
+        ))],
+        background_color,
+        overlay,
+    );
+
+    // The backend swaps the red and blue channels to match the layout of
+    // `softbuffer`, and `tiny-skia` stores premultiplied colors.
+    for pixel in buffer.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+
+        let alpha = pixel[3];
+
+        if alpha > 0 && alpha < u8::MAX {
+            for channel in &mut pixel[..3] {
+                *channel = (u16::from(*channel) * u16::from(u8::MAX)
+                    / u16::from(alpha))
+                .min(u16::from(u8::MAX)) as u8;
+            }
+        }
+    }
+
+    buffer
+}
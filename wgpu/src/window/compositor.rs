@@ -205,6 +205,117 @@ pub fn present<Theme, T: AsRef<str>>(
     }
 }
 
+/// Renders the given primitives to an offscreen texture and reads its pixels
+/// back, ordered as `RGBA` in the sRGB color space.
+pub fn screenshot<Theme, T: AsRef<str>>(
+    compositor: &Compositor<Theme>,
+    backend: &mut Backend,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Vec<u8> {
+    let physical_size = viewport.physical_size();
+
+    if physical_size.width == 0 || physical_size.height == 0 {
+        return Vec::new();
+    }
+
+    let extent = wgpu::Extent3d {
+        width: physical_size.width,
+        height: physical_size.height,
+        depth_or_array_layers: 1,
+    };
+
+    let texture = compositor.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu screenshot texture"),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: compositor.format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut encoder = compositor.device.create_command_encoder(
+        &wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu screenshot encoder"),
+        },
+    );
+
+    backend.present(
+        &compositor.device,
+        &compositor.queue,
+        &mut encoder,
+        Some(background_color),
+        &view,
+        primitives,
+        viewport,
+        overlay,
+    );
+
+    // Rows of a texture copy must be aligned to `COPY_BYTES_PER_ROW_ALIGNMENT`
+    let bytes_per_row = physical_size.width as usize * 4;
+    let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize;
+    let padded_bytes_per_row =
+        (bytes_per_row + alignment - 1) / alignment * alignment;
+
+    let buffer = compositor.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_wgpu screenshot buffer"),
+        size: (padded_bytes_per_row * physical_size.height as usize) as u64,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row as u32),
+                rows_per_image: None,
+            },
+        },
+        extent,
+    );
+
+    let submission = compositor.queue.submit(Some(encoder.finish()));
+
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |_| {});
+
+    let _ = compositor
+        .device
+        .poll(wgpu::Maintain::WaitForSubmissionIndex(submission));
+
+    let is_bgra = matches!(
+        compositor.format,
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+    );
+
+    let mut pixels =
+        Vec::with_capacity(bytes_per_row * physical_size.height as usize);
+
+    for row in slice.get_mapped_range().chunks(padded_bytes_per_row) {
+        pixels.extend_from_slice(&row[..bytes_per_row]);
+    }
+
+    buffer.unmap();
+
+    if is_bgra {
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+
+    pixels
+}
+
 impl<Theme> graphics::Compositor for Compositor<Theme> {
     type Settings = Settings;
     type Renderer = Renderer<Theme>;
@@ -283,4 +394,24 @@ impl<Theme> graphics::Compositor for Compositor<Theme> {
             )
         })
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        renderer.with_primitives(|backend, primitives| {
+            screenshot(
+                self,
+                backend,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            )
+        })
+    }
 }
@@ -5,14 +5,17 @@ use iced_runtime::core::widget::Id;
 use iced_runtime::{keyboard, Command};
 use std::borrow::Cow;
 
+use crate::core::animation::{Animation, Curve, Interpolate};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Vector, Widget,
@@ -73,6 +76,7 @@ where
     width: Length,
     height: Length,
     padding: Padding,
+    transition: Option<Curve>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -96,6 +100,7 @@ where
             width: Length::Shrink,
             height: Length::Shrink,
             padding: Padding::new(5.0),
+            transition: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
@@ -126,6 +131,15 @@ where
         self
     }
 
+    /// Sets the [`Curve`] used to cross-fade the [`Button`] between its
+    /// styles, like when it is hovered or pressed.
+    ///
+    /// By default, the [`Button`] changes its style instantly.
+    pub fn transition(mut self, curve: impl Into<Curve>) -> Self {
+        self.transition = Some(curve.into());
+        self
+    }
+
     /// Sets the style variant of this [`Button`].
     pub fn style(
         mut self,
@@ -185,11 +199,11 @@ where
     Renderer::Theme: StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+        tree::Tag::of::<Internal>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::new(Internal::default())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -240,7 +254,7 @@ where
                 operation,
             );
         });
-        let state = tree.state.downcast_mut::<State>();
+        let state = &mut tree.state.downcast_mut::<Internal>().state;
         operation.focusable(state, Some(&self.id));
     }
 
@@ -254,6 +268,19 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let (
+            Some(curve),
+            Event::Window(_, window::Event::RedrawRequested(now)),
+        ) = (self.transition, &event)
+        {
+            let is_hovered =
+                self.on_press.is_some() && cursor.is_over(layout.bounds());
+
+            tree.state
+                .downcast_mut::<Internal>()
+                .animate(curve, is_hovered, *now, shell);
+        }
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
@@ -273,7 +300,7 @@ where
             cursor,
             shell,
             &self.on_press,
-            || tree.state.downcast_mut::<State>(),
+            || &mut tree.state.downcast_mut::<Internal>().state,
         )
     }
 
@@ -289,16 +316,29 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
+        let Internal { state, transition } =
+            tree.state.downcast_ref::<Internal>();
 
-        let styling = draw(
-            renderer,
-            bounds,
-            cursor,
-            self.on_press.is_some(),
-            theme,
-            &self.style,
-            || tree.state.downcast_ref::<State>(),
-        );
+        let styling = match transition {
+            Some(transition)
+                if self.transition.is_some() && self.on_press.is_some() =>
+            {
+                let styling = transition.appearance(theme, &self.style);
+
+                draw_background(renderer, bounds, &styling);
+
+                styling
+            }
+            _ => draw(
+                renderer,
+                bounds,
+                cursor,
+                self.on_press.is_some(),
+                theme,
+                &self.style,
+                || state,
+            ),
+        };
 
         self.content.as_widget().draw(
             &tree.children[0],
@@ -371,7 +411,8 @@ where
             (x + width) as f64,
             (y + height) as f64,
         );
-        let is_hovered = state.state.downcast_ref::<State>().is_hovered;
+        let is_hovered =
+            state.state.downcast_ref::<Internal>().state.is_hovered;
 
         let mut node = NodeBuilder::new(Role::Button);
         node.add_action(Action::Focus);
@@ -469,6 +510,92 @@ impl State {
     }
 }
 
+/// The widget state of a [`Button`], along with the [`Transition`] of its
+/// styles.
+#[derive(Debug, Clone, Copy, Default)]
+struct Internal {
+    state: State,
+    transition: Option<Transition>,
+}
+
+impl Internal {
+    /// Moves the styles of the [`Button`] towards its current interaction.
+    fn animate<Message>(
+        &mut self,
+        curve: Curve,
+        is_hovered: bool,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let weight = |is_active: bool| if is_active { 1.0 } else { 0.0 };
+
+        let targets = [
+            weight(is_hovered),
+            weight(is_hovered && self.state.is_pressed),
+            weight(self.state.is_focused),
+        ];
+
+        let transition = self.transition.get_or_insert_with(|| {
+            let [hovered, pressed, focused] =
+                targets.map(|target| Animation::new(target).curve(curve));
+
+            Transition {
+                hovered,
+                pressed,
+                focused,
+                now,
+            }
+        });
+
+        transition.now = now;
+
+        for (animation, target) in [
+            &mut transition.hovered,
+            &mut transition.pressed,
+            &mut transition.focused,
+        ]
+        .into_iter()
+        .zip(targets)
+        {
+            animation.go(target, now);
+            animation.request_redraw(now, shell);
+        }
+    }
+}
+
+/// The styles of a [`Button`] cross-fading between its interactions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transition {
+    hovered: Animation<f32>,
+    pressed: Animation<f32>,
+    focused: Animation<f32>,
+    now: Instant,
+}
+
+impl Transition {
+    /// Returns the [`Appearance`] of the [`Button`] at the last frame.
+    fn appearance<Theme: StyleSheet + ?Sized>(
+        &self,
+        style_sheet: &Theme,
+        style: &Theme::Style,
+    ) -> Appearance {
+        style_sheet
+            .active(style)
+            .interpolate(
+                &style_sheet.focused(style),
+                self.focused.value(self.now),
+            )
+            .interpolate(
+                &style_sheet.hovered(style),
+                self.hovered.value(self.now),
+            )
+            .interpolate(
+                &style_sheet.pressed(style),
+                self.pressed.value(self.now),
+            )
+    }
+}
+
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
 /// accordingly.
 pub fn update<'a, Message: Clone>(
@@ -588,6 +715,17 @@ where
         style_sheet.active(style)
     };
 
+    draw_background(renderer, bounds, &styling);
+
+    styling
+}
+
+/// Draws the background of a [`Button`] with the given [`Appearance`].
+fn draw_background<Renderer: crate::core::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    styling: &Appearance,
+) {
     if styling.background.is_some() || styling.border_width > 0.0 {
         if styling.shadow_offset != Vector::default() {
             // TODO: Implement proper shadow support
@@ -618,8 +756,6 @@ where
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
-
-    styling
 }
 
 /// Computes the layout of a [`Button`].
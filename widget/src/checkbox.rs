@@ -3,16 +3,19 @@ use iced_runtime::core::widget::Id;
 use std::borrow::Cow;
 
 use crate::core::alignment;
+use crate::core::animation::{Animation, Curve, Interpolate};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
 use crate::core::touch;
-use crate::core::widget::Tree;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    id::Internal, Alignment, Clipboard, Element, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Widget,
+    id::Internal, Alignment, Clipboard, Color, Element, Layout, Length, Pixels,
+    Point, Rectangle, Shell, Widget,
 };
 use crate::{Row, Text};
 
@@ -59,6 +62,7 @@ where
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     icon: Icon<Renderer::Font>,
+    transition: Option<Curve>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -109,6 +113,7 @@ where
                 line_height: text::LineHeight::default(),
                 shaping: text::Shaping::Advanced,
             },
+            transition: None,
             style: Default::default(),
         }
     }
@@ -166,6 +171,14 @@ where
         self
     }
 
+    /// Sets the [`Curve`] used to fade the [`Checkbox`] between its states.
+    ///
+    /// By default, the [`Checkbox`] changes its state instantly.
+    pub fn transition(mut self, curve: impl Into<Curve>) -> Self {
+        self.transition = Some(curve.into());
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     pub fn style(
         mut self,
@@ -209,6 +222,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + crate::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -243,7 +264,7 @@ where
 
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -252,6 +273,16 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if let Some(curve) = self.transition {
+                    tree.state.downcast_mut::<State>().animate(
+                        curve,
+                        self.is_checked,
+                        now,
+                        shell,
+                    );
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = cursor.is_over(layout.bounds());
@@ -285,7 +316,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -297,12 +328,28 @@ where
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_checked)
-        } else {
-            theme.active(&self.style, self.is_checked)
+        let appearance = |is_checked| {
+            if is_mouse_over {
+                theme.hovered(&self.style, is_checked)
+            } else {
+                theme.active(&self.style, is_checked)
+            }
+        };
+
+        let progress = self
+            .transition
+            .and(tree.state.downcast_ref::<State>().progress());
+
+        let custom_style = match progress {
+            Some(progress) => {
+                appearance(false).interpolate(&appearance(true), progress)
+            }
+            None => appearance(self.is_checked),
         };
 
+        let icon_alpha =
+            progress.unwrap_or(if self.is_checked { 1.0 } else { 0.0 });
+
         {
             let layout = children.next().unwrap();
             let bounds = layout.bounds();
@@ -326,7 +373,7 @@ where
             } = &self.icon;
             let size = size.unwrap_or(bounds.height * 0.7);
 
-            if self.is_checked {
+            if icon_alpha > 0.0 {
                 renderer.fill_text(text::Text {
                     content: &code_point.to_string(),
                     font: *font,
@@ -337,7 +384,10 @@ where
                         y: bounds.center_y(),
                         ..bounds
                     },
-                    color: custom_style.icon_color,
+                    color: Color {
+                        a: custom_style.icon_color.a * icon_alpha,
+                        ..custom_style.icon_color
+                    },
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: *shaping,
@@ -358,6 +408,7 @@ where
                 self.font,
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
@@ -455,6 +506,41 @@ where
     }
 }
 
+/// The local state of a [`Checkbox`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    transition: Option<(Animation<f32>, Instant)>,
+}
+
+impl State {
+    /// Moves the check mark of the [`Checkbox`] towards its current state.
+    fn animate<Message>(
+        &mut self,
+        curve: Curve,
+        is_checked: bool,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let target = if is_checked { 1.0 } else { 0.0 };
+
+        let (progress, last_frame) = self
+            .transition
+            .get_or_insert_with(|| (Animation::new(target).curve(curve), now));
+
+        progress.go(target, now);
+        progress.request_redraw(now, shell);
+
+        *last_frame = now;
+    }
+
+    /// Returns how visible the check mark of the [`Checkbox`] is at the last
+    /// frame, if it has been animated.
+    fn progress(&self) -> Option<f32> {
+        self.transition
+            .map(|(progress, last_frame)| progress.value(last_frame))
+    }
+}
+
 impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
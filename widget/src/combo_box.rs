@@ -0,0 +1,620 @@
+//! Display a text input with a dropdown list of suggestions to pick from.
+//!
+//! A [`ComboBox`] filters its options as the user types, using the matcher
+//! of its [`State`].
+use crate::container;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+use crate::scrollable;
+use crate::text_input::{self, TextInput};
+
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Arc;
+
+/// A text input that suggests and filters a list of options as the user
+/// types.
+///
+/// The options can be browsed with the mouse or with the arrow keys, which
+/// wrap around the ends of the list, and picked with a click or by pressing
+/// Enter, just like in a [`PickList`].
+///
+/// [`PickList`]: crate::PickList
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + menu::StyleSheet,
+{
+    state: &'a State<T>,
+    id: Id,
+    text_input: TextInput<'a, TextInputEvent, Renderer>,
+    selection: String,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    padding: Padding,
+    size: Option<f32>,
+    font: Option<Renderer::Font>,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: fmt::Display + Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`ComboBox`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`ComboBox`] with the given [`State`], a placeholder,
+    /// the current selection, and the message to produce when an option is
+    /// selected.
+    pub fn new(
+        state: &'a State<T>,
+        placeholder: &str,
+        selection: Option<&T>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let id = Id::unique();
+        let text_input = TextInput::new(placeholder, &state.value())
+            .id(id.clone())
+            .padding(Self::DEFAULT_PADDING)
+            .on_input(TextInputEvent::TextChanged);
+
+        Self {
+            state,
+            id,
+            text_input,
+            selection: selection.map(T::to_string).unwrap_or_default(),
+            on_selected: Box::new(on_selected),
+            on_input: None,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            font: None,
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when some text is typed into
+    /// the [`ComboBox`].
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the [`Id`] of the [`ComboBox`].
+    ///
+    /// The [`Id`] can be used with the [`text_input`] commands, like
+    /// [`text_input::focus`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.text_input = self.text_input.id(id.clone());
+        self.id = id;
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ComboBox`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        let padding = padding.into();
+
+        self.text_input = self.text_input.padding(padding);
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.size = Some(size.0);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the [`text_input::Icon`] of the [`ComboBox`].
+    pub fn icon(mut self, icon: text_input::Icon<Renderer::Font>) -> Self {
+        self.text_input = self.text_input.icon(icon);
+        self
+    }
+
+    /// Sets the style of the input of the [`ComboBox`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the dropdown menu of the [`ComboBox`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+/// The options of a [`ComboBox`], together with the text typed into it.
+///
+/// It must be kept in the state of your application, so the typed text and
+/// the filtered options persist across calls to `view`.
+pub struct State<T>(RefCell<Inner<T>>);
+
+struct Inner<T> {
+    value: String,
+    options: Vec<T>,
+    filtered_options: Arc<Vec<T>>,
+    matcher: Box<dyn Fn(&T, &str) -> bool>,
+}
+
+impl<T> State<T>
+where
+    T: Clone,
+{
+    /// Creates a new [`State`] for a [`ComboBox`] with the given options.
+    ///
+    /// The options are filtered with [`matches`].
+    pub fn new(options: Vec<T>) -> Self
+    where
+        T: fmt::Display + 'static,
+    {
+        Self::with_matcher(options, matches)
+    }
+
+    /// Creates a new [`State`] for a [`ComboBox`] with the given options and
+    /// a custom matcher.
+    ///
+    /// The matcher decides whether an option should be suggested for the
+    /// text typed so far.
+    pub fn with_matcher(
+        options: Vec<T>,
+        matcher: impl Fn(&T, &str) -> bool + 'static,
+    ) -> Self {
+        let filtered_options = Arc::new(options.clone());
+
+        Self(RefCell::new(Inner {
+            value: String::new(),
+            options,
+            filtered_options,
+            matcher: Box::new(matcher),
+        }))
+    }
+
+    /// Returns the text currently typed into the [`ComboBox`].
+    pub fn value(&self) -> String {
+        self.0.borrow().value.clone()
+    }
+
+    /// Returns the options that match the text currently typed into the
+    /// [`ComboBox`].
+    pub fn filtered_options(&self) -> Vec<T> {
+        self.0.borrow().filtered_options.as_ref().clone()
+    }
+
+    /// Replaces the typed text and filters the options with it.
+    fn filter(&self, value: String) {
+        let mut inner = self.0.borrow_mut();
+
+        let filtered_options = inner
+            .options
+            .iter()
+            .filter(|option| (inner.matcher)(option, &value))
+            .cloned()
+            .collect();
+
+        inner.filtered_options = Arc::new(filtered_options);
+        inner.value = value;
+    }
+
+    /// Replaces the typed text, suggesting every option.
+    fn reset(&self, value: String) {
+        let mut inner = self.0.borrow_mut();
+
+        inner.filtered_options = Arc::new(inner.options.clone());
+        inner.value = value;
+    }
+
+    /// Copies the current suggestions into the local state of a widget.
+    fn sync(&self, menu: &mut Dropdown<T>) {
+        let inner = self.0.borrow();
+
+        if !Arc::ptr_eq(&inner.filtered_options, &menu.options) {
+            menu.options = Arc::clone(&inner.filtered_options);
+            menu.hovered_option = menu
+                .hovered_option
+                .filter(|index| *index < menu.options.len());
+        }
+    }
+}
+
+impl<T> fmt::Debug for State<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.0.borrow();
+
+        f.debug_struct("State")
+            .field("value", &inner.value)
+            .field("options", &inner.options)
+            .field("filtered_options", &inner.filtered_options)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns true if every word of the `query` is contained in the textual
+/// representation of the `option`, ignoring case.
+///
+/// This is the default matcher of a [`State`].
+pub fn matches<T>(option: &T, query: &str) -> bool
+where
+    T: fmt::Display,
+{
+    let option = option.to_string().to_lowercase();
+
+    query
+        .split_whitespace()
+        .all(|word| option.contains(&word.to_lowercase()))
+}
+
+/// The messages produced by the inner [`TextInput`] of a [`ComboBox`].
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+}
+
+/// The local state of the dropdown menu of a [`ComboBox`].
+struct Dropdown<T> {
+    menu: menu::State,
+    options: Arc<Vec<T>>,
+    hovered_option: Option<usize>,
+    new_selection: Option<T>,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: fmt::Display + Clone + 'static,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: text_input::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Dropdown<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut dropdown = Dropdown {
+            menu: menu::State::default(),
+            options: Arc::default(),
+            hovered_option: None,
+            new_selection: None,
+        };
+
+        self.state.sync(&mut dropdown);
+
+        tree::State::new(dropdown)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.text_input as &dyn Widget<TextInputEvent, Renderer>,
+        )]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.state.sync(tree.state.downcast_mut::<Dropdown<T>>());
+
+        tree.diff_children(&mut [
+            &mut self.text_input as &mut dyn Widget<TextInputEvent, Renderer>
+        ]);
+    }
+
+    fn width(&self) -> Length {
+        Widget::<TextInputEvent, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<TextInputEvent, Renderer>::height(&self.text_input)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::<TextInputEvent, Renderer>::layout(
+            &self.text_input,
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.children[0].state.downcast_mut::<text_input::State>();
+
+        operation.focusable(state, Some(&self.id));
+        operation.text_input(state, Some(&self.id));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let dropdown = tree.state.downcast_mut::<Dropdown<T>>();
+        let text_input_tree = &mut tree.children[0];
+
+        let was_focused = text_input_tree
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = self.text_input.on_event(
+            text_input_tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for message in messages {
+            match message {
+                TextInputEvent::TextChanged(value) => {
+                    self.state.filter(value.clone());
+                    self.state.sync(dropdown);
+
+                    dropdown.hovered_option =
+                        (!dropdown.options.is_empty()).then_some(0);
+
+                    if let Some(on_input) = &self.on_input {
+                        shell.publish(on_input(value));
+                    } else {
+                        shell.invalidate_widgets();
+                    }
+                }
+            }
+        }
+
+        let text_input_state =
+            text_input_tree.state.downcast_mut::<text_input::State>();
+
+        if let Some(selection) = dropdown.new_selection.take() {
+            self.state.reset(selection.to_string());
+            self.state.sync(dropdown);
+
+            text_input_state.unfocus();
+            shell.publish((self.on_selected)(selection));
+
+            return event::Status::Captured;
+        }
+
+        let is_focused = text_input_state.is_focused();
+
+        if is_focused && !was_focused {
+            // Suggest every option until the user starts typing
+            self.state.reset(self.state.value());
+            self.state.sync(dropdown);
+
+            dropdown.hovered_option = dropdown
+                .options
+                .iter()
+                .position(|option| option.to_string() == self.selection);
+
+            text_input_state.select_all();
+        } else if was_focused && !is_focused {
+            self.state.reset(self.selection.clone());
+            self.state.sync(dropdown);
+
+            dropdown.hovered_option = None;
+
+            shell.invalidate_widgets();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<TextInputEvent, Renderer>::mouse_interaction(
+            &self.text_input,
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<TextInputEvent, Renderer>::draw(
+            &self.text_input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        let Dropdown {
+            menu,
+            options,
+            hovered_option,
+            new_selection,
+        } = tree.state.downcast_mut::<Dropdown<T>>();
+
+        if !is_focused || options.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu =
+            Menu::new(menu, options.as_slice(), hovered_option, new_selection)
+                .width(bounds.width)
+                .padding(self.padding)
+                .type_ahead(false)
+                .wrap_around(true)
+                .style(self.menu_style.clone());
+
+        if let Some(font) = self.font {
+            menu = menu.font(font);
+        }
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        Widget::<TextInputEvent, Renderer>::a11y_nodes(
+            &self.text_input,
+            layout,
+            &state.children[0],
+            cursor,
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: fmt::Display + Clone + 'static,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: text_input::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Self::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_every_word_ignoring_case() {
+        assert!(matches(&"Fira Sans Mono", "sans"));
+        assert!(matches(&"Fira Sans Mono", "MONO fira"));
+        assert!(matches(&"Fira Sans Mono", ""));
+        assert!(!matches(&"Fira Sans Mono", "fira serif"));
+    }
+
+    #[test]
+    fn filter_uses_custom_matcher() {
+        let state = State::with_matcher(
+            vec!["en-US", "en-GB", "es-ES"],
+            |option: &&str, query| option.starts_with(query),
+        );
+
+        state.filter(String::from("en"));
+        assert_eq!(state.filtered_options(), vec!["en-US", "en-GB"]);
+
+        state.reset(String::from("es-ES"));
+        assert_eq!(state.value(), "es-ES");
+        assert_eq!(state.filtered_options().len(), 3);
+    }
+}
@@ -0,0 +1,413 @@
+//! Show a menu of actions when some content is right-clicked.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::{tree, Operation, OperationOutputWrapper, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Widget,
+};
+use crate::overlay::cascade::{self, Cascade};
+
+pub use crate::overlay::cascade::{Item, StyleSheet};
+
+#[cfg(feature = "wayland")]
+use crate::core::window;
+#[cfg(feature = "wayland")]
+use crate::runtime::command::platform_specific::wayland::popup::{
+    SctkPopupSettings, SctkPositioner,
+};
+#[cfg(feature = "wayland")]
+use sctk::reexports::protocols::xdg::shell::client::xdg_positioner::{
+    Anchor, Gravity,
+};
+
+/// A container that opens a menu at the cursor when right-clicked.
+///
+/// # Example
+/// ```no_run
+/// # type Renderer = iced_widget::renderer::Renderer<iced_widget::style::Theme>;
+/// # type ContextMenu<'a, Message> = iced_widget::ContextMenu<'a, Message, Renderer>;
+/// # type Text<'a> = iced_widget::Text<'a, Renderer>;
+/// use iced_widget::context_menu::Item;
+/// use iced_widget::core::keyboard::{KeyCode, Modifiers};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+///     Share(&'static str),
+/// }
+///
+/// let context_menu: ContextMenu<'_, Message> = ContextMenu::new(
+///     Text::new("Right-click me!"),
+///     [
+///         Item::new("Copy", Message::Copy)
+///             .shortcut(Modifiers::CTRL, KeyCode::C),
+///         Item::new("Paste", Message::Paste).enabled(false),
+///         Item::separator(),
+///         Item::submenu(
+///             "Share",
+///             [
+///                 Item::new("Email", Message::Share("email")),
+///                 Item::new("Chat", Message::Share("chat")),
+///             ],
+///         ),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Option<Renderer::Font>,
+    on_close: Option<Message>,
+    is_open: bool,
+    #[cfg(feature = "wayland")]
+    popup: Option<Popup<'a, Message>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+#[cfg(feature = "wayland")]
+struct Popup<'a, Message> {
+    parent: window::Id,
+    id: window::Id,
+    on_open: Box<dyn Fn(SctkPopupSettings) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ContextMenu`] wrapping the given content with the
+    /// items of its menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            padding: Padding::from([5, 10]),
+            text_size: None,
+            font: None,
+            on_close: None,
+            is_open: false,
+            #[cfg(feature = "wayland")]
+            popup: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the items of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the font of the items of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when the menu is dismissed
+    /// without choosing an item.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets whether the menu is open at the top-left corner of the
+    /// [`ContextMenu`] when it is first displayed.
+    ///
+    /// This is useful to display the menu in a surface of its own.
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    /// Opens the menu in an `xdg_popup` instead of an overlay, so it can
+    /// extend outside of the window.
+    ///
+    /// When the content is right-clicked, the [`ContextMenu`] produces a
+    /// message with the [`SctkPopupSettings`] of the popup with the given
+    /// `id`. Your application should then create the popup with them, and
+    /// display another [`ContextMenu`] with the same items in it, opened
+    /// with [`ContextMenu::open`]. The popup should be destroyed once an
+    /// item is chosen or the menu is closed.
+    #[cfg(feature = "wayland")]
+    pub fn popup(
+        mut self,
+        parent: window::Id,
+        id: window::Id,
+        on_open: impl Fn(SctkPopupSettings) -> Message + 'a,
+    ) -> Self {
+        self.popup = Some(Popup {
+            parent,
+            id,
+            on_open: Box::new(on_open),
+        });
+        self
+    }
+
+    /// Sets the style of the menu of the [`ContextMenu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<cascade::State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = cascade::State::new();
+
+        if self.is_open {
+            state.open(Point::ORIGIN);
+        }
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) =
+            event
+        else {
+            return event::Status::Ignored;
+        };
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return event::Status::Ignored;
+        };
+
+        if self.items.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        #[cfg(feature = "wayland")]
+        if let Some(popup) = &self.popup {
+            let size = cascade::Metrics::new(
+                renderer,
+                self.padding,
+                self.text_size,
+                text::LineHeight::default(),
+                self.font,
+            )
+            .size(renderer, &self.items);
+
+            shell.publish((popup.on_open)(SctkPopupSettings {
+                parent: popup.parent,
+                id: popup.id,
+                positioner: SctkPositioner {
+                    size: Some((
+                        size.width.ceil() as u32,
+                        size.height.ceil() as u32,
+                    )),
+                    anchor_rect: Rectangle {
+                        x: position.x as i32,
+                        y: position.y as i32,
+                        width: 1,
+                        height: 1,
+                    },
+                    anchor: Anchor::TopLeft,
+                    gravity: Gravity::BottomRight,
+                    ..Default::default()
+                },
+                parent_size: None,
+                grab: true,
+            }));
+
+            return event::Status::Captured;
+        }
+
+        tree.state.downcast_mut::<cascade::State>().open(position);
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<cascade::State>();
+
+        if !state.is_open() {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            );
+        }
+
+        let mut cascade = Cascade::new(state, &self.items)
+            .padding(self.padding)
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            cascade = cascade.text_size(text_size);
+        }
+
+        if let Some(font) = self.font {
+            cascade = cascade.font(font);
+        }
+
+        if let Some(on_close) = &self.on_close {
+            cascade = cascade.on_close(on_close.clone());
+        }
+
+        Some(cascade.overlay())
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &tree.children[0], cursor)
+    }
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
@@ -0,0 +1,705 @@
+//! Distribute content in rows and columns that line up.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, OperationOutputWrapper, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+/// A container that places its contents at cells of a grid.
+///
+/// The size of every column and row track is defined by a [`Length`]:
+///
+/// - [`Length::Fixed`] tracks take the given amount of pixels.
+/// - [`Length::Shrink`] tracks take the space their contents need.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the space
+///   left by the others. If there is no limit to the available space, they
+///   behave like [`Length::Shrink`].
+///
+/// Children placed past the defined tracks add [`Length::Shrink`] tracks to
+/// the [`Grid`].
+///
+/// # Example
+/// ```no_run
+/// # type Grid<'a, Message> =
+/// #     iced_widget::Grid<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Text<'a> = iced_widget::Text<'a, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type TextInput<'a, Message> = iced_widget::TextInput<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::core::Length;
+/// use iced_widget::grid::Cell;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NameChanged(String),
+///     EmailChanged(String),
+/// }
+///
+/// let form: Grid<'_, Message> = Grid::new()
+///     .columns([Length::Shrink, Length::Fill])
+///     .spacing(10)
+///     .push((0, 0), Text::new("Name"))
+///     .push((0, 1), TextInput::new("", "").on_input(Message::NameChanged))
+///     .push((1, 0), Text::new("Email"))
+///     .push((1, 1), TextInput::new("", "").on_input(Message::EmailChanged))
+///     .push(Cell::new(2, 0).column_span(2), Text::new("All fields are required"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer = crate::Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    cells: Vec<Cell>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Grid {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            cells: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the column tracks of the [`Grid`].
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = Length>,
+    ) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Length>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the spacing _between_ both columns and rows.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into().0;
+
+        self.column_spacing = amount;
+        self.row_spacing = amount;
+        self
+    }
+
+    /// Sets the spacing _between_ columns.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the spacing _between_ rows.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents within their
+    /// [`Cell`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents within their
+    /// [`Cell`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] at the given [`Cell`].
+    pub fn push(
+        mut self,
+        cell: impl Into<Cell>,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.cells.push(cell.into());
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The placement of a child of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    horizontal_alignment: Option<alignment::Horizontal>,
+    vertical_alignment: Option<alignment::Vertical>,
+}
+
+impl Cell {
+    /// Creates a [`Cell`] at the given row and column, starting from zero.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: None,
+            vertical_alignment: None,
+        }
+    }
+
+    /// Sets the amount of rows the [`Cell`] spans.
+    pub fn row_span(mut self, span: usize) -> Self {
+        self.row_span = span.max(1);
+        self
+    }
+
+    /// Sets the amount of columns the [`Cell`] spans.
+    pub fn column_span(mut self, span: usize) -> Self {
+        self.column_span = span.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`],
+    /// overriding the one of the [`Grid`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`],
+    /// overriding the one of the [`Grid`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+}
+
+impl From<(usize, usize)> for Cell {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::new(row, column)
+    }
+}
+
+/// The tracks a child spans along an axis, and the space it needs there.
+///
+/// A child that fills the axis needs no specific space.
+struct Span {
+    start: usize,
+    len: usize,
+    size: Option<f32>,
+}
+
+/// Computes the size of each track along an axis.
+fn resolve_tracks(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    spans: &[Span],
+) -> Vec<f32> {
+    let is_fill =
+        |track: &Length| track.fill_factor() > 0 && available.is_finite();
+    let is_auto = |track: &Length| {
+        matches!(track, Length::Shrink)
+            || (track.fill_factor() > 0 && !available.is_finite())
+    };
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Fixed(amount) => *amount,
+            _ => 0.0,
+        })
+        .collect();
+
+    for span in spans.iter().filter(|span| span.len == 1) {
+        if let Some(size) = span.size {
+            if is_auto(&tracks[span.start]) {
+                sizes[span.start] = sizes[span.start].max(size);
+            }
+        }
+    }
+
+    // Children spanning many tracks grow the auto ones among them evenly,
+    // unless a fill track can take the space instead
+    for span in spans.iter().filter(|span| span.len > 1) {
+        let Some(size) = span.size else {
+            continue;
+        };
+
+        let range = span.start..span.start + span.len;
+
+        if tracks[range.clone()].iter().any(is_fill) {
+            continue;
+        }
+
+        let auto: Vec<usize> =
+            range.clone().filter(|&i| is_auto(&tracks[i])).collect();
+
+        let current =
+            sizes[range].iter().sum::<f32>() + spacing * (span.len - 1) as f32;
+
+        if !auto.is_empty() && size > current {
+            let extra = (size - current) / auto.len() as f32;
+
+            for i in auto {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    let total_factor: u32 = tracks
+        .iter()
+        .filter(|track| is_fill(track))
+        .map(|track| u32::from(track.fill_factor()))
+        .sum();
+
+    if total_factor > 0 {
+        let used = sizes.iter().sum::<f32>()
+            + spacing * tracks.len().saturating_sub(1) as f32;
+        let remaining = (available - used).max(0.0);
+
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if is_fill(track) {
+                *size = remaining * f32::from(track.fill_factor())
+                    / total_factor as f32;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Computes the offset of each track, given their sizes.
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let current = *offset;
+            *offset += size + spacing;
+
+            Some(current)
+        })
+        .collect()
+}
+
+/// Returns the total size of the tracks in `start..start + len`.
+fn span_size(sizes: &[f32], spacing: f32, start: usize, len: usize) -> f32 {
+    sizes[start..start + len].iter().sum::<f32>() + spacing * (len - 1) as f32
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.children)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.pad(self.padding).max();
+
+        let column_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.column + cell.column_span)
+            .fold(self.columns.len(), usize::max);
+
+        let row_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .fold(self.rows.len(), usize::max);
+
+        let columns: Vec<Length> = self
+            .columns
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(Length::Shrink))
+            .take(column_count)
+            .collect();
+
+        let rows: Vec<Length> = self
+            .rows
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(Length::Shrink))
+            .take(row_count)
+            .collect();
+
+        let is_fill = |length: Length| length.fill_factor() > 0;
+
+        // Columns are sized first, so the rows can be sized with the final
+        // width of their contents
+        let column_spans: Vec<Span> = self
+            .children
+            .iter()
+            .zip(&self.cells)
+            .map(|(child, cell)| Span {
+                start: cell.column,
+                len: cell.column_span,
+                size: (!is_fill(child.as_widget().width())).then(|| {
+                    child
+                        .as_widget()
+                        .layout(renderer, &layout::Limits::new(Size::ZERO, max))
+                        .size()
+                        .width
+                }),
+            })
+            .collect();
+
+        let widths = resolve_tracks(
+            &columns,
+            max.width,
+            self.column_spacing,
+            &column_spans,
+        );
+
+        let cell_width = |cell: &Cell| {
+            span_size(
+                &widths,
+                self.column_spacing,
+                cell.column,
+                cell.column_span,
+            )
+        };
+
+        let row_spans: Vec<Span> = self
+            .children
+            .iter()
+            .zip(&self.cells)
+            .map(|(child, cell)| Span {
+                start: cell.row,
+                len: cell.row_span,
+                size: (!is_fill(child.as_widget().height())).then(|| {
+                    child
+                        .as_widget()
+                        .layout(
+                            renderer,
+                            &layout::Limits::new(
+                                Size::ZERO,
+                                Size::new(cell_width(cell), max.height),
+                            ),
+                        )
+                        .size()
+                        .height
+                }),
+            })
+            .collect();
+
+        let heights =
+            resolve_tracks(&rows, max.height, self.row_spacing, &row_spans);
+
+        let column_offsets = offsets(&widths, self.column_spacing);
+        let row_offsets = offsets(&heights, self.row_spacing);
+
+        let children = self
+            .children
+            .iter()
+            .zip(&self.cells)
+            .map(|(child, cell)| {
+                let size = Size::new(
+                    cell_width(cell),
+                    span_size(
+                        &heights,
+                        self.row_spacing,
+                        cell.row,
+                        cell.row_span,
+                    ),
+                );
+
+                let mut node = child
+                    .as_widget()
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+                node.move_to(Point::new(
+                    self.padding.left + column_offsets[cell.column],
+                    self.padding.top + row_offsets[cell.row],
+                ));
+
+                node.align(
+                    Alignment::from(
+                        cell.horizontal_alignment
+                            .unwrap_or(self.horizontal_alignment),
+                    ),
+                    Alignment::from(
+                        cell.vertical_alignment
+                            .unwrap_or(self.vertical_alignment),
+                    ),
+                    size,
+                );
+
+                node
+            })
+            .collect();
+
+        let intrinsic_size = Size::new(
+            widths.iter().sum::<f32>()
+                + self.column_spacing * widths.len().saturating_sub(1) as f32,
+            heights.iter().sum::<f32>()
+                + self.row_spacing * heights.len().saturating_sub(1) as f32,
+        );
+
+        layout::Node::with_children(
+            limits.resolve(intrinsic_size.pad(self.padding)),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| {
+                    c.as_widget().a11y_nodes(c_layout, state, p)
+                }),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    fn bounds(node: &layout::Node) -> Vec<Rectangle> {
+        node.children().iter().map(layout::Node::bounds).collect()
+    }
+
+    #[test]
+    fn fill_tracks_share_the_remaining_space() {
+        let grid: Grid<'_, (), Null> = Grid::new()
+            .columns([Length::Fixed(50.0), Length::Fill])
+            .spacing(10)
+            .push((0, 0), Space::new(30.0, 20.0))
+            .push((0, 1), Space::new(Length::Fill, 30.0))
+            .push(
+                Cell::new(1, 0).column_span(2),
+                Space::new(Length::Fill, 10.0),
+            );
+
+        let node = grid.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, f32::INFINITY)),
+        );
+
+        assert_eq!(node.size(), Size::new(200.0, 50.0));
+        assert_eq!(
+            bounds(&node),
+            vec![
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(30.0, 20.0)),
+                Rectangle::new(Point::new(60.0, 0.0), Size::new(140.0, 30.0)),
+                Rectangle::new(Point::new(0.0, 40.0), Size::new(200.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn spanning_children_grow_auto_tracks() {
+        let grid: Grid<'_, (), Null> = Grid::new()
+            .spacing(10)
+            .align_y(alignment::Vertical::Center)
+            .push((0, 0), Space::new(20.0, 10.0))
+            .push((0, 1), Space::new(30.0, 20.0))
+            .push(Cell::new(1, 0).column_span(2), Space::new(100.0, 10.0));
+
+        let node = grid.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(100.0, 40.0));
+        assert_eq!(
+            bounds(&node),
+            vec![
+                Rectangle::new(Point::new(0.0, 5.0), Size::new(20.0, 10.0)),
+                Rectangle::new(Point::new(50.0, 0.0), Size::new(30.0, 20.0)),
+                Rectangle::new(Point::new(0.0, 30.0), Size::new(100.0, 10.0)),
+            ]
+        );
+    }
+}
@@ -1,22 +1,37 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::{self, ContextMenu};
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels};
+use crate::grid::Grid;
+use crate::menu_bar::{self, MenuBar};
+use crate::modal::{self, Modal};
+use crate::number_input::{self, NumberInput};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::rich_text::{RichText, Span};
 use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::stack::Stack;
+use crate::table::{self, Table};
+use crate::tabs::{self, Tabs};
 use crate::text::{self, Text};
+use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::toast::{self, Toast};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
+use crate::virtual_list::{RowHeight, VirtualList};
+use crate::wrap::Wrap;
 use crate::{Column, MouseArea, Row, Space, VerticalSlider};
 
 #[cfg(feature = "wayland")]
@@ -53,6 +68,33 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Stack`] with the given children, from the bottom-most layer
+/// to the top-most one.
+///
+/// [`Stack`]: widget::Stack
+#[macro_export]
+macro_rules! stack {
+    () => (
+        $crate::Stack::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Stack::with_children(vec![$($crate::core::Element::from($x)),+])
+    );
+}
+
+/// Creates a [`Wrap`] with the given children.
+///
+/// [`Wrap`]: widget::Wrap
+#[macro_export]
+macro_rules! wrap {
+    () => (
+        $crate::Wrap::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Wrap::with_children(vec![$($crate::core::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: widget::Container
@@ -66,6 +108,62 @@ where
     Container::new(content)
 }
 
+/// Creates a new [`ContextMenu`] wrapping the given content with the items
+/// of its menu.
+///
+/// [`ContextMenu`]: widget::ContextMenu
+pub fn context_menu<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    items: impl IntoIterator<Item = context_menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: context_menu::StyleSheet,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: widget::MenuBar
+pub fn menu_bar<Message, Renderer>(
+    menus: impl IntoIterator<Item = menu_bar::Menu<Message>>,
+) -> MenuBar<Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: menu_bar::StyleSheet,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`Modal`] showing the given content over the base.
+///
+/// [`Modal`]: widget::Modal
+pub fn modal<'a, Message, Renderer>(
+    base: impl Into<Element<'a, Message, Renderer>>,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Modal<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: modal::StyleSheet,
+{
+    Modal::new(base, content)
+}
+
+/// Creates a new [`toast::Manager`] showing the given [`Toast`]s over some
+/// content.
+pub fn toasts<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    toasts: &'a [Toast],
+    on_close: impl Fn(usize) -> Message + 'a,
+) -> toast::Manager<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: toast::StyleSheet,
+{
+    toast::Manager::new(content, toasts, on_close)
+}
+
 /// Creates a new [`Column`] with the given children.
 ///
 /// [`Column`]: widget::Column
@@ -84,6 +182,34 @@ pub fn row<Message, Renderer>(
     Row::with_children(children)
 }
 
+/// Creates a new [`Stack`] with the given children, from the bottom-most
+/// layer to the top-most one.
+///
+/// [`Stack`]: widget::Stack
+pub fn stack<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> Stack<'_, Message, Renderer> {
+    Stack::with_children(children)
+}
+
+/// Creates a new [`Grid`] with the given column tracks.
+///
+/// [`Grid`]: widget::Grid
+pub fn grid<'a, Message, Renderer>(
+    columns: impl IntoIterator<Item = Length>,
+) -> Grid<'a, Message, Renderer> {
+    Grid::new().columns(columns)
+}
+
+/// Creates a new [`Wrap`] with the given children.
+///
+/// [`Wrap`]: widget::Wrap
+pub fn wrap<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> Wrap<'_, Message, Renderer> {
+    Wrap::with_children(children)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: widget::Scrollable
@@ -97,6 +223,67 @@ where
     Scrollable::new(content)
 }
 
+/// Creates a new [`Table`] with the given columns and rows.
+///
+/// [`Table`]: widget::Table
+pub fn table<'a, T, Message, Renderer>(
+    columns: impl IntoIterator<Item = table::Column<'a, T, Message, Renderer>>,
+    rows: impl IntoIterator<Item = T>,
+) -> Table<'a, Message, Renderer>
+where
+    T: Clone,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: table::StyleSheet,
+{
+    Table::new(columns, rows)
+}
+
+/// Creates a new [`Tabs`] widget with the given tabs and the index of the
+/// active one.
+///
+/// [`Tabs`]: widget::Tabs
+pub fn tabs<'a, Message, Renderer>(
+    tabs: impl IntoIterator<Item = tabs::Tab<'a, Message, Renderer>>,
+    active: usize,
+) -> Tabs<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: tabs::StyleSheet,
+{
+    Tabs::new(tabs, active)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: widget::TreeView
+pub fn tree_view<'a, Key, Message, Renderer>(
+    roots: impl IntoIterator<Item = tree_view::Node<'a, Key, Message, Renderer>>,
+) -> TreeView<'a, Key, Message, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: tree_view::StyleSheet,
+{
+    TreeView::new(roots)
+}
+
+/// Creates a new [`VirtualList`] with the given amount of rows, the
+/// [`RowHeight`] policy and a closure that produces the row at an index.
+///
+/// [`VirtualList`]: widget::VirtualList
+/// [`RowHeight`]: widget::virtual_list::RowHeight
+pub fn virtual_list<'a, Message, Renderer>(
+    count: usize,
+    row_height: impl Into<RowHeight>,
+    view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> VirtualList<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: scrollable::StyleSheet,
+{
+    VirtualList::new(count, row_height, view)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: widget::Button
@@ -138,6 +325,29 @@ where
     Text::new(text.to_string())
 }
 
+/// Creates a new [`RichText`] with the given [`Span`]s.
+///
+/// [`RichText`]: widget::RichText
+/// [`Span`]: widget::rich_text::Span
+pub fn rich_text<'a, Link, Message, Renderer>(
+    spans: impl IntoIterator<Item = Span<'a, Link>>,
+) -> RichText<'a, Link, Message, Renderer>
+where
+    Link: Clone,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: text::StyleSheet,
+{
+    RichText::new(spans)
+}
+
+/// Creates a new [`Span`] of a [`RichText`] with the given text.
+///
+/// [`Span`]: widget::rich_text::Span
+/// [`RichText`]: widget::RichText
+pub fn span<'a, Link>(text: impl Into<Cow<'a, str>>) -> Span<'a, Link> {
+    Span::new(text)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: widget::Checkbox
@@ -156,12 +366,12 @@ where
 /// Creates a new [`Radio`].
 ///
 /// [`Radio`]: widget::Radio
-pub fn radio<Message, Renderer, V>(
+pub fn radio<'a, Message, Renderer, V>(
     label: impl Into<String>,
     value: V,
     selected: Option<V>,
     on_click: impl FnOnce(V) -> Message,
-) -> Radio<Message, Renderer>
+) -> Radio<'a, Message, Renderer>
 where
     Message: Clone,
     Renderer: core::text::Renderer,
@@ -201,6 +411,40 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`TextEditor`] displaying the given [`Content`].
+///
+/// [`TextEditor`]: widget::TextEditor
+/// [`Content`]: text_editor::Content
+pub fn text_editor<'a, Message, Renderer>(
+    content: &'a text_editor::Content,
+) -> TextEditor<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_editor::StyleSheet,
+{
+    TextEditor::new(content)
+}
+
+/// Creates a new [`NumberInput`] with the given value and the message to
+/// produce when it changes.
+///
+/// [`NumberInput`]: widget::NumberInput
+pub fn number_input<'a, T, Message, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + PartialOrd
+        + Copy
+        + std::fmt::Display,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + number_input::StyleSheet,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: widget::Slider
@@ -257,6 +501,23 @@ where
     PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`ComboBox`].
+///
+/// [`ComboBox`]: widget::ComboBox
+pub fn combo_box<'a, T, Message, Renderer>(
+    state: &'a combo_box::State<T>,
+    placeholder: &str,
+    selection: Option<&T>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> ComboBox<'a, T, Message, Renderer>
+where
+    T: std::fmt::Display + Clone,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + overlay::menu::StyleSheet,
+{
+    ComboBox::new(state, placeholder, selection, on_selected)
+}
+
 /// Creates a new horizontal [`Space`] with the given [`Length`].
 ///
 /// [`Space`]: widget::Space
@@ -300,10 +561,10 @@ where
 ///   * the current value of the [`ProgressBar`].
 ///
 /// [`ProgressBar`]: widget::ProgressBar
-pub fn progress_bar<Renderer>(
+pub fn progress_bar<'a, Renderer>(
     range: RangeInclusive<f32>,
     value: f32,
-) -> ProgressBar<Renderer>
+) -> ProgressBar<'a, Renderer>
 where
     Renderer: core::Renderer,
     Renderer::Theme: progress_bar::StyleSheet,
@@ -294,6 +294,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
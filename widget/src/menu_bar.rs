@@ -0,0 +1,690 @@
+//! Display the menus of an application in a bar.
+//!
+//! A [`MenuBar`] opens its menus when their title is clicked or when its
+//! mnemonic is pressed together with Alt. While a menu is open, Left and
+//! Right move to the previous and next menus.
+//!
+//! The shortcuts of the items of a [`MenuBar`] are handled even when their
+//! menu is closed, as long as the [`MenuBar`] is displayed.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+use crate::overlay::cascade::{self, Cascade, Label};
+
+pub use crate::overlay::cascade::{Appearance, Item, StyleSheet};
+
+#[cfg(feature = "a11y")]
+use std::cell::RefCell;
+
+/// A menu of a [`MenuBar`].
+///
+/// Like the labels of its items, an ampersand in the title of a [`Menu`]
+/// marks the next character as its mnemonic.
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    title: Label,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    pub fn new(
+        title: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            title: Label::parse(title.as_ref()),
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Returns the title of the [`Menu`], without its mnemonic marker.
+    pub fn title(&self) -> &str {
+        &self.title.text
+    }
+
+    /// Returns the items of the [`Menu`].
+    pub fn items(&self) -> &[Item<Message>] {
+        &self.items
+    }
+}
+
+/// A bar of menus.
+///
+/// # Example
+/// ```no_run
+/// # type Renderer = iced_widget::renderer::Renderer<iced_widget::style::Theme>;
+/// # type MenuBar<Message> = iced_widget::MenuBar<Message, Renderer>;
+/// use iced_widget::core::keyboard::{KeyCode, Modifiers};
+/// use iced_widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Quit,
+///     ToggleWrap,
+///     Zoom(u32),
+/// }
+///
+/// let wrap = true;
+/// let zoom = 100;
+///
+/// let menu_bar: MenuBar<Message> = MenuBar::new([
+///     Menu::new(
+///         "&File",
+///         [
+///             Item::new("&Save", Message::Save)
+///                 .shortcut(Modifiers::CTRL, KeyCode::S),
+///             Item::separator(),
+///             Item::new("&Quit", Message::Quit)
+///                 .shortcut(Modifiers::CTRL, KeyCode::Q),
+///         ],
+///     ),
+///     Menu::new(
+///         "&View",
+///         [
+///             Item::checkbox("&Wrap Lines", wrap, Message::ToggleWrap),
+///             Item::submenu(
+///                 "&Zoom",
+///                 [100, 150, 200].map(|level| {
+///                     Item::radio(
+///                         format!("{level}%"),
+///                         zoom == level,
+///                         Message::Zoom(level),
+///                     )
+///                 }),
+///             ),
+///         ],
+///     ),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    item_padding: Padding,
+    text_size: Option<f32>,
+    font: Option<Renderer::Font>,
+    on_close: Option<Message>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<Message>>) -> Self {
+        Self {
+            id: Id::unique(),
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Padding::from([5, 10]),
+            item_padding: Padding::from([5, 10]),
+            text_size: None,
+            font: None,
+            on_close: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`MenuBar`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the menus of the [`MenuBar`].
+    pub fn item_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.item_padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a menu is dismissed
+    /// without choosing an item.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn font_or_default(&self, renderer: &Renderer) -> Renderer::Font {
+        self.font.unwrap_or_else(|| renderer.default_font())
+    }
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Opens the menu with the given index below its title.
+    fn open(
+        &self,
+        state: &mut State,
+        layout: Layout<'_>,
+        index: usize,
+        from_keyboard: bool,
+    ) {
+        let Some(title) = layout.children().nth(index) else {
+            return;
+        };
+
+        let bounds = title.bounds();
+
+        state.menu = Some(index);
+        state
+            .cascade
+            .open(Point::new(bounds.x, bounds.y + bounds.height));
+
+        if from_keyboard {
+            state.cascade.focus_first(&self.menus[index].items);
+        }
+    }
+
+    fn close(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if let Some(on_close) = &self.on_close {
+            shell.publish(on_close.clone());
+        }
+
+        state.menu = None;
+        state.cascade.close();
+    }
+
+    /// Returns the index of the menu with the given mnemonic, if any.
+    fn menu_with_mnemonic(&self, key_code: keyboard::KeyCode) -> Option<usize> {
+        let c = cascade::key_char(key_code)?;
+
+        self.menus
+            .iter()
+            .position(|menu| menu.title.mnemonic() == Some(c))
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size_or_default(renderer);
+        let font = self.font_or_default(renderer);
+        let height = f32::from(
+            text::LineHeight::default().to_absolute(Pixels(text_size)),
+        ) + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = renderer.measure_width(
+                    &menu.title.text,
+                    text_size,
+                    font,
+                    text::Shaping::Advanced,
+                ) + self.padding.horizontal();
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The menu may have been closed by choosing an item or dismissing it
+        if !state.cascade.is_open() {
+            state.menu = None;
+        }
+
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest { action, .. },
+        ) = &event
+        {
+            use iced_accessibility::accesskit::Action as A11yAction;
+
+            let index = state
+                .title_ids
+                .borrow()
+                .iter()
+                .take(self.menus.len())
+                .position(|id| id == event_id);
+
+            if let Some(index) = index {
+                if matches!(action, A11yAction::Default) {
+                    if state.menu == Some(index) {
+                        self.close(state, shell);
+                    } else {
+                        self.open(state, layout, index, true);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let hovered_title = cursor.position().and_then(|position| {
+            layout
+                .children()
+                .position(|title| title.bounds().contains(position))
+        });
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = hovered_title else {
+                    return event::Status::Ignored;
+                };
+
+                if state.menu == Some(index) {
+                    self.close(state, shell);
+                } else {
+                    self.open(state, layout, index, false);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                match (state.menu, hovered_title) {
+                    (Some(open), Some(index)) if open != index => {
+                        self.open(state, layout, index, false);
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                let shortcut = self.menus.iter().find_map(|menu| {
+                    cascade::find_shortcut(&menu.items, modifiers, key_code)
+                });
+
+                if let Some(message) = shortcut {
+                    shell.publish(message.clone());
+
+                    state.menu = None;
+                    state.cascade.close();
+
+                    return event::Status::Captured;
+                }
+
+                if modifiers.alt() && !modifiers.control() {
+                    if let Some(index) = self.menu_with_mnemonic(key_code) {
+                        self.open(state, layout, index, true);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                // The open menu ignores Left and Right when there is no
+                // submenu to move into or out of
+                let Some(open) = state.menu else {
+                    return event::Status::Ignored;
+                };
+
+                let count = self.menus.len();
+
+                let index = match key_code {
+                    keyboard::KeyCode::Left => (open + count - 1) % count,
+                    keyboard::KeyCode::Right => (open + 1) % count,
+                    _ => return event::Status::Ignored,
+                };
+
+                self.open(state, layout, index, true);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let text_size = self.text_size_or_default(renderer);
+        let font = self.font_or_default(renderer);
+
+        let open = state.menu.filter(|_| state.cascade.is_open());
+        let show_mnemonics = state.modifiers.alt() || open.is_some();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: Color::TRANSPARENT,
+                border_width: 0.0,
+                border_radius: 0.0.into(),
+            },
+            appearance.background,
+        );
+
+        if appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - appearance.border_width,
+                        height: appearance.border_width,
+                        ..bounds
+                    },
+                    border_color: Color::TRANSPARENT,
+                    border_width: 0.0,
+                    border_radius: 0.0.into(),
+                },
+                appearance.border_color,
+            );
+        }
+
+        for (index, (menu, title)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let title_bounds = title.bounds();
+
+            let is_highlighted = match open {
+                Some(open) => open == index,
+                None => cursor.is_over(title_bounds),
+            };
+
+            let color = if is_highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: title_bounds,
+                        border_color: Color::TRANSPARENT,
+                        border_width: 0.0,
+                        border_radius: appearance.border_radius,
+                    },
+                    appearance.selected_background,
+                );
+
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            let position = Point::new(
+                title_bounds.x + self.padding.left,
+                title_bounds.center_y(),
+            );
+
+            renderer.fill_text(Text {
+                content: &menu.title.text,
+                bounds: Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    width: f32::INFINITY,
+                    ..title_bounds
+                },
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font,
+                color,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if show_mnemonics {
+                menu.title
+                    .draw_mnemonic(renderer, position, text_size, font, color);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.cascade.is_open() {
+            return None;
+        }
+
+        let menu = self.menus.get(state.menu?)?;
+
+        let mut cascade = Cascade::new(&mut state.cascade, &menu.items)
+            .padding(self.item_padding)
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            cascade = cascade.text_size(text_size);
+        }
+
+        if let Some(font) = self.font {
+            cascade = cascade.font(font);
+        }
+
+        if let Some(on_close) = &self.on_close {
+            cascade = cascade.on_close(on_close.clone());
+        }
+
+        Some(cascade.overlay())
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action as A11yAction, NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let open = state.menu.filter(|_| state.cascade.is_open());
+
+        let mut title_ids = state.title_ids.borrow_mut();
+
+        while title_ids.len() < self.menus.len() {
+            title_ids.push(Id::unique());
+        }
+
+        let titles = self
+            .menus
+            .iter()
+            .zip(layout.children())
+            .zip(title_ids.iter())
+            .enumerate()
+            .map(|(index, ((menu, title), id))| {
+                let bounds = title.bounds();
+
+                let mut node = NodeBuilder::new(Role::MenuItem);
+                node.set_bounds(rect(bounds));
+                node.set_name(menu.title.text.clone());
+                node.set_expanded(open == Some(index));
+                node.add_action(A11yAction::Default);
+
+                if cursor.is_over(bounds) {
+                    node.set_hovered();
+                }
+
+                A11yTree::leaf(node, id.clone())
+            });
+
+        let mut menu_bar = NodeBuilder::new(Role::MenuBar);
+        menu_bar.set_bounds(rect(layout.bounds()));
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(menu_bar, self.id.clone()),
+            A11yTree::join(titles),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Default)]
+struct State {
+    menu: Option<usize>,
+    cascade: cascade::State,
+    modifiers: keyboard::Modifiers,
+    #[cfg(feature = "a11y")]
+    title_ids: RefCell<Vec<Id>>,
+}
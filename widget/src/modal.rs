@@ -0,0 +1,654 @@
+//! Show a dialog over some content, blocking any interaction with it.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{
+    Focusable, OperationOutputWrapper, Scrollable, TextInput,
+};
+use crate::core::widget::{tree, Id, Operation, Tree};
+use crate::core::{
+    Alignment, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::style::modal::{Appearance, StyleSheet};
+
+use std::any::Any;
+#[cfg(feature = "a11y")]
+use std::cell::RefCell;
+
+/// A widget that shows a dialog over some base content.
+///
+/// While a [`Modal`] is shown, its base content cannot be interacted with:
+/// a backdrop covers it, and the keyboard focus stays inside the dialog.
+/// <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd> move the focus between the
+/// widgets of the dialog.
+///
+/// # Example
+/// ```no_run
+/// # type Modal<'a, Message> =
+/// #     iced_widget::Modal<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Text<'a> = iced_widget::Text<'a, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseDialog,
+/// }
+///
+/// let modal: Modal<'_, Message> =
+///     Modal::new(Text::new("Content"), Text::new("Are you sure?"))
+///         .on_dismiss(Message::CloseDialog);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    base: Element<'a, Message, Renderer>,
+    content: Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+    padding: Padding,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Modal`] showing the given dialog content over some
+    /// base content.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Self {
+            id: Id::unique(),
+            base: base.into(),
+            content: content.into(),
+            on_dismiss: None,
+            padding: Padding::ZERO,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message produced when the [`Modal`] is dismissed, either by
+    /// pressing the backdrop or the <kbd>Escape</kbd> key.
+    ///
+    /// Unless `on_dismiss` is called, the [`Modal`] can only be closed by
+    /// its content.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the [`Padding`] between the dialog and the edges of the
+    /// [`Modal`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the alignment of the dialog in the horizontal axis.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the alignment of the dialog in the vertical axis.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the [`Id`] of the dialog of the [`Modal`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+}
+
+/// The local state of a [`Modal`].
+#[derive(Debug, Default)]
+struct State {
+    /// The layout of the dialog in the last frame, used to describe it to
+    /// assistive technologies.
+    #[cfg(feature = "a11y")]
+    content_layout: RefCell<Option<layout::Node>>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.base), Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [&mut self.base, &mut self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.base.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.base.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut Unfocus { operation },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.base.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        #[cfg(feature = "a11y")]
+        let state = tree.state.downcast_ref::<State>();
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Overlay {
+                content: &mut self.content,
+                tree: &mut tree.children[1],
+                size: layout.bounds().size(),
+                on_dismiss: self.on_dismiss.clone(),
+                padding: self.padding,
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                style: &self.style,
+                #[cfg(feature = "a11y")]
+                content_layout: &state.content_layout,
+            }),
+        ))
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let base =
+            self.base
+                .as_widget()
+                .a11y_nodes(layout, &tree.children[0], cursor);
+
+        let state = tree.state.downcast_ref::<State>();
+        let content_layout = state.content_layout.borrow();
+
+        let Some(content_layout) = content_layout.as_ref() else {
+            return base;
+        };
+
+        let content_layout = Layout::new(content_layout);
+        let bounds = content_layout.bounds();
+
+        let content = self.content.as_widget().a11y_nodes(
+            content_layout,
+            &tree.children[1],
+            cursor,
+        );
+
+        let mut dialog = NodeBuilder::new(Role::Dialog);
+        dialog.set_modal();
+        dialog.set_bounds(Rect::new(
+            bounds.x as f64,
+            bounds.y as f64,
+            (bounds.x + bounds.width) as f64,
+            (bounds.y + bounds.height) as f64,
+        ));
+
+        A11yTree::join(
+            [
+                base,
+                A11yTree::node_with_child_tree(
+                    A11yNode::new(dialog, self.id.clone()),
+                    content,
+                ),
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    on_dismiss: Option<Message>,
+    padding: Padding,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+    #[cfg(feature = "a11y")]
+    content_layout: &'b RefCell<Option<layout::Node>>,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Moves the focus to the next or previous focusable widget of the
+    /// dialog, wrapping around at its ends.
+    fn cycle_focus(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        forward: bool,
+    ) {
+        let mut cycle = Cycle::default();
+
+        self.content
+            .as_widget()
+            .operate(self.tree, layout, renderer, &mut cycle);
+
+        if cycle.total == 0 {
+            return;
+        }
+
+        let total = cycle.total;
+
+        cycle.target = Some(match (cycle.focused, forward) {
+            (None, true) => 0,
+            (None, false) => total - 1,
+            (Some(focused), true) => (focused + 1) % total,
+            (Some(focused), false) => (focused + total - 1) % total,
+        });
+
+        self.content
+            .as_widget()
+            .operate(self.tree, layout, renderer, &mut cycle);
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size)
+            .width(Length::Fill)
+            .height(Length::Fill);
+
+        let mut content = self
+            .content
+            .as_widget()
+            .layout(renderer, &limits.pad(self.padding).loose());
+
+        content.move_to(Point::new(self.padding.left, self.padding.top));
+        content.align(
+            Alignment::from(self.horizontal_alignment),
+            Alignment::from(self.vertical_alignment),
+            limits.pad(self.padding).max(),
+        );
+
+        #[cfg(feature = "a11y")]
+        {
+            *self.content_layout.borrow_mut() =
+                Some(content.clone().translate(crate::core::Vector::new(
+                    position.x, position.y,
+                )));
+        }
+
+        let mut node = layout::Node::with_children(self.size, vec![content]);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content_layout = layout.children().next().unwrap();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = &event
+        {
+            if !cursor.is_over(content_layout.bounds()) {
+                if let Some(message) = &self.on_dismiss {
+                    shell.publish(message.clone());
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(message) = &self.on_dismiss {
+                    shell.publish(message.clone());
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) => {
+                self.cycle_focus(content_layout, renderer, !modifiers.shift());
+
+                event::Status::Captured
+            }
+            // The base content keeps receiving window events, so it can
+            // keep animating behind the backdrop
+            Event::Window(..) => event::Status::Ignored,
+            _ => event::Status::Captured,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: Default::default(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.backdrop,
+        );
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+/// An [`Operation`] that moves the focus between the focusable widgets of
+/// a dialog.
+///
+/// The first pass counts the focusable widgets and finds the focused one.
+/// Once a `target` is set, the second pass focuses it.
+#[derive(Debug, Default)]
+struct Cycle {
+    total: usize,
+    focused: Option<usize>,
+    target: Option<usize>,
+    current: usize,
+}
+
+impl<T> Operation<T> for Cycle {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        match self.target {
+            None => {
+                if state.is_focused() {
+                    self.focused = Some(self.total);
+                }
+
+                self.total += 1;
+            }
+            Some(target) => {
+                if self.current == target {
+                    state.focus();
+                } else {
+                    state.unfocus();
+                }
+
+                self.current += 1;
+            }
+        }
+    }
+}
+
+/// Forwards an [`Operation`] to the base content of a [`Modal`], keeping
+/// its widgets from being focused while the dialog is shown.
+struct Unfocus<'a, T> {
+    operation: &'a mut dyn Operation<T>,
+}
+
+impl<'a, T> Operation<T> for Unfocus<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut Unfocus { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        state.unfocus();
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(modal: Modal<'a, Message, Renderer>) -> Self {
+        Element::new(modal)
+    }
+}
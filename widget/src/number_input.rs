@@ -0,0 +1,809 @@
+//! Let users type a number, or step it up and down.
+//!
+//! A [`NumberInput`] combines a [`TextInput`] with buttons to increment and
+//! decrement its value.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Color, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+use crate::text_input::{self, TextInput};
+
+pub use crate::style::number_input::{Appearance, StyleSheet};
+
+use std::fmt;
+
+/// A field that holds a number, which can be typed or stepped with its
+/// buttons, the arrow keys, <kbd>PageUp</kbd> and <kbd>PageDown</kbd>, or the
+/// mouse wheel while focused.
+///
+/// Typed text that does not parse, or that falls outside of the bounds of
+/// the [`NumberInput`], is shown with an invalid style and produces no
+/// messages. It is replaced by the current value once the input loses
+/// focus.
+///
+/// # Example
+/// ```no_run
+/// # type NumberInput<'a, T, Message> =
+/// #     iced_widget::NumberInput<'a, T, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VolumeChanged(f32),
+/// }
+///
+/// let volume = 0.5;
+///
+/// let number_input: NumberInput<'_, f32, Message> =
+///     NumberInput::new(volume, Message::VolumeChanged)
+///         .min(0.0)
+///         .max(1.0)
+///         .step(0.05)
+///         .precision(2);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + StyleSheet,
+{
+    id: Id,
+    text_input: TextInput<'a, InputEvent, Renderer>,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    page_step: Option<T>,
+    precision: Option<usize>,
+    formatter: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    size: Option<f32>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: num_traits::Num + num_traits::Bounded + PartialOrd + Copy + fmt::Display,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + StyleSheet,
+{
+    /// The default padding of a [`NumberInput`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`NumberInput`] with the given value and the message to
+    /// produce when it changes.
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        let id = Id::unique();
+        let text_input = TextInput::new("", &value.to_string())
+            .id(id.clone())
+            .padding(Self::DEFAULT_PADDING)
+            .on_input(InputEvent::Changed)
+            .on_submit(InputEvent::Submitted);
+
+        Self {
+            id,
+            text_input,
+            value,
+            min: None,
+            max: None,
+            step: T::one(),
+            page_step: None,
+            precision: None,
+            formatter: None,
+            on_change: Box::new(on_change),
+            size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount the value of the [`NumberInput`] changes with each
+    /// step.
+    ///
+    /// By default, it is one.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the amount the value of the [`NumberInput`] changes when
+    /// pressing <kbd>PageUp</kbd> or <kbd>PageDown</kbd>.
+    ///
+    /// By default, it is ten steps.
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    /// Sets the amount of decimal digits shown by the [`NumberInput`].
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self.formatter = None;
+        self.reset_text()
+    }
+
+    /// Sets the function used to show the value of the [`NumberInput`].
+    ///
+    /// The text it produces should parse back to the same value.
+    pub fn formatter(mut self, formatter: impl Fn(&T) -> String + 'a) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self.precision = None;
+        self.reset_text()
+    }
+
+    /// Sets the [`Id`] of the [`NumberInput`].
+    ///
+    /// The [`Id`] can be used with the [`text_input`] commands, like
+    /// [`text_input::focus`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.text_input = self.text_input.id(id.clone());
+        self.id = id;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`], including its buttons.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.size = Some(size.0);
+        self
+    }
+
+    /// Sets the font of the [`NumberInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the style of the input of the [`NumberInput`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the buttons and the invalid state of the
+    /// [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn reset_text(mut self) -> Self {
+        self.text_input.set_value(&self.format(&self.value));
+        self
+    }
+
+    fn format(&self, value: &T) -> String {
+        match (&self.formatter, self.precision) {
+            (Some(formatter), _) => formatter(value),
+            (None, Some(precision)) => format!("{value:.precision$}"),
+            (None, None) => value.to_string(),
+        }
+    }
+
+    /// Parses the given text, as long as it is a value within the bounds of
+    /// the [`NumberInput`].
+    fn parse(&self, text: &str) -> Option<T> {
+        T::from_str_radix(text.trim(), 10)
+            .ok()
+            .filter(|value| self.min.is_none_or(|min| *value >= min))
+            .filter(|value| self.max.is_none_or(|max| *value <= max))
+    }
+
+    /// Returns whether the value can be stepped in the given direction,
+    /// without leaving the bounds of the [`NumberInput`] or of its type.
+    fn can_step(&self, value: T, direction: Direction) -> bool {
+        match direction {
+            Direction::Decrement => {
+                value > self.min.unwrap_or_else(T::min_value)
+            }
+            Direction::Increment => {
+                value < self.max.unwrap_or_else(T::max_value)
+            }
+        }
+    }
+
+    /// Steps the value by the given amount, clamping it to the bounds of the
+    /// [`NumberInput`] and of its type.
+    fn stepped(&self, value: T, direction: Direction, amount: T) -> T {
+        let value = match direction {
+            Direction::Decrement => saturating_sub(value, amount),
+            Direction::Increment => saturating_add(value, amount),
+        };
+
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn page_amount(&self) -> T {
+        self.page_step.unwrap_or_else(|| {
+            (0..10).fold(T::zero(), |sum, _| saturating_add(sum, self.step))
+        })
+    }
+
+    /// Returns the value the [`NumberInput`] currently shows, which may
+    /// be ahead of its `value` while the user types or steps.
+    fn current(&self, state: &State) -> T {
+        state
+            .text
+            .as_deref()
+            .and_then(|text| self.parse(text))
+            .unwrap_or(self.value)
+    }
+
+    fn is_invalid(&self, state: &State) -> bool {
+        state
+            .text
+            .as_deref()
+            .is_some_and(|text| self.parse(text).is_none())
+    }
+}
+
+/// The messages produced by the inner [`TextInput`] of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum InputEvent {
+    Changed(String),
+    Submitted,
+}
+
+/// A step button of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Decrement,
+    Increment,
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Default)]
+struct State {
+    /// The text shown by the input, if it differs from the formatted value.
+    text: Option<String>,
+    pressed: Option<Direction>,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: num_traits::Num + num_traits::Bounded + PartialOrd + Copy + fmt::Display,
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet + StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.text_input as &dyn Widget<InputEvent, Renderer>,
+        )]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [
+            &mut self.text_input as &mut dyn Widget<InputEvent, Renderer>
+        ]);
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // Keep what the user is typing, even if it does not match the
+        // formatted value yet
+        if !is_focused {
+            state.text = None;
+        }
+
+        if let Some(text) = &state.text {
+            self.text_input.set_value(text);
+        }
+    }
+
+    fn width(&self) -> Length {
+        Widget::<InputEvent, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width());
+
+        let height = Widget::<InputEvent, Renderer>::layout(
+            &self.text_input,
+            renderer,
+            &limits,
+        )
+        .size()
+        .height;
+
+        // The buttons are square, so they line up with the input
+        let input = Widget::<InputEvent, Renderer>::layout(
+            &self.text_input,
+            renderer,
+            &limits.shrink(Size::new(2.0 * height, 0.0)),
+        );
+
+        let width = input.size().width;
+
+        let mut decrement = layout::Node::new(Size::new(height, height));
+        decrement.move_to(Point::new(width, 0.0));
+
+        let mut increment = layout::Node::new(Size::new(height, height));
+        increment.move_to(Point::new(width + height, 0.0));
+
+        layout::Node::with_children(
+            Size::new(width + 2.0 * height, height),
+            vec![input, decrement, increment],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.children[0].state.downcast_mut::<text_input::State>();
+
+        operation.focusable(state, Some(&self.id));
+        operation.text_input(state, Some(&self.id));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let input_tree = &mut tree.children[0];
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement = children.next().unwrap().bounds();
+        let increment = children.next().unwrap().bounds();
+
+        let was_focused = input_tree
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        let step = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let direction = if cursor.is_over(decrement) {
+                    Some(Direction::Decrement)
+                } else if cursor.is_over(increment) {
+                    Some(Direction::Increment)
+                } else {
+                    None
+                };
+
+                if let Some(direction) = direction {
+                    state.pressed = Some(direction);
+
+                    Some((direction, self.step))
+                } else {
+                    None
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                None
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if was_focused => match key_code {
+                keyboard::KeyCode::Down => {
+                    Some((Direction::Decrement, self.step))
+                }
+                keyboard::KeyCode::Up => {
+                    Some((Direction::Increment, self.step))
+                }
+                keyboard::KeyCode::PageDown => {
+                    Some((Direction::Decrement, self.page_amount()))
+                }
+                keyboard::KeyCode::PageUp => {
+                    Some((Direction::Increment, self.page_amount()))
+                }
+                _ => None,
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if was_focused && cursor.is_over(layout.bounds()) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 {
+                    Some((Direction::Increment, self.step))
+                } else if y < 0.0 {
+                    Some((Direction::Decrement, self.step))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        if let Some((direction, amount)) = step {
+            let current = self.current(state);
+
+            if self.can_step(current, direction) {
+                let value = self.stepped(current, direction, amount);
+                let text = self.format(&value);
+
+                self.text_input.set_value(&text);
+                state.text = Some(text);
+
+                shell.publish((self.on_change)(value));
+            }
+
+            return event::Status::Captured;
+        }
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = self.text_input.on_event(
+            input_tree,
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for message in messages {
+            match message {
+                InputEvent::Changed(text) => {
+                    if let Some(value) = self.parse(&text) {
+                        shell.publish((self.on_change)(value));
+                    }
+
+                    state.text = Some(text);
+                }
+                InputEvent::Submitted => {
+                    let text = self.format(&self.current(state));
+
+                    self.text_input.set_value(&text);
+                    state.text = Some(text);
+                }
+            }
+        }
+
+        let is_focused = input_tree
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        if was_focused && !is_focused {
+            let text = self.format(&self.current(state));
+
+            self.text_input.set_value(&text);
+            state.text = None;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        if children.any(|button| cursor.is_over(button.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        Widget::<InputEvent, Renderer>::mouse_interaction(
+            &self.text_input,
+            &tree.children[0],
+            input_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        Widget::<InputEvent, Renderer>::draw(
+            &self.text_input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        let current = self.current(state);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (direction, button) in [Direction::Decrement, Direction::Increment]
+            .into_iter()
+            .zip(children)
+        {
+            let bounds = button.bounds();
+
+            let appearance = if !self.can_step(current, direction) {
+                theme.disabled(&self.style)
+            } else if state.pressed == Some(direction) {
+                theme.pressed(&self.style)
+            } else if cursor.is_over(bounds) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            if appearance.background.is_some() || appearance.border_width > 0.0
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance.background.unwrap_or(Color::TRANSPARENT.into()),
+                );
+            }
+
+            renderer.fill_text(text::Text {
+                content: match direction {
+                    Direction::Decrement => "−",
+                    Direction::Increment => "+",
+                },
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                line_height: LineHeight::default(),
+                color: appearance.icon_color,
+                font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+            });
+        }
+
+        if self.is_invalid(state) {
+            let active = theme.active(&self.style);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: active.border_radius,
+                    border_width: active.border_width.max(1.0),
+                    border_color: theme.invalid_border_color(&self.style),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        Widget::<InputEvent, Renderer>::a11y_nodes(
+            &self.text_input,
+            layout.children().next().unwrap(),
+            &state.children[0],
+            cursor,
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + PartialOrd
+        + Copy
+        + fmt::Display
+        + 'a,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: text_input::StyleSheet + StyleSheet,
+{
+    fn from(number_input: NumberInput<'a, T, Message, Renderer>) -> Self {
+        Self::new(number_input)
+    }
+}
+
+/// Adds the amount to the value, saturating at the bounds of its type.
+fn saturating_add<T>(value: T, amount: T) -> T
+where
+    T: num_traits::Num + num_traits::Bounded + PartialOrd + Copy,
+{
+    if amount > T::zero() && value > T::max_value() - amount {
+        T::max_value()
+    } else if amount < T::zero() && value < T::min_value() - amount {
+        T::min_value()
+    } else {
+        value + amount
+    }
+}
+
+/// Subtracts the amount from the value, saturating at the bounds of its type.
+fn saturating_sub<T>(value: T, amount: T) -> T
+where
+    T: num_traits::Num + num_traits::Bounded + PartialOrd + Copy,
+{
+    if amount > T::zero() && value < T::min_value() + amount {
+        T::min_value()
+    } else if amount < T::zero() && value > T::max_value() + amount {
+        T::max_value()
+    } else {
+        value - amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Theme;
+
+    type NumberInput<'a, T> =
+        super::NumberInput<'a, T, T, crate::renderer::Renderer<Theme>>;
+
+    #[test]
+    fn parses_values_within_bounds() {
+        let number_input: NumberInput<'_, i32> =
+            NumberInput::new(5, |value| value).min(0).max(10);
+
+        assert_eq!(number_input.parse(" 7 "), Some(7));
+        assert_eq!(number_input.parse("11"), None);
+        assert_eq!(number_input.parse("-1"), None);
+        assert_eq!(number_input.parse("7a"), None);
+        assert_eq!(number_input.parse(""), None);
+    }
+
+    #[test]
+    fn steps_are_clamped_to_bounds() {
+        let number_input: NumberInput<'_, f32> =
+            NumberInput::new(0.5, |value| value)
+                .min(0.0)
+                .max(1.0)
+                .step(0.25)
+                .precision(2);
+
+        assert_eq!(number_input.stepped(0.9, Direction::Increment, 0.25), 1.0);
+        assert_eq!(number_input.stepped(0.1, Direction::Decrement, 0.25), 0.0);
+        assert_eq!(number_input.page_amount(), 2.5);
+        assert!(!number_input.can_step(1.0, Direction::Increment));
+        assert_eq!(number_input.format(&0.5), "0.50");
+    }
+
+    #[test]
+    fn steps_saturate_without_bounds() {
+        let number_input: NumberInput<'_, u32> =
+            NumberInput::new(0, |value| value).step(5);
+
+        assert!(!number_input.can_step(0, Direction::Decrement));
+        assert_eq!(number_input.stepped(0, Direction::Decrement, 5), 0);
+        assert_eq!(number_input.stepped(3, Direction::Decrement, 5), 0);
+        assert_eq!(
+            number_input.stepped(u32::MAX - 2, Direction::Increment, 5),
+            u32::MAX
+        );
+
+        let number_input: NumberInput<'_, u8> =
+            NumberInput::new(0, |value| value).step(30);
+
+        assert_eq!(number_input.page_amount(), u8::MAX);
+        assert_eq!(saturating_add(i8::MIN, -1), i8::MIN);
+        assert_eq!(saturating_sub(i8::MAX, -1), i8::MAX);
+    }
+}
@@ -0,0 +1,1251 @@
+//! Display menus whose items can open nested submenus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::{
+    Clipboard, Color, Padding, Pixels, Point, Rectangle, Shell, Size,
+};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// The height of a separator between the items of a menu.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// An item of a menu.
+///
+/// An ampersand in the label of an [`Item`] marks the next character as its
+/// mnemonic, which chooses the [`Item`] when its key is pressed while the
+/// menu is open. A literal ampersand can be written as `&&`.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: Label,
+    kind: Kind<Message>,
+    shortcut: Option<(keyboard::Modifiers, keyboard::KeyCode)>,
+    is_enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Button(Message),
+    Checkbox(Message, bool),
+    Radio(Message, bool),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] that produces the given message when chosen.
+    pub fn new(label: impl AsRef<str>, on_press: Message) -> Self {
+        Self::with_kind(label.as_ref(), Kind::Button(on_press))
+    }
+
+    /// Creates a new [`Item`] showing a check mark when checked.
+    ///
+    /// The given message is produced when the [`Item`] is chosen; it is up to
+    /// your application to toggle it.
+    pub fn checkbox(
+        label: impl AsRef<str>,
+        is_checked: bool,
+        on_toggle: Message,
+    ) -> Self {
+        Self::with_kind(label.as_ref(), Kind::Checkbox(on_toggle, is_checked))
+    }
+
+    /// Creates a new [`Item`] that is one of a group of mutually exclusive
+    /// options, showing a dot when selected.
+    ///
+    /// The given message is produced when the [`Item`] is chosen.
+    pub fn radio(
+        label: impl AsRef<str>,
+        is_selected: bool,
+        on_select: Message,
+    ) -> Self {
+        Self::with_kind(label.as_ref(), Kind::Radio(on_select, is_selected))
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(
+        label: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self::with_kind(
+            label.as_ref(),
+            Kind::Submenu(items.into_iter().collect()),
+        )
+    }
+
+    /// Creates a new [`Item`] that separates groups of items.
+    pub fn separator() -> Self {
+        Self::with_kind("", Kind::Separator)
+    }
+
+    fn with_kind(label: &str, kind: Kind<Message>) -> Self {
+        Self {
+            label: Label::parse(label),
+            kind,
+            shortcut: None,
+            is_enabled: true,
+        }
+    }
+
+    /// Sets the keyboard shortcut of the [`Item`], shown on its right.
+    ///
+    /// A [`MenuBar`] chooses the [`Item`] when the key combination is
+    /// pressed, even if its menu is closed.
+    ///
+    /// [`MenuBar`]: crate::MenuBar
+    pub fn shortcut(
+        mut self,
+        modifiers: keyboard::Modifiers,
+        key_code: keyboard::KeyCode,
+    ) -> Self {
+        self.shortcut = Some((modifiers, key_code));
+        self
+    }
+
+    /// Sets whether the [`Item`] can be chosen.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    /// Returns the label of the [`Item`], without its mnemonic marker.
+    pub fn label(&self) -> &str {
+        &self.label.text
+    }
+
+    /// Returns the items of the submenu of the [`Item`], if it has one.
+    pub fn items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the text shown on the right of the [`Item`], if any.
+    fn accelerator_text(&self) -> Option<String> {
+        self.shortcut
+            .map(|(modifiers, key_code)| shortcut_text(modifiers, key_code))
+    }
+
+    /// Returns the message of the [`Item`], if it produces one when chosen.
+    fn message(&self) -> Option<&Message> {
+        match &self.kind {
+            Kind::Button(message)
+            | Kind::Checkbox(message, _)
+            | Kind::Radio(message, _) => Some(message),
+            Kind::Submenu(_) | Kind::Separator => None,
+        }
+    }
+
+    fn is_separator(&self) -> bool {
+        matches!(self.kind, Kind::Separator)
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.is_enabled && !self.is_separator()
+    }
+}
+
+/// Returns the message of the enabled item with the given shortcut, searching
+/// in submenus as well.
+pub(crate) fn find_shortcut<Message>(
+    items: &[Item<Message>],
+    modifiers: keyboard::Modifiers,
+    key_code: keyboard::KeyCode,
+) -> Option<&Message> {
+    items
+        .iter()
+        .filter(|item| item.is_enabled)
+        .find_map(|item| match item.items() {
+            Some(items) => find_shortcut(items, modifiers, key_code),
+            None => item
+                .message()
+                .filter(|_| item.shortcut == Some((modifiers, key_code))),
+        })
+}
+
+/// A label with an optional mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Label {
+    pub(crate) text: String,
+    /// The byte index of the mnemonic in the text, if any.
+    pub(crate) mnemonic: Option<usize>,
+}
+
+impl Label {
+    /// Parses a label, where an ampersand marks the next character as its
+    /// mnemonic.
+    pub(crate) fn parse(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('&', Some('&')) => {
+                    let _ = chars.next();
+                    text.push('&');
+                }
+                ('&', Some(_)) if mnemonic.is_none() => {
+                    mnemonic = Some(text.len());
+                }
+                _ => text.push(c),
+            }
+        }
+
+        Self { text, mnemonic }
+    }
+
+    /// Returns the mnemonic character of the [`Label`], in lowercase.
+    pub(crate) fn mnemonic(&self) -> Option<char> {
+        let c = self.text[self.mnemonic?..].chars().next()?;
+
+        c.to_lowercase().next()
+    }
+
+    /// Draws an underline below the mnemonic of the [`Label`], if any.
+    ///
+    /// The position is the left end of the vertical center of the text.
+    pub(crate) fn draw_mnemonic<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        position: Point,
+        size: f32,
+        font: Renderer::Font,
+        color: Color,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let Some(index) = self.mnemonic else {
+            return;
+        };
+
+        let Some(c) = self.text[index..].chars().next() else {
+            return;
+        };
+
+        let measure = |content: &str| {
+            renderer.measure_width(content, size, font, text::Shaping::Advanced)
+        };
+
+        let x = measure(&self.text[..index]);
+        let width = measure(&self.text[..index + c.len_utf8()]) - x;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: position.x + x,
+                    y: (position.y + size * 0.5).round(),
+                    width,
+                    height: 1.0,
+                },
+                border_color: Color::TRANSPARENT,
+                border_width: 0.0,
+                border_radius: 0.0.into(),
+            },
+            color,
+        );
+    }
+}
+
+/// Returns the lowercase character typed by a key, if it is a letter or a
+/// digit.
+pub(crate) fn key_char(key_code: keyboard::KeyCode) -> Option<char> {
+    // Letters are named after themselves and digits are prefixed by `Key`
+    let name = format!("{key_code:?}");
+    let mut chars = name.strip_prefix("Key").unwrap_or(&name).chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphanumeric() => {
+            Some(c.to_ascii_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the text describing a keyboard shortcut, like `Ctrl+Shift+S`.
+fn shortcut_text(
+    modifiers: keyboard::Modifiers,
+    key_code: keyboard::KeyCode,
+) -> String {
+    let mut text = String::new();
+
+    for (modifier, name) in [
+        (keyboard::Modifiers::CTRL, "Ctrl+"),
+        (keyboard::Modifiers::ALT, "Alt+"),
+        (keyboard::Modifiers::SHIFT, "Shift+"),
+        (keyboard::Modifiers::LOGO, "Super+"),
+    ] {
+        if modifiers.contains(modifier) {
+            text.push_str(name);
+        }
+    }
+
+    match key_char(key_code) {
+        Some(c) => text.push(c.to_ascii_uppercase()),
+        None => text.push_str(&format!("{key_code:?}")),
+    }
+
+    text
+}
+
+/// The local state of a [`Cascade`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    position: Point,
+    open: Vec<usize>,
+    hovered: Option<usize>,
+    show_mnemonics: bool,
+}
+
+impl State {
+    /// Creates a new, closed [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the menu at the given position.
+    pub fn open(&mut self, position: Point) {
+        *self = Self {
+            is_open: true,
+            position,
+            open: Vec::new(),
+            hovered: None,
+            show_mnemonics: false,
+        };
+    }
+
+    /// Hovers the first item of the root menu that can be chosen and shows
+    /// the mnemonics of the items, as when the menu is opened with the
+    /// keyboard.
+    pub(crate) fn focus_first<Message>(&mut self, items: &[Item<Message>]) {
+        self.hovered = next_selectable(items, None, true);
+        self.show_mnemonics = true;
+    }
+
+    /// Closes the menu and all of its submenus.
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the position the menu was opened at.
+    pub fn position(&self) -> Point {
+        self.position
+    }
+}
+
+/// A menu whose items can open submenus next to it.
+#[allow(missing_debug_implementations)]
+pub struct Cascade<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    padding: Padding,
+    text_size: Option<f32>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    on_close: Option<Message>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Cascade<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Cascade`] with the given [`State`] and items.
+    pub fn new(state: &'a mut State, items: &'a [Item<Message>]) -> Self {
+        Self {
+            state,
+            items,
+            padding: Padding::from([5, 10]),
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            on_close: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of every item of the [`Cascade`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Cascade`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the font of the [`Cascade`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Cascade`] is
+    /// dismissed without choosing an item.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Cascade`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Turns the [`Cascade`] into an overlay [`Element`] opened at the
+    /// position stored in its [`State`].
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.state.position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> Cascade<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        Metrics::new(
+            renderer,
+            self.padding,
+            self.text_size,
+            self.text_line_height,
+            self.font,
+        )
+    }
+
+    /// Returns the items of every open level, starting with the root menu.
+    fn levels(&self) -> Vec<&'a [Item<Message>]> {
+        let mut levels = vec![self.items];
+
+        for &index in &self.state.open {
+            let Some(items) = levels
+                .last()
+                .and_then(|items| items.get(index))
+                .and_then(Item::items)
+            else {
+                break;
+            };
+
+            levels.push(items);
+        }
+
+        levels
+    }
+
+    fn hit(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<(usize, Option<usize>)> {
+        let metrics = self.metrics(renderer);
+
+        self.levels()
+            .into_iter()
+            .zip(layout.children())
+            .enumerate()
+            .find_map(|(level, (items, layout))| {
+                let bounds = layout.bounds();
+
+                bounds.contains(position).then(|| {
+                    (level, metrics.item_at(items, bounds, position.y))
+                })
+            })
+    }
+
+    /// Hovers the given item, opening its submenu if it has one.
+    fn hover(&mut self, level: usize, index: usize) {
+        let Some(item) =
+            self.levels().get(level).and_then(|items| items.get(index))
+        else {
+            return;
+        };
+
+        self.state.open.truncate(level);
+        self.state.hovered = None;
+
+        if !item.is_selectable() {
+            return;
+        }
+
+        if item.items().is_some() {
+            self.state.open.push(index);
+        } else {
+            self.state.hovered = Some(index);
+        }
+    }
+
+    /// Chooses the given item, publishing its message or opening its
+    /// submenu.
+    fn choose(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(item) =
+            self.levels().get(level).and_then(|items| items.get(index))
+        else {
+            return;
+        };
+
+        if !item.is_enabled {
+            return;
+        }
+
+        match &item.kind {
+            Kind::Button(message)
+            | Kind::Checkbox(message, _)
+            | Kind::Radio(message, _) => {
+                shell.publish(message.clone());
+
+                self.state.close();
+            }
+            Kind::Submenu(items) => {
+                self.state.open.truncate(level);
+                self.state.open.push(index);
+                self.state.hovered = next_selectable(items, None, true);
+            }
+            Kind::Separator => {}
+        }
+    }
+
+    fn dismiss(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(on_close) = &self.on_close {
+            shell.publish(on_close.clone());
+        }
+
+        self.state.close();
+    }
+}
+
+impl<'a, Message, Renderer> crate::core::Overlay<Message, Renderer>
+    for Cascade<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let metrics = self.metrics(renderer);
+        let menus = self.levels();
+        let mut levels: Vec<Rectangle> = Vec::with_capacity(menus.len());
+
+        for (level, items) in menus.iter().enumerate() {
+            let size = metrics.size(renderer, items);
+
+            let (x, y) = match levels.last() {
+                None => (
+                    flip(position.x, 0.0, size.width, bounds.width),
+                    flip(position.y, 0.0, size.height, bounds.height),
+                ),
+                Some(parent) => {
+                    let offset = metrics.offset_of(
+                        menus[level - 1],
+                        self.state.open[level - 1],
+                    );
+
+                    (
+                        flip(
+                            parent.x + parent.width,
+                            parent.width,
+                            size.width,
+                            bounds.width,
+                        ),
+                        (parent.y + offset - self.padding.top)
+                            .min(bounds.height - size.height)
+                            .max(0.0),
+                    )
+                }
+            };
+
+            levels.push(Rectangle {
+                x,
+                y,
+                width: size.width,
+                height: size.height,
+            });
+        }
+
+        layout::Node::with_children(
+            bounds,
+            levels
+                .into_iter()
+                .map(|level| {
+                    let mut node = layout::Node::new(level.size());
+                    node.move_to(level.position());
+                    node
+                })
+                .collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                match self.hit(layout, renderer, position) {
+                    Some((level, Some(index))) => {
+                        self.hover(level, index);
+
+                        event::Status::Captured
+                    }
+                    Some((_, None)) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let hit = cursor
+                    .position()
+                    .and_then(|position| self.hit(layout, renderer, position));
+
+                match hit {
+                    Some((level, Some(index))) => {
+                        self.choose(level, index, shell);
+
+                        event::Status::Captured
+                    }
+                    Some((_, None)) => event::Status::Captured,
+                    None => {
+                        self.dismiss(shell);
+
+                        // A right click outside can open a new menu right away
+                        if button == mouse::Button::Right {
+                            event::Status::Ignored
+                        } else {
+                            event::Status::Captured
+                        }
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let hit = cursor
+                    .position()
+                    .and_then(|position| self.hit(layout, renderer, position));
+
+                match hit {
+                    Some((level, Some(index))) => {
+                        self.choose(level, index, shell);
+                    }
+                    Some((_, None)) => {}
+                    None => self.dismiss(shell),
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                let levels = self.levels();
+                let level = levels.len() - 1;
+                let items = levels[level];
+
+                self.state.show_mnemonics = true;
+
+                match key_code {
+                    keyboard::KeyCode::Escape => {
+                        self.dismiss(shell);
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let forward = key_code == keyboard::KeyCode::Down;
+
+                        if let Some(index) =
+                            next_selectable(items, self.state.hovered, forward)
+                        {
+                            self.state.hovered = Some(index);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        match self
+                            .state
+                            .hovered
+                            .filter(|&index| items[index].items().is_some())
+                        {
+                            Some(index) => self.choose(level, index, shell),
+                            None => return event::Status::Ignored,
+                        }
+                    }
+                    keyboard::KeyCode::Left => match self.state.open.pop() {
+                        Some(index) => self.state.hovered = Some(index),
+                        None => return event::Status::Ignored,
+                    },
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => {
+                        if let Some(index) = self.state.hovered {
+                            self.choose(level, index, shell);
+                        }
+                    }
+                    _ => {
+                        let mnemonic = key_char(key_code)
+                            .filter(|_| {
+                                !modifiers.control() && !modifiers.logo()
+                            })
+                            .and_then(|c| {
+                                items.iter().position(|item| {
+                                    item.is_selectable()
+                                        && item.label.mnemonic() == Some(c)
+                                })
+                            });
+
+                        match mnemonic {
+                            Some(index) => self.choose(level, index, shell),
+                            None => return event::Status::Ignored,
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let levels = self.levels();
+
+        let is_over_item = cursor
+            .position()
+            .and_then(|position| self.hit(layout, renderer, position))
+            .and_then(|(level, index)| levels[level].get(index?))
+            .is_some_and(Item::is_selectable);
+
+        if is_over_item {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(&self.style);
+        let metrics = self.metrics(renderer);
+
+        for (level, (items, layout)) in
+            self.levels().into_iter().zip(layout.children()).enumerate()
+        {
+            let highlighted = self.state.open.get(level).copied().or(self
+                .state
+                .hovered
+                .filter(|_| level == self.state.open.len()));
+
+            draw_menu(
+                renderer,
+                &appearance,
+                &metrics,
+                items,
+                layout.bounds(),
+                highlighted,
+                self.state.show_mnemonics,
+            );
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|level| level.bounds().contains(cursor_position))
+    }
+}
+
+/// The measurements of the items of a menu.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Metrics<Font> {
+    padding: Padding,
+    text_size: f32,
+    text_line_height: text::LineHeight,
+    item_height: f32,
+    font: Font,
+}
+
+impl<Font: Copy> Metrics<Font> {
+    /// Computes the [`Metrics`] of a menu.
+    pub(crate) fn new<Renderer>(
+        renderer: &Renderer,
+        padding: Padding,
+        text_size: Option<f32>,
+        text_line_height: text::LineHeight,
+        font: Option<Font>,
+    ) -> Self
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+        Self {
+            padding,
+            text_size,
+            text_line_height,
+            item_height: f32::from(
+                text_line_height.to_absolute(Pixels(text_size)),
+            ) + padding.vertical(),
+            font: font.unwrap_or_else(|| renderer.default_font()),
+        }
+    }
+
+    fn height_of<Message>(&self, item: &Item<Message>) -> f32 {
+        if item.is_separator() {
+            SEPARATOR_HEIGHT
+        } else {
+            self.item_height
+        }
+    }
+
+    /// Returns the vertical offset of an item from the top of its menu.
+    fn offset_of<Message>(&self, items: &[Item<Message>], index: usize) -> f32 {
+        self.padding.top
+            + items[..index]
+                .iter()
+                .map(|item| self.height_of(item))
+                .sum::<f32>()
+    }
+
+    /// Returns the index of the item at the given height, if any.
+    fn item_at<Message>(
+        &self,
+        items: &[Item<Message>],
+        bounds: Rectangle,
+        y: f32,
+    ) -> Option<usize> {
+        let mut top = bounds.y + self.padding.top;
+
+        items.iter().position(|item| {
+            let bottom = top + self.height_of(item);
+            let is_hit = (top..bottom).contains(&y);
+
+            top = bottom;
+            is_hit
+        })
+    }
+
+    /// Returns the width of the check marks of a menu, if it has any.
+    fn leading_width<Message>(&self, items: &[Item<Message>]) -> f32 {
+        let has_indicators = items.iter().any(|item| {
+            matches!(item.kind, Kind::Checkbox(..) | Kind::Radio(..))
+        });
+
+        if has_indicators {
+            self.text_size * 1.5
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the width of the accelerators and submenu arrows of a menu.
+    fn trailing_width<Message, Renderer>(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let accelerator = items
+            .iter()
+            .filter_map(Item::accelerator_text)
+            .map(|accelerator| {
+                renderer.measure_width(
+                    &accelerator,
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Basic,
+                )
+            })
+            .fold(0.0, f32::max);
+
+        let arrow = if items.iter().any(|item| item.items().is_some()) {
+            self.text_size
+        } else {
+            0.0
+        };
+
+        if accelerator > 0.0 {
+            self.text_size * 2.0 + accelerator + arrow
+        } else if arrow > 0.0 {
+            self.text_size + arrow
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the [`Size`] of a menu with the given items.
+    pub(crate) fn size<Message, Renderer>(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> Size
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let label = items
+            .iter()
+            .map(|item| {
+                renderer.measure_width(
+                    &item.label.text,
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Advanced,
+                )
+            })
+            .fold(0.0, f32::max);
+
+        Size::new(
+            label
+                + self.leading_width(items)
+                + self.trailing_width(renderer, items)
+                + self.padding.horizontal(),
+            items.iter().map(|item| self.height_of(item)).sum::<f32>()
+                + self.padding.vertical(),
+        )
+    }
+}
+
+/// Draws a menu with the given items, highlighting one of them.
+pub(crate) fn draw_menu<Message, Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    metrics: &Metrics<Renderer::Font>,
+    items: &[Item<Message>],
+    bounds: Rectangle,
+    highlighted: Option<usize>,
+    show_mnemonics: bool,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_color: appearance.border_color,
+            border_width: appearance.border_width,
+            border_radius: appearance.border_radius,
+        },
+        appearance.background,
+    );
+
+    let padding = metrics.padding;
+    let leading = metrics.leading_width(items);
+    let mut y = bounds.y + padding.top;
+
+    for (index, item) in items.iter().enumerate() {
+        let height = metrics.height_of(item);
+        let item_bounds = Rectangle {
+            x: bounds.x,
+            y,
+            width: bounds.width,
+            height,
+        };
+
+        y += height;
+
+        if item.is_separator() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + padding.left,
+                        y: item_bounds.center_y().floor(),
+                        width: bounds.width - padding.horizontal(),
+                        height: 1.0,
+                    },
+                    border_color: Color::TRANSPARENT,
+                    border_width: 0.0,
+                    border_radius: 0.0.into(),
+                },
+                Color {
+                    a: appearance.text_color.a * 0.2,
+                    ..appearance.text_color
+                },
+            );
+
+            continue;
+        }
+
+        let is_highlighted = item.is_enabled && highlighted == Some(index);
+
+        if is_highlighted {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + appearance.border_width,
+                        width: bounds.width - appearance.border_width * 2.0,
+                        ..item_bounds
+                    },
+                    border_color: Color::TRANSPARENT,
+                    border_width: 0.0,
+                    border_radius: appearance.border_radius,
+                },
+                appearance.selected_background,
+            );
+        }
+
+        let color = if is_highlighted {
+            appearance.selected_text_color
+        } else if item.is_enabled {
+            appearance.text_color
+        } else {
+            Color {
+                a: appearance.text_color.a * 0.5,
+                ..appearance.text_color
+            }
+        };
+
+        let text = |content, x, size, alignment, shaping| Text {
+            content,
+            bounds: Rectangle {
+                x,
+                y: item_bounds.center_y(),
+                width: f32::INFINITY,
+                ..item_bounds
+            },
+            size,
+            line_height: metrics.text_line_height,
+            font: metrics.font,
+            color,
+            horizontal_alignment: alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping,
+        };
+
+        let indicator = match item.kind {
+            Kind::Checkbox(_, true) => Some("✓"),
+            Kind::Radio(_, true) => Some("●"),
+            _ => None,
+        };
+
+        if let Some(indicator) = indicator {
+            renderer.fill_text(text(
+                indicator,
+                item_bounds.x + padding.left,
+                metrics.text_size,
+                alignment::Horizontal::Left,
+                text::Shaping::Advanced,
+            ));
+        }
+
+        let label = Point::new(
+            item_bounds.x + padding.left + leading,
+            item_bounds.center_y(),
+        );
+
+        renderer.fill_text(text(
+            &item.label.text,
+            label.x,
+            metrics.text_size,
+            alignment::Horizontal::Left,
+            text::Shaping::Advanced,
+        ));
+
+        if show_mnemonics {
+            item.label.draw_mnemonic(
+                renderer,
+                label,
+                metrics.text_size,
+                metrics.font,
+                color,
+            );
+        }
+
+        let right = item_bounds.x + item_bounds.width - padding.right;
+
+        if item.items().is_some() {
+            renderer.fill_text(text(
+                "▶",
+                right,
+                metrics.text_size * 0.7,
+                alignment::Horizontal::Right,
+                text::Shaping::Advanced,
+            ));
+        }
+
+        if let Some(accelerator) = item.accelerator_text() {
+            let arrow = if items.iter().any(|item| item.items().is_some()) {
+                metrics.text_size
+            } else {
+                0.0
+            };
+
+            renderer.fill_text(Text {
+                color: Color {
+                    a: color.a * 0.7,
+                    ..color
+                },
+                ..text(
+                    &accelerator,
+                    right - arrow,
+                    metrics.text_size,
+                    alignment::Horizontal::Right,
+                    text::Shaping::Basic,
+                )
+            });
+        }
+    }
+}
+
+/// Returns the position of a menu along an axis, flipping it to the other
+/// side of its anchor if it does not fit and clamping it to the viewport.
+///
+/// The anchor spans from `position - anchor` to `position`.
+fn flip(position: f32, anchor: f32, size: f32, bounds: f32) -> f32 {
+    if position + size <= bounds {
+        position
+    } else if position - anchor - size >= 0.0 {
+        position - anchor - size
+    } else {
+        (bounds - size).max(0.0)
+    }
+}
+
+/// Returns the next item that can be chosen, starting from the given one.
+fn next_selectable<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let selectable = |index: &usize| items[*index].is_selectable();
+
+    match (current, forward) {
+        (None, true) => (0..items.len()).find(selectable),
+        (None, false) => (0..items.len()).rev().find(selectable),
+        (Some(current), true) => (current + 1..items.len())
+            .find(selectable)
+            .or(Some(current)),
+        (Some(current), false) => {
+            (0..current).rev().find(selectable).or(Some(current))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<Item<()>> {
+        vec![
+            Item::new("Cut", ()).enabled(false),
+            Item::new("Copy", ()),
+            Item::separator(),
+            Item::new("Paste", ()),
+        ]
+    }
+
+    #[test]
+    fn next_selectable_skips_separators_and_disabled_items() {
+        let items = items();
+
+        assert_eq!(next_selectable(&items, None, true), Some(1));
+        assert_eq!(next_selectable(&items, None, false), Some(3));
+        assert_eq!(next_selectable(&items, Some(1), true), Some(3));
+        assert_eq!(next_selectable(&items, Some(3), false), Some(1));
+        assert_eq!(next_selectable(&items, Some(1), false), Some(1));
+    }
+
+    #[test]
+    fn label_parses_mnemonic() {
+        let label = Label::parse("&Save && Quit");
+        assert_eq!(label.text, "Save & Quit");
+        assert_eq!(label.mnemonic(), Some('s'));
+
+        let label = Label::parse("Save &As...");
+        assert_eq!(label.text, "Save As...");
+        assert_eq!(label.mnemonic, Some(5));
+
+        let label = Label::parse("Copy");
+        assert_eq!(label.text, "Copy");
+        assert_eq!(label.mnemonic(), None);
+    }
+
+    #[test]
+    fn find_shortcut_searches_enabled_submenus() {
+        let ctrl = keyboard::Modifiers::CTRL;
+        let items = vec![
+            Item::new("Open", 1).shortcut(ctrl, keyboard::KeyCode::O),
+            Item::submenu(
+                "Export",
+                [Item::new("PDF", 2).shortcut(ctrl, keyboard::KeyCode::E)],
+            ),
+            Item::new("Quit", 3)
+                .shortcut(ctrl, keyboard::KeyCode::Q)
+                .enabled(false),
+        ];
+
+        assert_eq!(find_shortcut(&items, ctrl, keyboard::KeyCode::O), Some(&1));
+        assert_eq!(find_shortcut(&items, ctrl, keyboard::KeyCode::E), Some(&2));
+        assert_eq!(find_shortcut(&items, ctrl, keyboard::KeyCode::Q), None);
+        assert_eq!(
+            find_shortcut(
+                &items,
+                keyboard::Modifiers::empty(),
+                keyboard::KeyCode::O
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn flip_clamps_to_bounds() {
+        assert_eq!(flip(10.0, 0.0, 50.0, 100.0), 10.0);
+        assert_eq!(flip(80.0, 0.0, 50.0, 100.0), 30.0);
+        assert_eq!(flip(80.0, 20.0, 50.0, 100.0), 10.0);
+        assert_eq!(flip(10.0, 0.0, 150.0, 100.0), 0.0);
+    }
+}
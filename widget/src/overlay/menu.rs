@@ -2,6 +2,7 @@
 use crate::container::{self, Container};
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -15,6 +16,8 @@ use crate::core::{
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
 
+use std::cell::Cell;
+
 pub use iced_style::menu::{Appearance, StyleSheet};
 
 /// A list of selectable options.
@@ -34,6 +37,8 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    type_ahead: bool,
+    wrap_around: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -63,6 +68,8 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Advanced,
             font: None,
+            type_ahead: true,
+            wrap_around: false,
             style: Default::default(),
         }
     }
@@ -106,6 +113,24 @@ where
         self
     }
 
+    /// Sets whether typing a character should hover the next option starting
+    /// with it.
+    ///
+    /// It is enabled by default.
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Sets whether moving the hovered option with the arrow keys should wrap
+    /// around the ends of the list.
+    ///
+    /// It is disabled by default.
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
     /// Sets the style of the [`Menu`].
     pub fn style(
         mut self,
@@ -161,8 +186,12 @@ where
 {
     state: &'a mut Tree,
     container: Container<'a, Message, Renderer>,
+    hovered_option: &'a Cell<Option<usize>>,
     width: f32,
     target_height: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    text_line_height: text::LineHeight,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -189,18 +218,26 @@ where
             text_size,
             text_line_height,
             text_shaping,
+            type_ahead,
+            wrap_around,
             style,
         } = menu;
 
+        // The hovered option is shared with the list, so the overlay can
+        // scroll it into view when it is changed with the keyboard
+        let hovered_option = Cell::from_mut(hovered_option);
+
         let mut container = Container::new(Scrollable::new(List {
             options,
             hovered_option,
-            last_selection,
+            last_selection: Cell::from_mut(last_selection),
             font,
             text_size,
             text_line_height,
             text_shaping,
             padding,
+            type_ahead,
+            wrap_around,
             style: style.clone(),
         }));
 
@@ -209,13 +246,70 @@ where
         Self {
             state: &mut state.tree,
             container,
+            hovered_option,
             width,
             target_height,
+            padding,
+            text_size,
+            text_line_height,
             style,
         }
     }
 }
 
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + container::StyleSheet,
+{
+    /// Scrolls the menu, if necessary, so the hovered option is fully
+    /// visible.
+    fn scroll_to_hovered_option(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) {
+        let Some(index) = self.hovered_option.get() else {
+            return;
+        };
+
+        let Some(scrollable) = layout.children().next() else {
+            return;
+        };
+
+        let Some(list) = scrollable.children().next() else {
+            return;
+        };
+
+        let bounds = scrollable.bounds();
+        let content_bounds = list.bounds();
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let option_height =
+            f32::from(self.text_line_height.to_absolute(Pixels(text_size)))
+                + self.padding.vertical();
+
+        let top = option_height * index as f32;
+        let bottom = top + option_height;
+
+        let state = self.state.children[0]
+            .state
+            .downcast_mut::<scrollable::State>();
+        let offset = state.offset(bounds, content_bounds);
+
+        let y = if top < offset.y {
+            top
+        } else if bottom > offset.y + bounds.height {
+            bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(scrollable::AbsoluteOffset { x: offset.x, y });
+    }
+}
+
 impl<'a, Message, Renderer> crate::core::Overlay<Message, Renderer>
     for Overlay<'a, Message, Renderer>
 where
@@ -264,9 +358,18 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.container.on_event(
+        let is_keyboard_event = matches!(event, Event::Keyboard(_));
+        let hovered_option = self.hovered_option.get();
+
+        let status = self.container.on_event(
             self.state, event, layout, cursor, renderer, clipboard, shell,
-        )
+        );
+
+        if is_keyboard_event && self.hovered_option.get() != hovered_option {
+            self.scroll_to_hovered_option(layout, renderer);
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -312,13 +415,15 @@ where
     Renderer::Theme: StyleSheet,
 {
     options: &'a [T],
-    hovered_option: &'a mut Option<usize>,
-    last_selection: &'a mut Option<T>,
+    hovered_option: &'a Cell<Option<usize>>,
+    last_selection: &'a Cell<Option<T>>,
     padding: Padding,
     text_size: Option<f32>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    type_ahead: bool,
+    wrap_around: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -377,11 +482,7 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if cursor.is_over(layout.bounds()) {
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
-                        }
-                    }
+                    self.select_hovered_option();
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
@@ -396,8 +497,9 @@ where
                         self.text_line_height.to_absolute(Pixels(text_size)),
                     ) + self.padding.vertical();
 
-                    *self.hovered_option =
-                        Some((cursor_position.y / option_height) as usize);
+                    self.hovered_option.set(Some(
+                        (cursor_position.y / option_height) as usize,
+                    ));
                 }
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -412,14 +514,56 @@ where
                         self.text_line_height.to_absolute(Pixels(text_size)),
                     ) + self.padding.vertical();
 
-                    *self.hovered_option =
-                        Some((cursor_position.y / option_height) as usize);
+                    self.hovered_option.set(Some(
+                        (cursor_position.y / option_height) as usize,
+                    ));
 
-                    if let Some(index) = *self.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            *self.last_selection = Some(option.clone());
+                    self.select_hovered_option();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let last = self.options.len().checked_sub(1);
+                let hovered_option = self.hovered_option.get();
+
+                let next_option = match key_code {
+                    keyboard::KeyCode::Up => match hovered_option {
+                        Some(0) if self.wrap_around => last,
+                        Some(index) => Some(index.saturating_sub(1)),
+                        None => last,
+                    },
+                    keyboard::KeyCode::Down => match (hovered_option, last) {
+                        (Some(index), Some(last)) if index >= last => {
+                            Some(if self.wrap_around { 0 } else { last })
                         }
+                        (Some(index), Some(_)) => Some(index + 1),
+                        (_, last) => last.map(|_| 0),
+                    },
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        // The owner of the menu decides what to do with the
+                        // selection, so the event is not captured
+                        self.select_hovered_option();
+
+                        None
                     }
+                    _ => None,
+                };
+
+                if let Some(index) = next_option {
+                    self.hovered_option.set(Some(index));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.type_ahead && !c.is_control() =>
+            {
+                if let Some(index) = self.find_by_first_letter(c) {
+                    self.hovered_option.set(Some(index));
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
@@ -472,7 +616,7 @@ where
 
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+            let is_selected = self.hovered_option.get() == Some(i);
 
             let bounds = Rectangle {
                 x: bounds.x,
@@ -521,6 +665,39 @@ where
     }
 }
 
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    T: Clone + ToString,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Stores the hovered option, if any, as the last selection.
+    fn select_hovered_option(&self) {
+        if let Some(option) = self
+            .hovered_option
+            .get()
+            .and_then(|index| self.options.get(index))
+        {
+            self.last_selection.set(Some(option.clone()));
+        }
+    }
+
+    /// Finds the next option after the hovered one that starts with the
+    /// given character, ignoring case and wrapping around the list.
+    fn find_by_first_letter(&self, c: char) -> Option<usize> {
+        let start = self.hovered_option.get().map_or(0, |index| index + 1);
+        let len = self.options.len();
+
+        (0..len).map(|i| (start + i) % len).find(|&index| {
+            self.options[index]
+                .to_string()
+                .chars()
+                .next()
+                .is_some_and(|first| first.to_lowercase().eq(c.to_lowercase()))
+        })
+    }
+}
+
 impl<'a, T, Message, Renderer> From<List<'a, T, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -41,6 +41,7 @@ use crate::core::overlay::{self, Group};
 use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
     Size, Vector, Widget,
@@ -101,6 +102,13 @@ where
     Renderer: crate::core::Renderer,
     Renderer::Theme: StyleSheet + container::StyleSheet,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<std::borrow::Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     contents: Contents<'a, Content<'a, Message, Renderer>>,
     width: Length,
     height: Length,
@@ -147,6 +155,13 @@ where
         };
 
         Self {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             contents,
             width: Length::Fill,
             height: Length::Fill,
@@ -222,6 +237,44 @@ where
         self
     }
 
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`PaneGrid`].
+    pub fn name(mut self, name: impl Into<std::borrow::Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PaneGrid`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PaneGrid`].
+    pub fn description(
+        mut self,
+        description: impl Into<std::borrow::Cow<'a, str>>,
+    ) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`PaneGrid`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
+
     fn drag_enabled(&self) -> bool {
         (!self.contents.is_maximized())
             .then(|| self.on_drag.is_some())
@@ -456,6 +509,75 @@ where
 
         (!children.is_empty()).then(|| Group::with_children(children).overlay())
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, NodeId, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let panes = A11yTree::join(
+            self.contents
+                .iter()
+                .zip(&state.children)
+                .zip(layout.children())
+                .map(|(((_, content), state), layout)| {
+                    content.a11y_nodes(layout, state, cursor_position)
+                }),
+        );
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::Group);
+        node.set_bounds(bounds);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            panes,
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Renderer> From<PaneGrid<'a, Message, Renderer>>
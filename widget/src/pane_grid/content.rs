@@ -6,7 +6,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 use crate::pane_grid::{Draggable, TitleBar};
 
@@ -19,6 +19,7 @@ where
     Renderer: crate::core::Renderer,
     Renderer::Theme: container::StyleSheet,
 {
+    id: Id,
     title_bar: Option<TitleBar<'a, Message, Renderer>>,
     body: Element<'a, Message, Renderer>,
     style: <Renderer::Theme as container::StyleSheet>::Style,
@@ -32,6 +33,7 @@ where
     /// Creates a new [`Content`] with the provided body.
     pub fn new(body: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Self {
+            id: Id::unique(),
             title_bar: None,
             body: body.into(),
             style: Default::default(),
@@ -70,12 +72,18 @@ where
         };
 
         Tree {
+            id: Some(self.id.clone()),
             children,
             ..Tree::empty()
         }
     }
 
     pub(super) fn diff(&mut self, tree: &mut Tree) {
+        match tree.id.clone() {
+            Some(id) => self.id = id,
+            None => tree.id = Some(self.id.clone()),
+        }
+
         if tree.children.len() == 2 {
             if let Some(title_bar) = self.title_bar.as_mut() {
                 title_bar.diff(&mut tree.children[1]);
@@ -215,6 +223,77 @@ where
         );
     }
 
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, Rect, Role},
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let bounds = layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::Pane);
+
+        let children = if let Some(title_bar) = &self.title_bar {
+            let mut children = layout.children();
+            let title_bar_layout = children.next().unwrap();
+            let body_layout = children.next().unwrap();
+
+            node.set_labelled_by(
+                vec![A11yId::from(title_bar.a11y_id()).into()],
+            );
+
+            A11yTree::join(
+                [
+                    title_bar.a11y_nodes(
+                        title_bar_layout,
+                        &tree.children[1],
+                        cursor_position,
+                    ),
+                    self.body.as_widget().a11y_nodes(
+                        body_layout,
+                        &tree.children[0],
+                        cursor_position,
+                    ),
+                ]
+                .into_iter(),
+            )
+        } else {
+            self.body.as_widget().a11y_nodes(
+                layout,
+                &tree.children[0],
+                cursor_position,
+            )
+        };
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
+
+        if cursor_position.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            children,
+        )
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -6,7 +6,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{
     Clipboard, Element, Layout, Padding, Point, Rectangle, Shell, Size,
 };
@@ -20,6 +20,7 @@ where
     Renderer: crate::core::Renderer,
     Renderer::Theme: container::StyleSheet,
 {
+    id: Id,
     content: Element<'a, Message, Renderer>,
     controls: Option<Element<'a, Message, Renderer>>,
     padding: Padding,
@@ -38,6 +39,7 @@ where
         E: Into<Element<'a, Message, Renderer>>,
     {
         Self {
+            id: Id::unique(),
             content: content.into(),
             controls: None,
             padding: Padding::ZERO,
@@ -97,12 +99,18 @@ where
         };
 
         Tree {
+            id: Some(self.id.clone()),
             children,
             ..Tree::empty()
         }
     }
 
     pub(super) fn diff(&mut self, tree: &mut Tree) {
+        match tree.id.clone() {
+            Some(id) => self.id = id,
+            None => tree.id = Some(self.id.clone()),
+        }
+
         if tree.children.len() == 2 {
             if let Some(controls) = self.controls.as_mut() {
                 tree.children[1].diff(controls);
@@ -297,6 +305,84 @@ where
         }
     }
 
+    /// Returns the [`Id`] of the accessibility node of the [`TitleBar`].
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_id(&self) -> Id {
+        self.id.clone()
+    }
+
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+        let mut show_title = true;
+
+        let controls = if let Some(controls) = &self.controls {
+            let controls_layout = children.next().unwrap();
+
+            if title_layout.bounds().width + controls_layout.bounds().width
+                > padded.bounds().width
+            {
+                show_title = false;
+            }
+
+            controls.as_widget().a11y_nodes(
+                controls_layout,
+                &tree.children[1],
+                cursor_position,
+            )
+        } else {
+            A11yTree::default()
+        };
+
+        let title = if show_title {
+            self.content.as_widget().a11y_nodes(
+                title_layout,
+                &tree.children[0],
+                cursor_position,
+            )
+        } else {
+            A11yTree::default()
+        };
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let mut node = NodeBuilder::new(Role::TitleBar);
+        node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
+
+        if cursor_position.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::join([title, controls].into_iter()),
+        )
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
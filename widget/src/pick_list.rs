@@ -11,7 +11,9 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, OperationOutputWrapper};
 use crate::core::{
     Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle, Shell,
     Size, Widget,
@@ -31,6 +33,13 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     on_selected: Box<dyn Fn(T) -> Message + 'a>,
     options: Cow<'a, [T]>,
     placeholder: Option<String>,
@@ -68,6 +77,13 @@ where
         on_selected: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             on_selected: Box::new(on_selected),
             options: options.into(),
             placeholder: None,
@@ -142,6 +158,41 @@ where
         self.style = style.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`PickList`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`PickList`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
 impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
@@ -193,6 +244,18 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        operation.focusable(state, Some(&self.id));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -203,6 +266,53 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest {
+                action, data, ..
+            },
+        ) = &event
+        {
+            use iced_accessibility::accesskit::{Action, ActionData};
+
+            if *event_id != self.id {
+                return event::Status::Ignored;
+            }
+
+            let state = tree.state.downcast_mut::<State<T>>();
+
+            match (action, data) {
+                (Action::Focus, _) => state.is_focused = true,
+                (Action::Blur, _) => state.is_focused = false,
+                (Action::Expand, _) => {
+                    state.open(&self.options, self.selected.as_ref());
+                }
+                (Action::Collapse, _) => state.is_open = false,
+                (Action::Default, _) => {
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        state.open(&self.options, self.selected.as_ref());
+                    }
+                }
+                (Action::SetValue, Some(ActionData::Value(value))) => {
+                    if let Some(option) = self
+                        .options
+                        .iter()
+                        .find(|option| option.to_string() == value.as_ref())
+                    {
+                        shell.publish((self.on_selected)(option.clone()));
+
+                        state.is_open = false;
+                    }
+                }
+                _ => return event::Status::Ignored,
+            }
+
+            return event::Status::Captured;
+        }
+
         update(
             event,
             layout,
@@ -274,6 +384,86 @@ where
             self.style.clone(),
         )
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, HasPopup, NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State<T>>();
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor_position.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::ComboBoxMenuButton);
+        node.set_bounds(bounds);
+        node.set_has_popup(HasPopup::Listbox);
+        node.set_expanded(state.is_open);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.add_action(if state.is_open {
+            Action::Collapse
+        } else {
+            Action::Expand
+        });
+        node.add_action(Action::SetValue);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(selected) = self.selected.as_ref() {
+            node.set_value(selected.to_string());
+        } else if let Some(placeholder) = self.placeholder.as_ref() {
+            node.set_placeholder(placeholder.clone());
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, T: 'a, Message, Renderer> From<PickList<'a, T, Message, Renderer>>
@@ -300,6 +490,7 @@ where
 pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
+    is_focused: bool,
     is_open: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
@@ -311,6 +502,7 @@ impl<T> State<T> {
         Self {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_focused: bool::default(),
             is_open: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
@@ -318,12 +510,36 @@ impl<T> State<T> {
     }
 }
 
+impl<T: PartialEq> State<T> {
+    /// Opens the menu of the [`PickList`], hovering the selected option.
+    fn open(&mut self, options: &[T], selected: Option<&T>) {
+        self.is_open = true;
+        self.hovered_option =
+            options.iter().position(|option| Some(option) == selected);
+    }
+}
+
 impl<T> Default for State<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_open = false;
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -455,12 +671,13 @@ where
 
                 event::Status::Captured
             } else if cursor.is_over(layout.bounds()) {
-                state.is_open = true;
-                state.hovered_option =
-                    options.iter().position(|option| Some(option) == selected);
+                state.is_focused = true;
+                state.open(options, selected);
 
                 event::Status::Captured
             } else {
+                state.is_focused = false;
+
                 event::Status::Ignored
             };
 
@@ -517,6 +734,47 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if state.is_open {
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        // The menu stores the hovered option as the last
+                        // selection when Enter is pressed
+                        if let Some(last_selection) =
+                            state.last_selection.take()
+                        {
+                            shell.publish((on_selected)(last_selection));
+                        }
+
+                        state.is_open = false;
+
+                        event::Status::Captured
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_open = false;
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            } else if state.is_focused {
+                match key_code {
+                    keyboard::KeyCode::Space
+                    | keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        state.open(options, selected);
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            } else {
+                event::Status::Ignored
+            }
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             let state = state();
 
@@ -614,8 +872,9 @@ pub fn draw<'a, T, Renderer>(
     let bounds = layout.bounds();
     let is_mouse_over = cursor.is_over(bounds);
     let is_selected = selected.is_some();
+    let state = state();
 
-    let style = if is_mouse_over {
+    let style = if is_mouse_over || state.is_focused {
         theme.hovered(style)
     } else {
         theme.active(style)
@@ -647,7 +906,7 @@ pub fn draw<'a, T, Renderer>(
             shaping,
         }) => Some((*font, *code_point, *size, *line_height, *shaping)),
         Handle::Dynamic { open, closed } => {
-            if state().is_open {
+            if state.is_open {
                 Some((
                     open.font,
                     open.code_point,
@@ -2,9 +2,11 @@
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{Color, Element, Layout, Length, Rectangle, Size, Widget};
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
 use std::ops::RangeInclusive;
 
 pub use iced_style::progress_bar::{Appearance, StyleSheet};
@@ -13,8 +15,8 @@ pub use iced_style::progress_bar::{Appearance, StyleSheet};
 ///
 /// # Example
 /// ```no_run
-/// # type ProgressBar =
-/// #     iced_widget::ProgressBar<iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type ProgressBar<'a> =
+/// #     iced_widget::ProgressBar<'a, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
 /// #
 /// let value = 50.0;
 ///
@@ -23,19 +25,27 @@ pub use iced_style::progress_bar::{Appearance, StyleSheet};
 ///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
-pub struct ProgressBar<Renderer = crate::Renderer>
+pub struct ProgressBar<'a, Renderer = crate::Renderer>
 where
     Renderer: crate::core::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     range: RangeInclusive<f32>,
     value: f32,
     width: Length,
     height: Option<Length>,
     style: <Renderer::Theme as StyleSheet>::Style,
+    phantom_data: std::marker::PhantomData<&'a ()>,
 }
 
-impl<Renderer> ProgressBar<Renderer>
+impl<'a, Renderer> ProgressBar<'a, Renderer>
 where
     Renderer: crate::core::Renderer,
     Renderer::Theme: StyleSheet,
@@ -50,11 +60,19 @@ where
     ///   * the current value of the [`ProgressBar`]
     pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
         ProgressBar {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             value: value.clamp(*range.start(), *range.end()),
             range,
             width: Length::Fill,
             height: None,
             style: Default::default(),
+            phantom_data: std::marker::PhantomData,
         }
     }
 
@@ -78,9 +96,45 @@ where
         self.style = style.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`ProgressBar`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ProgressBar`].
+    pub fn description_widget<T: iced_accessibility::Describes>(
+        mut self,
+        description: &T,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ProgressBar`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`ProgressBar`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for ProgressBar<Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ProgressBar<'a, Renderer>
 where
     Renderer: crate::core::Renderer,
     Renderer::Theme: StyleSheet,
@@ -154,9 +208,71 @@ where
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        _cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Live, NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::ProgressIndicator);
+        node.set_bounds(bounds);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        node.set_min_numeric_value(*self.range.start() as f64);
+        node.set_max_numeric_value(*self.range.end() as f64);
+        node.set_numeric_value(self.value as f64);
+        node.set_live(Live::Polite);
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
-impl<'a, Message, Renderer> From<ProgressBar<Renderer>>
+impl<'a, Message, Renderer> From<ProgressBar<'a, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Message: 'a,
@@ -164,7 +280,7 @@ where
     Renderer::Theme: StyleSheet,
 {
     fn from(
-        progress_bar: ProgressBar<Renderer>,
+        progress_bar: ProgressBar<'a, Renderer>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(progress_bar)
     }
@@ -6,21 +6,24 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{
-    Alignment, Clipboard, Color, Element, Layout, Length, Pixels, Rectangle,
-    Shell, Widget,
+    id::Internal, Alignment, Clipboard, Color, Element, Layout, Length, Pixels,
+    Rectangle, Shell, Widget,
 };
 use crate::{Row, Text};
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 pub use iced_style::radio::{Appearance, StyleSheet};
 
 /// A circular button representing a choice.
 ///
 /// # Example
 /// ```no_run
-/// # type Radio<Message> =
-/// #     iced_widget::Radio<Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Radio<'a, Message> =
+/// #     iced_widget::Radio<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
 /// #
 /// # use iced_widget::column;
 /// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -69,11 +72,17 @@ pub use iced_style::radio::{Appearance, StyleSheet};
 /// let content = column![a, b, c, all];
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct Radio<Message, Renderer = crate::Renderer>
+pub struct Radio<'a, Message, Renderer = crate::Renderer>
 where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Id,
+    label_id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     is_selected: bool,
     on_click: Message,
     label: String,
@@ -85,9 +94,10 @@ where
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     style: <Renderer::Theme as StyleSheet>::Style,
+    phantom_data: std::marker::PhantomData<&'a ()>,
 }
 
-impl<Message, Renderer> Radio<Message, Renderer>
+impl<'a, Message, Renderer> Radio<'a, Message, Renderer>
 where
     Message: Clone,
     Renderer: text::Renderer,
@@ -118,6 +128,12 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: Id::unique(),
+            label_id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: label.into(),
@@ -129,6 +145,7 @@ where
             text_shaping: text::Shaping::Advanced,
             font: None,
             style: Default::default(),
+            phantom_data: std::marker::PhantomData,
         }
     }
 
@@ -185,9 +202,37 @@ where
         self.style = style.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`Radio`] button.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description_widget<T: iced_accessibility::Describes>(
+        mut self,
+        description: &T,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Radio<'a, Message, Renderer>
 where
     Message: Clone,
     Renderer: text::Renderer,
@@ -236,12 +281,26 @@ where
     ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(layout.bounds()) {
-                    shell.publish(self.on_click.clone());
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(
+                event_id,
+                iced_accessibility::accesskit::ActionRequest { action, .. },
+            ) if event_id == self.id
+                && matches!(
+                    action,
+                    iced_accessibility::accesskit::Action::Default
+                ) =>
+            {
+                shell.publish(self.on_click.clone());
 
-                    return event::Status::Captured;
-                }
+                return event::Status::Captured;
             }
             _ => {}
         }
@@ -332,6 +391,7 @@ where
                 self.font,
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
@@ -339,16 +399,110 @@ where
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, CheckedState, DefaultActionVerb, NodeBuilder, NodeId,
+                Rect, Role,
+            },
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor_position.is_over(bounds);
+        let mut children = layout.children();
+        let _ = children.next();
+        let label_bounds = children.next().map(|l| l.bounds());
+
+        let to_rect = |bounds: Rectangle| {
+            let Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } = bounds;
+
+            Rect::new(
+                x as f64,
+                y as f64,
+                (x + width) as f64,
+                (y + height) as f64,
+            )
+        };
+
+        let mut node = NodeBuilder::new(Role::RadioButton);
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.set_default_action_verb(DefaultActionVerb::Click);
+        node.set_bounds(to_rect(bounds));
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+        node.set_checked_state(if self.is_selected {
+            CheckedState::True
+        } else {
+            CheckedState::False
+        });
+        if is_hovered {
+            node.set_hovered();
+        }
+        node.set_labelled_by(vec![A11yId::from(self.label_id.clone()).into()]);
+
+        let mut label_node = NodeBuilder::new(Role::StaticText);
+        label_node.set_name(self.label.clone());
+        label_node.set_bounds(to_rect(label_bounds.unwrap_or(bounds)));
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::leaf(label_node, self.label_id.clone()),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(Id(Internal::Set(vec![
+            self.id.0.clone(),
+            self.label_id.0.clone(),
+        ])))
+    }
+
+    fn set_id(&mut self, id: Id) {
+        if let Id(Internal::Set(list)) = id {
+            if list.len() == 2 {
+                self.id.0 = list[0].clone();
+                self.label_id.0 = list[1].clone();
+            }
+        }
+    }
 }
 
-impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
+impl<'a, Message, Renderer> From<Radio<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Message: 'a + Clone,
     Renderer: 'a + text::Renderer,
     Renderer::Theme: StyleSheet + crate::text::StyleSheet,
 {
-    fn from(radio: Radio<Message, Renderer>) -> Element<'a, Message, Renderer> {
+    fn from(
+        radio: Radio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(radio)
     }
 }
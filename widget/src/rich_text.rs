@@ -0,0 +1,924 @@
+//! Display a paragraph of text made of spans with different styles.
+//!
+//! A [`RichText`] wraps all of its [`Span`]s as a single paragraph. Each
+//! [`Span`] can have its own font, weight, size, color and decorations, and
+//! it can be turned into a link by attaching some data to it.
+use crate::core::event::{self, Event};
+use crate::core::font::{self, Font};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Background, BorderRadius, Clipboard, Color, Element, Length, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::borrow::Cow;
+use std::cell::{Ref, RefCell};
+use std::ops::Range;
+
+pub use crate::text::{Appearance, StyleSheet};
+
+/// A paragraph of text made of [`Span`]s with their own styles.
+///
+/// # Example
+/// ```no_run
+/// # pub type RichText<'a, Message> = iced_widget::RichText<'a, &'static str, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # use iced_widget::core::{font, Color};
+/// use iced_widget::rich_text::Span;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(&'static str),
+/// }
+///
+/// let text: RichText<'_, Message> = RichText::new([
+///     Span::new("Some "),
+///     Span::new("bold").weight(font::Weight::Bold),
+///     Span::new(" text with "),
+///     Span::new("a link")
+///         .color(Color::from_rgb(0.3, 0.5, 1.0))
+///         .underline(true)
+///         .link("https://iced.rs"),
+/// ])
+/// .on_link_click(Message::LinkClicked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<'a, Link, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    spans: Vec<Span<'a, Link>>,
+    size: Option<f32>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    font: Option<Font>,
+    shaping: Shaping,
+    on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    paragraph: RefCell<Option<Paragraph>>,
+}
+
+impl<'a, Link, Message, Renderer> RichText<'a, Link, Message, Renderer>
+where
+    Link: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RichText`] with the given [`Span`]s.
+    pub fn new(spans: impl IntoIterator<Item = Span<'a, Link>>) -> Self {
+        Self {
+            id: Id::unique(),
+            spans: spans.into_iter().collect(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: None,
+            shaping: Shaping::Advanced,
+            on_link_click: None,
+            style: Default::default(),
+            paragraph: RefCell::new(None),
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    pub fn push(mut self, span: impl Into<Span<'a, Link>>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the default size of the [`Span`]s of the [`RichText`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the default [`LineHeight`] of the [`Span`]s of the [`RichText`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default [`Font`] of the [`Span`]s of the [`RichText`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`RichText`].
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Span`] with a link
+    /// is clicked.
+    pub fn on_link_click(
+        mut self,
+        on_link_click: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_click = Some(Box::new(on_link_click));
+        self
+    }
+
+    /// Sets the style of the [`RichText`].
+    ///
+    /// The color of the style is used for the [`Span`]s without a color.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer, span: &Span<'_, Link>) -> Metrics {
+        let size = span
+            .size
+            .or(self.size)
+            .unwrap_or_else(|| renderer.default_size());
+
+        let line_height = span.line_height.unwrap_or(self.line_height);
+
+        let mut font = span
+            .font
+            .or(self.font)
+            .unwrap_or_else(|| renderer.default_font());
+
+        if let Some(weight) = span.weight {
+            font.weight = weight;
+        }
+
+        Metrics {
+            size,
+            line_height: f32::from(line_height.to_absolute(Pixels(size))),
+            font,
+        }
+    }
+
+    /// Returns the [`Paragraph`] computed during layout, wrapping the spans
+    /// with the given maximum width if it is missing.
+    fn paragraph(
+        &self,
+        renderer: &Renderer,
+        max_width: f32,
+    ) -> Ref<'_, Paragraph> {
+        if self.paragraph.borrow().is_none() {
+            let metrics: Vec<_> = self
+                .spans
+                .iter()
+                .map(|span| self.metrics(renderer, span))
+                .collect();
+
+            let paragraph =
+                Paragraph::new(&self.spans, &metrics, max_width, |i, text| {
+                    renderer.measure_width(
+                        text,
+                        metrics[i].size,
+                        metrics[i].font,
+                        self.shaping,
+                    )
+                });
+
+            *self.paragraph.borrow_mut() = Some(paragraph);
+        }
+
+        Ref::map(self.paragraph.borrow(), |paragraph| {
+            paragraph.as_ref().expect("Paragraph is computed")
+        })
+    }
+
+    /// Returns the index of the [`Span`] with a link under the cursor, if any.
+    fn hovered_link(
+        &self,
+        renderer: &Renderer,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let _ = self.on_link_click.as_ref()?;
+
+        let bounds = layout.bounds();
+        let position = cursor.position_in(bounds)?;
+
+        self.paragraph(renderer, bounds.width)
+            .hit_test(position)
+            .filter(|&span| self.spans[span].link.is_some())
+    }
+}
+
+impl<'a, Link, Message, Renderer> Widget<Message, Renderer>
+    for RichText<'a, Link, Message, Renderer>
+where
+    Link: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The paragraph may need to be wrapped differently for these limits
+        *self.paragraph.borrow_mut() = None;
+
+        let size = self.paragraph(renderer, limits.max().width).size;
+
+        layout::Node::new(limits.resolve(size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.pressed_link =
+                    self.hovered_link(renderer, layout, cursor);
+
+                if state.pressed_link.is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                let Some(pressed_link) = state.pressed_link.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.hovered_link(renderer, layout, cursor)
+                    != Some(pressed_link)
+                {
+                    return event::Status::Ignored;
+                }
+
+                if let (Some(on_link_click), Some(link)) =
+                    (&self.on_link_click, self.spans[pressed_link].link.clone())
+                {
+                    shell.publish(on_link_click(link));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                tree.state.downcast_mut::<State>().pressed_link = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_link(renderer, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let appearance = theme.appearance(self.style.clone());
+        let paragraph = self.paragraph(renderer, bounds.width);
+        let translation = Vector::new(bounds.x, bounds.y);
+
+        for fragment in &paragraph.fragments {
+            let span = &self.spans[fragment.span];
+            let metrics = self.metrics(renderer, span);
+            let fragment_bounds = fragment.bounds + translation;
+
+            if !fragment_bounds.intersects(viewport) {
+                continue;
+            }
+
+            let color =
+                span.color.or(appearance.color).unwrap_or(style.text_color);
+
+            if let Some(highlight) = span.highlight {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: fragment_bounds,
+                        border_radius: highlight.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    highlight.background,
+                );
+            }
+
+            renderer.fill_text(text::Text {
+                content: &span.text[fragment.range.clone()],
+                bounds: Rectangle {
+                    width: f32::INFINITY,
+                    ..fragment_bounds
+                },
+                size: metrics.size,
+                line_height: LineHeight::Absolute(Pixels(metrics.line_height)),
+                color,
+                font: metrics.font,
+                horizontal_alignment: crate::core::alignment::Horizontal::Left,
+                vertical_alignment: crate::core::alignment::Vertical::Top,
+                shaping: self.shaping,
+            });
+
+            // The glyphs are vertically centered in the line, so the
+            // decorations are placed relative to the center of the em box
+            let em_center = fragment_bounds.center_y();
+            let thickness = (metrics.size / 14.0).max(1.0);
+
+            let mut decorate = |y: f32| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: fragment_bounds.x,
+                            y: y - thickness / 2.0,
+                            width: fragment.text_width,
+                            height: thickness,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            };
+
+            if span.underline {
+                decorate(em_center + metrics.size * 0.4);
+            }
+
+            if span.strikethrough {
+                decorate(em_center + metrics.size * 0.05);
+            }
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        _cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Live, NodeBuilder, Rect, Role},
+            A11yTree,
+        };
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+
+        let mut node = NodeBuilder::new(Role::StaticText);
+
+        node.set_name(
+            self.spans
+                .iter()
+                .map(|span| span.text.as_ref())
+                .collect::<String>()
+                .into_boxed_str(),
+        );
+        node.set_bounds(bounds);
+        node.set_live(Live::Polite);
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+impl<'a, Link, Message, Renderer> From<RichText<'a, Link, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Link: Clone + 'a,
+    Message: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(rich_text: RichText<'a, Link, Message, Renderer>) -> Self {
+        Self::new(rich_text)
+    }
+}
+
+/// A piece of text with its own style inside a [`RichText`].
+///
+/// Any style left unset is inherited from the [`RichText`].
+#[derive(Debug, Clone)]
+pub struct Span<'a, Link = ()> {
+    /// The content of the [`Span`].
+    pub text: Cow<'a, str>,
+    /// The size of the [`Span`] in logical pixels.
+    pub size: Option<f32>,
+    /// The [`LineHeight`] of the [`Span`].
+    pub line_height: Option<LineHeight>,
+    /// The [`Font`] of the [`Span`].
+    pub font: Option<Font>,
+    /// The [`font::Weight`] of the [`Span`], overriding the one of its font.
+    pub weight: Option<font::Weight>,
+    /// The [`Color`] of the [`Span`].
+    pub color: Option<Color>,
+    /// The [`Highlight`] drawn behind the [`Span`].
+    pub highlight: Option<Highlight>,
+    /// Whether the [`Span`] is underlined.
+    pub underline: bool,
+    /// Whether the [`Span`] is struck through.
+    pub strikethrough: bool,
+    /// The link of the [`Span`], reported when it is clicked.
+    pub link: Option<Link>,
+}
+
+impl<'a, Link> Span<'a, Link> {
+    /// Creates a new [`Span`] with the given text.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            size: None,
+            line_height: None,
+            font: None,
+            weight: None,
+            color: None,
+            highlight: None,
+            underline: false,
+            strikethrough: false,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Span`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`font::Weight`] of the [`Span`].
+    pub fn weight(mut self, weight: font::Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Highlight`] of the [`Span`].
+    pub fn highlight(mut self, highlight: Highlight) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Turns the [`Span`] into a link.
+    ///
+    /// The link will be reported by [`RichText::on_link_click`] when the
+    /// [`Span`] is clicked.
+    pub fn link(mut self, link: impl Into<Link>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+impl<'a, Link> From<&'a str> for Span<'a, Link> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'a, Link> From<String> for Span<'a, Link> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// The background drawn behind a [`Span`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Highlight {
+    /// The [`Background`] of the highlight.
+    pub background: Background,
+    /// The border radius of the highlight.
+    pub border_radius: BorderRadius,
+}
+
+impl Highlight {
+    /// Creates a new [`Highlight`] with the given [`Background`] and no
+    /// border radius.
+    pub fn new(background: impl Into<Background>) -> Self {
+        Self {
+            background: background.into(),
+            border_radius: 0.0.into(),
+        }
+    }
+
+    /// Sets the border radius of the [`Highlight`].
+    pub fn border_radius(
+        mut self,
+        border_radius: impl Into<BorderRadius>,
+    ) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+}
+
+/// The local state of a [`RichText`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    pressed_link: Option<usize>,
+}
+
+/// The resolved metrics of a [`Span`].
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    size: f32,
+    line_height: f32,
+    font: Font,
+}
+
+/// The [`Span`]s of a [`RichText`] wrapped into lines.
+#[derive(Debug, Clone, PartialEq)]
+struct Paragraph {
+    fragments: Vec<Fragment>,
+    size: Size,
+}
+
+/// The part of a [`Span`] that is laid out in a single line.
+#[derive(Debug, Clone, PartialEq)]
+struct Fragment {
+    /// The index of the [`Span`].
+    span: usize,
+    /// The byte range of the text of the [`Span`].
+    range: Range<usize>,
+    /// The bounds of the fragment, relative to the [`Paragraph`].
+    bounds: Rectangle,
+    /// The width of the text, without trailing whitespace.
+    text_width: f32,
+}
+
+impl Paragraph {
+    /// Wraps the given [`Span`]s into lines that fit the maximum width.
+    ///
+    /// Lines break at whitespace and newlines. A word that does not fit in a
+    /// line on its own is broken at any character. The fragments of a line
+    /// are aligned to its bottom.
+    fn new<Link>(
+        spans: &[Span<'_, Link>],
+        metrics: &[Metrics],
+        max_width: f32,
+        mut measure: impl FnMut(usize, &str) -> f32,
+    ) -> Self {
+        let mut builder = Builder::default();
+
+        for (index, span) in spans.iter().enumerate() {
+            let line_height = metrics[index].line_height;
+            let mut line_start = 0;
+
+            for (i, line) in span.text.split('\n').enumerate() {
+                if i > 0 {
+                    builder.break_line(line_height);
+                }
+
+                for (offset, word) in text::words(line) {
+                    let mut start = line_start + offset;
+                    let end = start + word.len();
+                    let text_end = start + word.trim_end().len();
+
+                    let mut text_width =
+                        measure(index, &span.text[start..text_end]);
+
+                    if builder.x > 0.0 && builder.x + text_width > max_width {
+                        builder.break_line(0.0);
+                    }
+
+                    // Words wider than a whole line are broken at any
+                    // character
+                    while text_width > max_width {
+                        let prefix = text::fitting_prefix(
+                            &span.text[start..text_end],
+                            max_width,
+                            |prefix| measure(index, prefix),
+                        );
+
+                        let width =
+                            measure(index, &span.text[start..start + prefix]);
+
+                        builder.push(
+                            index,
+                            start..start + prefix,
+                            width,
+                            width,
+                            line_height,
+                        );
+                        builder.break_line(0.0);
+
+                        start += prefix;
+                        text_width =
+                            measure(index, &span.text[start..text_end]);
+                    }
+
+                    let width = if text_end == end {
+                        text_width
+                    } else {
+                        measure(index, &span.text[start..end])
+                    };
+
+                    builder.push(
+                        index,
+                        start..end,
+                        width,
+                        text_width,
+                        line_height,
+                    );
+                }
+
+                line_start += line.len() + 1;
+            }
+        }
+
+        builder.finish()
+    }
+
+    /// Returns the index of the [`Span`] at the given position, relative to
+    /// the [`Paragraph`].
+    fn hit_test(&self, position: Point) -> Option<usize> {
+        self.fragments
+            .iter()
+            .find(|fragment| fragment.bounds.contains(position))
+            .map(|fragment| fragment.span)
+    }
+}
+
+/// Accumulates the fragments of a [`Paragraph`] line by line.
+#[derive(Debug, Default)]
+struct Builder {
+    fragments: Vec<Fragment>,
+    line_start: usize,
+    x: f32,
+    y: f32,
+    line_width: f32,
+    line_height: f32,
+    width: f32,
+}
+
+impl Builder {
+    fn push(
+        &mut self,
+        span: usize,
+        range: Range<usize>,
+        width: f32,
+        text_width: f32,
+        height: f32,
+    ) {
+        let last =
+            self.fragments[self.line_start..]
+                .last_mut()
+                .filter(|fragment| {
+                    fragment.span == span && fragment.range.end == range.start
+                });
+
+        if let Some(fragment) = last {
+            fragment.range.end = range.end;
+            fragment.bounds.width = self.x + width - fragment.bounds.x;
+            fragment.text_width = self.x + text_width - fragment.bounds.x;
+        } else {
+            self.fragments.push(Fragment {
+                span,
+                range,
+                bounds: Rectangle {
+                    x: self.x,
+                    y: 0.0,
+                    width,
+                    height,
+                },
+                text_width,
+            });
+        }
+
+        self.line_width = self.x + text_width;
+        self.x += width;
+        self.line_height = self.line_height.max(height);
+    }
+
+    /// Finishes the current line, which will be at least `min_height` tall.
+    fn break_line(&mut self, min_height: f32) {
+        let line_height = self.line_height.max(min_height);
+
+        for fragment in &mut self.fragments[self.line_start..] {
+            fragment.bounds.y = self.y + line_height - fragment.bounds.height;
+        }
+
+        self.y += line_height;
+        self.width = self.width.max(self.line_width);
+
+        self.line_start = self.fragments.len();
+        self.x = 0.0;
+        self.line_width = 0.0;
+        self.line_height = 0.0;
+    }
+
+    fn finish(mut self) -> Paragraph {
+        if self.line_start < self.fragments.len() {
+            self.break_line(0.0);
+        }
+
+        Paragraph {
+            fragments: self.fragments,
+            size: Size::new(self.width, self.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(spans: &[Span<'_>], max_width: f32) -> Paragraph {
+        let metrics: Vec<_> = spans
+            .iter()
+            .map(|span| Metrics {
+                size: span.size.unwrap_or(10.0),
+                line_height: span.size.unwrap_or(10.0) * 2.0,
+                font: Font::DEFAULT,
+            })
+            .collect();
+
+        // Every character is as wide as the size of its span
+        Paragraph::new(spans, &metrics, max_width, |i, text| {
+            text.chars().count() as f32 * metrics[i].size
+        })
+    }
+
+    fn lines(spans: &[Span<'_>], paragraph: &Paragraph) -> Vec<(f32, String)> {
+        paragraph
+            .fragments
+            .iter()
+            .map(|fragment| {
+                (
+                    fragment.bounds.y,
+                    spans[fragment.span].text[fragment.range.clone()]
+                        .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wraps_spans_as_one_paragraph() {
+        let spans = [Span::new("hello big "), Span::new("world of spans")];
+        let paragraph = paragraph(&spans, 100.0);
+
+        assert_eq!(
+            lines(&spans, &paragraph),
+            vec![
+                (0.0, String::from("hello big ")),
+                (20.0, String::from("world of ")),
+                (40.0, String::from("spans")),
+            ]
+        );
+        assert_eq!(paragraph.size, Size::new(90.0, 60.0));
+    }
+
+    #[test]
+    fn aligns_fragments_to_the_bottom_of_the_line() {
+        let spans = [Span::new("big ").size(20.0), Span::new("small")];
+        let paragraph = paragraph(&spans, 1000.0);
+
+        assert_eq!(paragraph.fragments[0].bounds.y, 0.0);
+        assert_eq!(paragraph.fragments[1].bounds.y, 20.0);
+        assert_eq!(paragraph.fragments[1].bounds.x, 80.0);
+        assert_eq!(paragraph.size, Size::new(130.0, 40.0));
+    }
+
+    #[test]
+    fn breaks_newlines_and_long_words() {
+        let spans = [Span::new("ab\n\nabcdefghijkl")];
+        let paragraph = paragraph(&spans, 50.0);
+
+        assert_eq!(
+            lines(&spans, &paragraph),
+            vec![
+                (0.0, String::from("ab")),
+                (40.0, String::from("abcde")),
+                (60.0, String::from("fghij")),
+                (80.0, String::from("kl")),
+            ]
+        );
+    }
+
+    #[test]
+    fn hit_test_finds_span() {
+        let spans = [Span::new("plain "), Span::new("link").link(())];
+        let paragraph = paragraph(&spans, 1000.0);
+
+        assert_eq!(paragraph.hit_test(Point::new(5.0, 5.0)), Some(0));
+        assert_eq!(paragraph.hit_test(Point::new(65.0, 5.0)), Some(1));
+        assert_eq!(paragraph.hit_test(Point::new(105.0, 5.0)), None);
+    }
+}
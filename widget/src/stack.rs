@@ -0,0 +1,512 @@
+//! Display elements on top of each other.
+use iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A container that displays its children on top of each other, within the
+/// same bounds.
+///
+/// The first child is the bottom-most layer. Events are handled by the
+/// top-most layer first, and a layer that captures an event stops it from
+/// reaching the layers below it.
+///
+/// # Example
+/// ```no_run
+/// # type Stack<'a, Message> =
+/// #     iced_widget::Stack<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Text<'a> = iced_widget::Text<'a, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::core::alignment;
+/// use iced_widget::core::Vector;
+/// use iced_widget::stack::Layer;
+///
+/// let avatar: Stack<'_, ()> = Stack::new()
+///     .push(Text::new("Inbox"))
+///     .push_layer(
+///         Text::new("3").size(10),
+///         Layer::new()
+///             .align_x(alignment::Horizontal::Right)
+///             .align_y(alignment::Vertical::Top)
+///             .offset(Vector::new(4.0, -4.0)),
+///     );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer = crate::Renderer> {
+    padding: Padding,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    children: Vec<Element<'a, Message, Renderer>>,
+    layers: Vec<Layer>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Stack`] with the given elements, from the bottom-most
+    /// layer to the top-most one.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Stack {
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            layers: vec![Layer::default(); children.len()],
+            children,
+        }
+    }
+
+    /// Sets the [`Padding`] of the [`Stack`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Stack`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the default alignment of the layers of the [`Stack`] in the
+    /// horizontal axis.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default alignment of the layers of the [`Stack`] in the
+    /// vertical axis.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`], using its default alignment.
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self.layers.push(Layer::default());
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`], placed as described by the
+    /// given [`Layer`].
+    pub fn push_layer(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+        layer: Layer,
+    ) -> Self {
+        self.children.push(child.into());
+        self.layers.push(layer);
+        self
+    }
+
+    /// Returns the alignment of the layer at the given index.
+    fn alignment(&self, index: usize) -> (Alignment, Alignment) {
+        let layer = self.layers[index];
+
+        (
+            Alignment::from(
+                layer
+                    .horizontal_alignment
+                    .unwrap_or(self.horizontal_alignment),
+            ),
+            Alignment::from(
+                layer.vertical_alignment.unwrap_or(self.vertical_alignment),
+            ),
+        )
+    }
+
+    /// Returns the index of the top-most layer that is interactive under
+    /// the cursor, if any.
+    ///
+    /// The layers below it do not see the cursor.
+    fn hovered_layer(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> Option<usize>
+    where
+        Renderer: crate::core::Renderer,
+    {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .enumerate()
+            .rev()
+            .find_map(|(index, ((child, state), layout))| {
+                let interaction = child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                );
+
+                (interaction != mouse::Interaction::Idle).then_some(index)
+            })
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The placement of a layer in a [`Stack`].
+///
+/// A [`Layer`] is aligned like the rest of the [`Stack`] unless told
+/// otherwise, and then moved by its offset.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Layer {
+    horizontal_alignment: Option<alignment::Horizontal>,
+    vertical_alignment: Option<alignment::Vertical>,
+    offset: Vector,
+}
+
+impl Layer {
+    /// Creates a new [`Layer`] with the alignment of its [`Stack`] and no
+    /// offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the alignment of the [`Layer`] in the horizontal axis.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the alignment of the [`Layer`] in the vertical axis.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+
+    /// Centers the [`Layer`] in both axes.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+
+    /// Sets the offset of the [`Layer`] from its aligned position.
+    ///
+    /// The offset does not change the size of the [`Stack`], so an offset
+    /// layer may be drawn outside of its bounds.
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let content_limits = limits.pad(self.padding).loose();
+
+        let mut nodes: Vec<_> = self
+            .children
+            .iter()
+            .map(|child| child.as_widget().layout(renderer, &content_limits))
+            .collect();
+
+        let intrinsic_size = nodes.iter().fold(Size::ZERO, |size, node| {
+            let node_size = node.size();
+
+            Size::new(
+                size.width.max(node_size.width),
+                size.height.max(node_size.height),
+            )
+        });
+
+        let size = limits.pad(self.padding).resolve(intrinsic_size);
+
+        for (index, (child, node)) in
+            self.children.iter().zip(&mut nodes).enumerate()
+        {
+            let widget = child.as_widget();
+
+            // Layers that fill the stack can only know their size now
+            if widget.width() != Length::Shrink
+                || widget.height() != Length::Shrink
+            {
+                *node = widget
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+            }
+
+            let (horizontal_alignment, vertical_alignment) =
+                self.alignment(index);
+            let offset = self.layers[index].offset;
+
+            node.move_to(Point::new(
+                self.padding.left + offset.x,
+                self.padding.top + offset.y,
+            ));
+            node.align(horizontal_alignment, vertical_alignment, size);
+        }
+
+        layout::Node::with_children(size.pad(self.padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let viewport = layout.bounds();
+        let mut cursor = cursor;
+
+        for ((child, state), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .rev()
+        {
+            let status = child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return event::Status::Captured;
+            }
+
+            if child
+                .as_widget()
+                .mouse_interaction(state, layout, cursor, &viewport, renderer)
+                != mouse::Interaction::Idle
+            {
+                cursor = mouse::Cursor::Unavailable;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().collect::<Vec<_>>())
+            .rev()
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::Idle)
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let hovered_layer =
+            self.hovered_layer(tree, layout, cursor, viewport, renderer);
+
+        for (index, ((child, state), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let cursor = match hovered_layer {
+                Some(hovered_layer) if index < hovered_layer => {
+                    mouse::Cursor::Unavailable
+                }
+                _ => cursor,
+            };
+
+            if index == 0 {
+                child.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            } else {
+                // Each layer is drawn on top of everything below it
+                renderer.with_layer(*viewport, |renderer| {
+                    child.as_widget().draw(
+                        state, renderer, theme, style, layout, cursor, viewport,
+                    );
+                });
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| {
+                    c.as_widget().a11y_nodes(c_layout, state, p)
+                }),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(stack: Stack<'a, Message, Renderer>) -> Self {
+        Self::new(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    #[test]
+    fn layers_share_the_bounds_of_the_stack() {
+        let stack: Stack<'_, (), Null> = Stack::new()
+            .push(Space::new(100.0, 50.0))
+            .push(Space::new(Length::Fill, Length::Fill))
+            .push_layer(
+                Space::new(10.0, 10.0),
+                Layer::new()
+                    .align_x(alignment::Horizontal::Right)
+                    .align_y(alignment::Vertical::Bottom)
+                    .offset(Vector::new(2.0, 2.0)),
+            )
+            .push_layer(Space::new(20.0, 20.0), Layer::new().center());
+
+        let node = stack.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+        );
+
+        let bounds: Vec<_> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(100.0, 50.0));
+        assert_eq!(
+            bounds,
+            [
+                Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0)),
+                Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0)),
+                Rectangle::new(Point::new(92.0, 42.0), Size::new(10.0, 10.0)),
+                Rectangle::new(Point::new(40.0, 15.0), Size::new(20.0, 20.0)),
+            ]
+        );
+    }
+}
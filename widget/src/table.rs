@@ -0,0 +1,1190 @@
+//! Display data in rows and columns that line up.
+//!
+//! A [`Table`] is built from a list of [`Column`] definitions and a list of
+//! rows. Every [`Column`] produces the cell of a row with a closure.
+//!
+//! Like [`PaneGrid`], a [`Table`] does not own its data. Sorting, resizing
+//! and selecting produce messages, and it is up to your application to
+//! update its state accordingly.
+//!
+//! [`PaneGrid`]: crate::PaneGrid
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+use std::cell::Cell;
+
+pub use crate::style::table::{Appearance, StyleSheet};
+
+/// The distance from a column divider at which it can be grabbed.
+const DIVIDER_GRAB_AREA: f32 = 4.0;
+
+/// A widget that displays rows of data in aligned columns.
+///
+/// # Example
+/// ```no_run
+/// # type Renderer = iced_widget::renderer::Renderer<iced_widget::style::Theme>;
+/// # type Table<'a, Message> = iced_widget::Table<'a, Message, Renderer>;
+/// # type Text<'a> = iced_widget::Text<'a, Renderer>;
+/// use iced_widget::table;
+///
+/// struct User {
+///     name: String,
+///     email: String,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, table::Order),
+///     Select(Vec<usize>),
+/// }
+///
+/// fn view(users: &[User]) -> Table<'_, Message> {
+///     Table::new(
+///         [
+///             table::Column::new(Text::new("Name"), |user: &User| {
+///                 Text::new(&user.name).into()
+///             }),
+///             table::Column::new(Text::new("Email"), |user: &User| {
+///                 Text::new(&user.email).into()
+///             })
+///             .min_width(200.0),
+///         ],
+///         users,
+///     )
+///     .on_sort(Message::Sort)
+///     .on_select(Message::Select)
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    columns: Vec<Track>,
+    cells: Vec<Element<'a, Message, Renderer>>,
+    width: Length,
+    padding: Padding,
+    sort: Option<(usize, Order)>,
+    selection: Vec<usize>,
+    is_multi_select: bool,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// The definition of a column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, T, Message, Renderer = crate::Renderer> {
+    header: Element<'a, Message, Renderer>,
+    view: Box<dyn Fn(T) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    min_width: f32,
+    is_sortable: bool,
+}
+
+impl<'a, T, Message, Renderer> Column<'a, T, Message, Renderer> {
+    /// Creates a new [`Column`] with the given header and a closure that
+    /// produces the cell of a row.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Renderer>>,
+        view: impl Fn(T) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            view: Box::new(view),
+            width: Length::Fill,
+            min_width: 0.0,
+            is_sortable: true,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// A [`Length::Shrink`] column fits its widest cell, while the
+    /// [`Length::Fill`] columns share the remaining space.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the minimum width of the [`Column`].
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be sorted by clicking its header.
+    ///
+    /// Columns are sortable by default, as long as the [`Table`] has an
+    /// [`on_sort`](Table::on_sort) handler.
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// The sorting order of a column of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+/// The sizing rules of a column of a [`Table`].
+#[derive(Debug, Clone, Copy)]
+struct Track {
+    width: Length,
+    min_width: f32,
+    is_sortable: bool,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Table`] with the given columns and rows.
+    pub fn new<T>(
+        columns: impl IntoIterator<Item = Column<'a, T, Message, Renderer>>,
+        rows: impl IntoIterator<Item = T>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut tracks = Vec::new();
+        let mut views = Vec::new();
+        let mut cells = Vec::new();
+
+        for column in columns {
+            tracks.push(Track {
+                width: column.width,
+                min_width: column.min_width,
+                is_sortable: column.is_sortable,
+            });
+            views.push(column.view);
+            cells.push(column.header);
+        }
+
+        for row in rows {
+            cells.extend(views.iter().map(|view| view(row.clone())));
+        }
+
+        Self {
+            columns: tracks,
+            cells,
+            width: Length::Fill,
+            padding: Padding::from([5, 10]),
+            sort: None,
+            selection: Vec::new(),
+            is_multi_select: true,
+            on_sort: None,
+            on_select: None,
+            on_resize: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every cell of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the column the [`Table`] is currently sorted by, which is
+    /// indicated in its header.
+    pub fn sorted_by(mut self, column: usize, order: Order) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the indices of the currently selected rows of the [`Table`].
+    pub fn selection(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = rows.into_iter().collect();
+        self.selection.sort_unstable();
+        self.selection.dedup();
+        self
+    }
+
+    /// Sets whether multiple rows of the [`Table`] can be selected by
+    /// holding Shift or Ctrl while clicking.
+    ///
+    /// Multiple selection is enabled by default.
+    pub fn multi_select(mut self, is_multi_select: bool) -> Self {
+        self.is_multi_select = is_multi_select;
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable [`Column`] is clicked.
+    ///
+    /// The message receives the index of the column and the requested
+    /// [`Order`].
+    pub fn on_sort(mut self, f: impl Fn(usize, Order) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the selected rows of
+    /// the [`Table`] change.
+    ///
+    /// The message receives the sorted indices of the new selection.
+    pub fn on_select(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a column divider is
+    /// dragged, enabling column resizing.
+    ///
+    /// The message receives the index of the column and its new width, which
+    /// can be applied with [`Column::width`].
+    pub fn on_resize(mut self, f: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn is_sortable(&self, column: usize) -> bool {
+        self.on_sort.is_some() && self.columns[column].is_sortable
+    }
+
+    /// Returns the horizontal space reserved for the sort indicator of a
+    /// header.
+    fn indicator_width(&self, renderer: &Renderer, column: usize) -> f32 {
+        if self.is_sortable(column) {
+            renderer.default_size()
+        } else {
+            0.0
+        }
+    }
+
+    /// Computes the width of every column, given the available width.
+    fn column_widths(&self, renderer: &Renderer, available: f32) -> Vec<f32> {
+        let columns = self.columns.len();
+        let intrinsic = layout::Limits::new(Size::ZERO, Size::INFINITY);
+
+        let mut widths: Vec<f32> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, track)| {
+                let width = match track.width {
+                    Length::Fixed(width) => width,
+                    Length::Shrink => {
+                        self.cells
+                            .iter()
+                            .skip(i)
+                            .step_by(columns)
+                            .map(|cell| {
+                                cell.as_widget()
+                                    .layout(renderer, &intrinsic)
+                                    .size()
+                                    .width
+                            })
+                            .fold(0.0, f32::max)
+                            + self.padding.horizontal()
+                            + self.indicator_width(renderer, i)
+                    }
+                    Length::Fill | Length::FillPortion(_) => 0.0,
+                };
+
+                width.max(track.min_width)
+            })
+            .collect();
+
+        let fill_factors: u16 = self
+            .columns
+            .iter()
+            .map(|track| track.width.fill_factor())
+            .sum();
+
+        if fill_factors > 0 {
+            let used: f32 = self
+                .columns
+                .iter()
+                .zip(&widths)
+                .filter(|(track, _)| track.width.fill_factor() == 0)
+                .map(|(_, width)| width)
+                .sum();
+
+            let remaining = if available.is_finite() {
+                (available - used).max(0.0)
+            } else {
+                0.0
+            };
+
+            for (track, width) in self.columns.iter().zip(&mut widths) {
+                let factor = track.width.fill_factor();
+
+                if factor > 0 {
+                    *width = (remaining * f32::from(factor)
+                        / f32::from(fill_factors))
+                    .max(track.min_width);
+                }
+            }
+        }
+
+        widths
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.cells);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let widths = self.column_widths(renderer, limits.max().width);
+        let columns = self.columns.len();
+
+        let mut slots = Vec::with_capacity(self.cells.len());
+        let mut y = 0.0;
+
+        if columns > 0 {
+            for (row, cells) in self.cells.chunks(columns).enumerate() {
+                let nodes: Vec<_> = cells
+                    .iter()
+                    .zip(&widths)
+                    .enumerate()
+                    .map(|(column, (cell, width))| {
+                        let reserved = if row == 0 {
+                            self.indicator_width(renderer, column)
+                        } else {
+                            0.0
+                        };
+
+                        let limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (width - self.padding.horizontal() - reserved)
+                                    .max(0.0),
+                                f32::INFINITY,
+                            ),
+                        );
+
+                        cell.as_widget().layout(renderer, &limits)
+                    })
+                    .collect();
+
+                let height = nodes
+                    .iter()
+                    .map(|node| node.size().height)
+                    .fold(0.0, f32::max);
+
+                let mut x = 0.0;
+
+                for (mut node, width) in nodes.into_iter().zip(&widths) {
+                    node.move_to(Point::new(
+                        self.padding.left,
+                        self.padding.top + (height - node.size().height) / 2.0,
+                    ));
+
+                    let mut slot = layout::Node::with_children(
+                        Size::new(*width, height + self.padding.vertical()),
+                        vec![node],
+                    );
+
+                    slot.move_to(Point::new(x, y));
+                    slots.push(slot);
+
+                    x += width;
+                }
+
+                y += height + self.padding.vertical();
+            }
+        }
+
+        let size = limits.resolve(Size::new(widths.iter().sum(), y));
+
+        layout::Node::with_children(size, slots)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((cell, state), slot)| {
+                    cell.as_widget().operate(
+                        state,
+                        slot.children().next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let header = Header::new(layout, self.columns.len(), state);
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.keyboard_modifiers = modifiers;
+        }
+
+        if let Some(on_resize) = &self.on_resize {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if let Some(position) = header.cursor(cursor).position() {
+                        if let Some(column) = header.divider_at(position) {
+                            state.drag = Some(Drag {
+                                column,
+                                origin: position.x,
+                                width: header.slots[column].width,
+                            });
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    if let Some(drag) = state.drag {
+                        let width = (drag.width + position.x - drag.origin)
+                            .max(self.columns[drag.column].min_width);
+
+                        shell.publish(on_resize(drag.column, width));
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. })
+                    if state.drag.take().is_some() =>
+                {
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let header_cursor = header.cursor(cursor);
+        let rows_cursor = if header.is_over(cursor) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let status = self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(i, ((cell, state), slot))| {
+                let cursor = if i < self.columns.len() {
+                    header_cursor
+                } else {
+                    rows_cursor
+                };
+
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    slot.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        if let Some(position) = header_cursor.position() {
+            if let Some(column) = header.column_at(position) {
+                if let (Some(on_sort), true) =
+                    (&self.on_sort, self.is_sortable(column))
+                {
+                    let order = match self.sort {
+                        Some((sorted, order)) if sorted == column => {
+                            order.reverse()
+                        }
+                        _ => Order::Ascending,
+                    };
+
+                    shell.publish(on_sort(column, order));
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let Some(on_select) = &self.on_select else {
+            return event::Status::Ignored;
+        };
+
+        let Some(position) = rows_cursor.position() else {
+            return event::Status::Ignored;
+        };
+
+        let Some(row) = row_at(layout, self.columns.len(), position) else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let modifiers = if self.is_multi_select {
+            state.keyboard_modifiers
+        } else {
+            keyboard::Modifiers::default()
+        };
+
+        let selection = select(&self.selection, state.anchor, row, modifiers);
+
+        if !modifiers.shift() {
+            state.anchor = Some(row);
+        }
+
+        shell.publish(on_select(selection));
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let header = Header::new(layout, self.columns.len(), state);
+        let header_cursor = header.cursor(cursor);
+
+        if state.drag.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let Some(position) = header_cursor.position() {
+            if self.on_resize.is_some() && header.divider_at(position).is_some()
+            {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+        }
+
+        let rows_cursor = if header.is_over(cursor) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let interaction = self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(i, ((cell, state), slot))| {
+                let cursor = if i < self.columns.len() {
+                    header_cursor
+                } else {
+                    rows_cursor
+                };
+
+                cell.as_widget().mouse_interaction(
+                    state,
+                    slot.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        let is_over_sortable_header = header_cursor
+            .position()
+            .and_then(|position| header.column_at(position))
+            .is_some_and(|column| self.is_sortable(column));
+
+        let is_over_row = self.on_select.is_some()
+            && rows_cursor.is_over(layout.bounds())
+            && rows_cursor.position().is_some_and(|position| {
+                row_at(layout, self.columns.len(), position).is_some()
+            });
+
+        if is_over_sortable_header || is_over_row {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let columns = self.columns.len();
+
+        if columns == 0 {
+            return;
+        }
+
+        let header_height = layout
+            .children()
+            .next()
+            .map_or(0.0, |slot| slot.bounds().height);
+
+        // The header sticks to the top of the visible part of the table
+        state.sticky_offset.set(
+            (viewport.y - bounds.y)
+                .clamp(0.0, (bounds.height - header_height).max(0.0)),
+        );
+
+        let header = Header::new(layout, columns, state);
+        let header_cursor = header.cursor(cursor);
+        let rows_cursor = if header.is_over(cursor) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let slots: Vec<_> = layout.children().collect();
+        let cells: Vec<_> = self.cells.iter().zip(&tree.children).collect();
+
+        for (row, (slots, cells)) in slots
+            .chunks(columns)
+            .zip(cells.chunks(columns))
+            .enumerate()
+            .skip(1)
+        {
+            let index = row - 1;
+            let first = slots[0].bounds();
+
+            let row_bounds = Rectangle {
+                x: bounds.x,
+                y: first.y,
+                width: bounds.width,
+                height: first.height,
+            };
+
+            if !row_bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = self.selection.binary_search(&index).is_ok();
+
+            let background = if is_selected {
+                Some(appearance.selected_row_background)
+            } else if self.on_select.is_some()
+                && rows_cursor.is_over(row_bounds)
+            {
+                appearance
+                    .hovered_row_background
+                    .or(appearance.row_background)
+            } else if index % 2 == 1 {
+                appearance
+                    .alternate_row_background
+                    .or(appearance.row_background)
+            } else {
+                appearance.row_background
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let style = renderer::Style {
+                text_color: if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+            };
+
+            for (slot, (cell, state)) in slots.iter().zip(cells) {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &style,
+                    slot.children().next().unwrap(),
+                    rows_cursor,
+                    viewport,
+                );
+            }
+        }
+
+        for (column, slot) in header.slots.iter().enumerate() {
+            let divider = Rectangle {
+                x: slot.x + slot.width - appearance.divider_width / 2.0,
+                y: bounds.y,
+                width: appearance.divider_width,
+                height: bounds.height,
+            };
+
+            if column + 1 < columns && divider.intersects(viewport) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: divider,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.divider_color,
+                );
+            }
+        }
+
+        let active_divider = state.drag.map(|drag| drag.column).or_else(|| {
+            let _ = self.on_resize.as_ref()?;
+
+            header_cursor
+                .position()
+                .and_then(|position| header.divider_at(position))
+        });
+
+        let header_bounds = Rectangle {
+            y: header.bounds.y + header.offset,
+            ..header.bounds
+        };
+
+        renderer.with_layer(header_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(0.0, header.offset),
+                |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: header.bounds,
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.header_background,
+                    );
+
+                    let style = renderer::Style {
+                        text_color: appearance.header_text_color,
+                    };
+
+                    for (column, ((slot, cell), state)) in slots
+                        .iter()
+                        .zip(&self.cells)
+                        .zip(&tree.children)
+                        .take(columns)
+                        .enumerate()
+                    {
+                        cell.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            &style,
+                            slot.children().next().unwrap(),
+                            header_cursor,
+                            &header.bounds,
+                        );
+
+                        let slot = slot.bounds();
+
+                        if let Some((_, order)) =
+                            self.sort.filter(|(sorted, _)| *sorted == column)
+                        {
+                            let size = renderer.default_size();
+
+                            renderer.fill_text(text::Text {
+                                content: match order {
+                                    Order::Ascending => "▲",
+                                    Order::Descending => "▼",
+                                },
+                                bounds: Rectangle {
+                                    x: slot.x + slot.width
+                                        - self.padding.right
+                                        - size / 2.0,
+                                    y: slot.center_y(),
+                                    width: size,
+                                    height: slot.height,
+                                },
+                                size: size * 0.75,
+                                line_height: LineHeight::default(),
+                                color: appearance.header_text_color,
+                                font: renderer.default_font(),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Center,
+                                vertical_alignment: alignment::Vertical::Center,
+                                shaping: Shaping::Advanced,
+                            });
+                        }
+
+                        if column + 1 < columns
+                            || active_divider == Some(column)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: slot.x + slot.width
+                                            - appearance.divider_width / 2.0,
+                                        y: slot.y,
+                                        width: appearance.divider_width,
+                                        height: slot.height,
+                                    },
+                                    border_radius: 0.0.into(),
+                                    border_width: 0.0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                if active_divider == Some(column) {
+                                    appearance.active_divider_color
+                                } else {
+                                    appearance.divider_color
+                                },
+                            );
+                        }
+                    }
+                },
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((cell, state), slot)| {
+                cell.as_widget_mut().overlay(
+                    state,
+                    slot.children().next().unwrap(),
+                    renderer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        A11yTree::join(
+            self.cells
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((cell, slot), state)| {
+                    cell.as_widget().a11y_nodes(
+                        slot.children().next().unwrap(),
+                        state,
+                        cursor,
+                    )
+                }),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    anchor: Option<usize>,
+    keyboard_modifiers: keyboard::Modifiers,
+    sticky_offset: Cell<f32>,
+}
+
+/// A column divider being dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+/// The header of a [`Table`], which may be shifted down to stay visible.
+struct Header {
+    bounds: Rectangle,
+    slots: Vec<Rectangle>,
+    offset: f32,
+}
+
+impl Header {
+    fn new(layout: Layout<'_>, columns: usize, state: &State) -> Self {
+        let slots: Vec<_> = layout
+            .children()
+            .take(columns)
+            .map(|slot| slot.bounds())
+            .collect();
+
+        let bounds = Rectangle {
+            height: slots.first().map_or(0.0, |slot| slot.height),
+            ..layout.bounds()
+        };
+
+        Self {
+            bounds,
+            slots,
+            offset: state.sticky_offset.get(),
+        }
+    }
+
+    /// Returns whether the cursor is over the header, where it is drawn.
+    fn is_over(&self, cursor: mouse::Cursor) -> bool {
+        cursor.is_over(Rectangle {
+            y: self.bounds.y + self.offset,
+            ..self.bounds
+        })
+    }
+
+    /// Returns the cursor relative to the original position of the header.
+    fn cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        match cursor.position() {
+            Some(position) if self.is_over(cursor) => mouse::Cursor::Available(
+                position - Vector::new(0.0, self.offset),
+            ),
+            _ => mouse::Cursor::Unavailable,
+        }
+    }
+
+    fn column_at(&self, position: Point) -> Option<usize> {
+        self.slots.iter().position(|slot| slot.contains(position))
+    }
+
+    fn divider_at(&self, position: Point) -> Option<usize> {
+        self.slots.iter().position(|slot| {
+            (position.x - (slot.x + slot.width)).abs() <= DIVIDER_GRAB_AREA
+                && position.y >= slot.y
+                && position.y <= slot.y + slot.height
+        })
+    }
+}
+
+/// Returns the index of the row at the given position, if any.
+fn row_at(
+    layout: Layout<'_>,
+    columns: usize,
+    position: Point,
+) -> Option<usize> {
+    let bounds = layout.bounds();
+
+    if columns == 0
+        || position.x < bounds.x
+        || position.x > bounds.x + bounds.width
+    {
+        return None;
+    }
+
+    layout.children().step_by(columns).skip(1).position(|slot| {
+        let slot = slot.bounds();
+
+        position.y >= slot.y && position.y < slot.y + slot.height
+    })
+}
+
+/// Computes the new selection after a row is clicked, given the current
+/// selection, the row that anchors a Shift selection and the pressed
+/// modifiers.
+fn select(
+    selection: &[usize],
+    anchor: Option<usize>,
+    row: usize,
+    modifiers: keyboard::Modifiers,
+) -> Vec<usize> {
+    let mut selection = match (modifiers.shift(), anchor) {
+        (true, Some(anchor)) => {
+            let range = anchor.min(row)..=anchor.max(row);
+
+            if modifiers.command() {
+                selection.iter().copied().chain(range).collect()
+            } else {
+                range.collect()
+            }
+        }
+        _ if modifiers.command() => {
+            if selection.contains(&row) {
+                selection.iter().copied().filter(|&i| i != row).collect()
+            } else {
+                selection.iter().copied().chain([row]).collect()
+            }
+        }
+        _ => vec![row],
+    };
+
+    selection.sort_unstable();
+    selection.dedup();
+    selection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_single_row() {
+        let modifiers = keyboard::Modifiers::default();
+
+        assert_eq!(select(&[1, 4], Some(1), 3, modifiers), vec![3]);
+    }
+
+    #[test]
+    fn select_toggles_row_with_command() {
+        let modifiers = keyboard::Modifiers::COMMAND;
+
+        assert_eq!(select(&[1, 4], Some(1), 3, modifiers), vec![1, 3, 4]);
+        assert_eq!(select(&[1, 3, 4], Some(3), 3, modifiers), vec![1, 4]);
+    }
+
+    #[test]
+    fn select_range_with_shift() {
+        let shift = keyboard::Modifiers::SHIFT;
+
+        assert_eq!(select(&[5], Some(5), 2, shift), vec![2, 3, 4, 5]);
+        assert_eq!(select(&[], None, 2, shift), vec![2]);
+        assert_eq!(
+            select(&[0], Some(5), 7, shift | keyboard::Modifiers::COMMAND),
+            vec![0, 5, 6, 7]
+        );
+    }
+}
@@ -0,0 +1,1331 @@
+//! Switch between different views with a bar of tabs.
+//!
+//! A [`Tabs`] widget only displays the content of its active [`Tab`], but it
+//! keeps the state of every [`Tab`] around. Like a [`PaneGrid`], it does not
+//! own the order of the tabs: selecting, closing and reordering them produce
+//! messages that your application can react to.
+//!
+//! [`PaneGrid`]: crate::PaneGrid
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+#[cfg(feature = "a11y")]
+use std::cell::RefCell;
+
+pub use crate::style::tabs::{Appearance, Bar, StyleSheet};
+
+/// The distance the cursor has to travel before a pressed tab is picked for
+/// dragging.
+const DRAG_DEADBAND: f32 = 5.0;
+
+/// A bar of tabs that shows the content of the active one.
+///
+/// # Example
+/// ```no_run
+/// # type Renderer = iced_widget::renderer::Renderer<iced_widget::style::Theme>;
+/// # type Tabs<'a, Message> = iced_widget::Tabs<'a, Message, Renderer>;
+/// # type Tab<'a, Message> = iced_widget::tabs::Tab<'a, Message, Renderer>;
+/// # type Text<'a> = iced_widget::Text<'a, Renderer>;
+/// use iced_widget::tabs;
+///
+/// struct Document {
+///     title: String,
+///     contents: String,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+///     Dragged(tabs::DragEvent),
+/// }
+///
+/// fn view(documents: &[Document], active: usize) -> Tabs<'_, Message> {
+///     Tabs::new(
+///         documents.iter().map(|document| {
+///             Tab::new(Text::new(&document.title), Text::new(&document.contents))
+///         }),
+///         active,
+///     )
+///     .on_select(Message::Select)
+///     .on_close(Message::Close)
+///     .on_drag(Message::Dragged)
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    tabs: Vec<Tab<'a, Message, Renderer>>,
+    active: usize,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A tab of a [`Tabs`] widget.
+#[allow(missing_debug_implementations)]
+pub struct Tab<'a, Message, Renderer = crate::Renderer> {
+    id: Option<Id>,
+    label: Element<'a, Message, Renderer>,
+    icon: Option<Element<'a, Message, Renderer>>,
+    content: Element<'a, Message, Renderer>,
+    is_closable: bool,
+}
+
+impl<'a, Message, Renderer> Tab<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Tab`] with the given label and content.
+    pub fn new(
+        label: impl Into<Element<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Self {
+            id: None,
+            label: label.into(),
+            icon: None,
+            content: content.into(),
+            is_closable: true,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Tab`].
+    ///
+    /// The state of a [`Tab`] with an [`Id`] follows it when the tabs are
+    /// reordered.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the icon of the [`Tab`], displayed before its label.
+    pub fn icon(
+        mut self,
+        icon: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets whether the [`Tab`] shows a close button.
+    ///
+    /// Close buttons are only shown when [`Tabs::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+
+    fn state(&self) -> Tree {
+        let icon = self
+            .icon
+            .as_ref()
+            .map(Tree::new)
+            .unwrap_or_else(Tree::empty);
+
+        Tree {
+            id: self.id.clone(),
+            children: vec![
+                Tree::new(&self.label),
+                icon,
+                Tree::new(&self.content),
+            ],
+            ..Tree::empty()
+        }
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        if tree.children.len() == 3 {
+            tree.children[0].diff(&mut self.label);
+
+            if let Some(icon) = self.icon.as_mut() {
+                tree.children[1].diff(icon);
+            }
+
+            tree.children[2].diff(&mut self.content);
+        } else {
+            *tree = self.state();
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Tabs`] widget with the given tabs and the index of the
+    /// active one.
+    pub fn new(
+        tabs: impl IntoIterator<Item = Tab<'a, Message, Renderer>>,
+        active: usize,
+    ) -> Self {
+        Self {
+            id: Id::unique(),
+            tabs: tabs.into_iter().collect(),
+            active,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::from([5, 10]),
+            spacing: 5.0,
+            on_select: None,
+            on_close: None,
+            on_drag: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Tab`] to the [`Tabs`] widget.
+    pub fn push(mut self, tab: Tab<'a, Message, Renderer>) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Tabs`] widget.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the width of the [`Tabs`] widget.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`] widget.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every tab in the bar.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the icon, the label and the close button of
+    /// every tab in the bar.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is selected.
+    pub fn on_select(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the close button of a
+    /// [`Tab`] is pressed.
+    ///
+    /// The tabs will not show any close buttons if this is not set.
+    pub fn on_close(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the drag and drop interactions of the [`Tabs`] widget, which
+    /// will use the provided function to produce messages.
+    pub fn on_drag(mut self, f: impl Fn(DragEvent) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`] widget.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn active(&self) -> Option<usize> {
+        self.tabs
+            .len()
+            .checked_sub(1)
+            .map(|last| self.active.min(last))
+    }
+
+    fn close_size(&self, renderer: &Renderer) -> f32 {
+        if self.on_close.is_some() {
+            renderer.default_size()
+        } else {
+            0.0
+        }
+    }
+
+    fn close_bounds(&self, header: Rectangle, size: f32) -> Rectangle {
+        Rectangle {
+            x: header.x + header.width - self.padding.right - size,
+            y: header.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    fn is_closable(&self, index: usize) -> bool {
+        self.on_close.is_some() && self.tabs[index].is_closable
+    }
+
+    /// Returns the current scroll offset of the bar, clamped to its contents.
+    fn offset(&self, state: &State, bar: Layout<'_>) -> f32 {
+        let bounds = bar.bounds();
+        let content_width = bar
+            .children()
+            .last()
+            .map(|header| {
+                let header = header.bounds();
+
+                header.x + header.width - bounds.x
+            })
+            .unwrap_or_default();
+
+        state.offset.min(content_width - bounds.width).max(0.0)
+    }
+
+    fn draw_tab(
+        &self,
+        index: usize,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let tab = &self.tabs[index];
+        let bounds = layout.bounds();
+        let is_selected = self.active() == Some(index);
+
+        let appearance = if cursor.is_over(bounds) {
+            theme.hovered(&self.style, is_selected)
+        } else {
+            theme.active(&self.style, is_selected)
+        };
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                background,
+            );
+        }
+
+        let style = renderer::Style {
+            text_color: appearance.text_color,
+        };
+
+        let mut children = layout.children();
+        let label_layout = children.next().unwrap();
+        let icon_layout = children.next().unwrap();
+
+        if let Some(icon) = &tab.icon {
+            icon.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                &style,
+                icon_layout,
+                cursor,
+                &bounds,
+            );
+        }
+
+        tab.label.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &style,
+            label_layout,
+            cursor,
+            &bounds,
+        );
+
+        if self.is_closable(index) {
+            let size = self.close_size(renderer);
+            let close = self.close_bounds(bounds, size);
+
+            if let Some(background) = appearance
+                .close_hovered_background
+                .filter(|_| cursor.is_over(close))
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: close,
+                        border_radius: (size / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(text::Text {
+                content: "×",
+                bounds: Rectangle {
+                    x: close.center_x(),
+                    y: close.center_y(),
+                    ..close
+                },
+                size,
+                line_height: LineHeight::default(),
+                color: appearance.close_color,
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Basic,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.tabs.iter().map(Tab::state).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let mut previous: Vec<_> = std::mem::take(&mut tree.children)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        tree.children = self
+            .tabs
+            .iter_mut()
+            .enumerate()
+            .map(|(index, tab)| {
+                let position = match &tab.id {
+                    Some(id) => previous.iter().position(|state| {
+                        state.as_ref().and_then(|state| state.id.as_ref())
+                            == Some(id)
+                    }),
+                    None => previous
+                        .get(index)
+                        .and_then(Option::as_ref)
+                        .filter(|state| state.id.is_none())
+                        .map(|_| index),
+                };
+
+                let mut state = position
+                    .and_then(|position| previous[position].take())
+                    .unwrap_or_else(|| tab.state());
+
+                tab.diff(&mut state);
+
+                state
+            })
+            .collect();
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let close_size = self.close_size(renderer);
+
+        let header_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(limits.max().width, f32::INFINITY),
+        );
+
+        let headers: Vec<_> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let label =
+                    tab.label.as_widget().layout(renderer, &header_limits);
+                let icon = tab.icon.as_ref().map(|icon| {
+                    icon.as_widget().layout(renderer, &header_limits)
+                });
+
+                (label, icon, self.is_closable(index))
+            })
+            .collect();
+
+        let content_height = headers
+            .iter()
+            .map(|(label, icon, is_closable)| {
+                let icon = icon
+                    .as_ref()
+                    .map(|icon| icon.size().height)
+                    .unwrap_or_default();
+                let close = if *is_closable { close_size } else { 0.0 };
+
+                label.size().height.max(icon).max(close)
+            })
+            .fold(0.0, f32::max);
+
+        let bar_height = content_height + self.padding.vertical();
+        let center = |node: &layout::Node| {
+            self.padding.top + (content_height - node.size().height) / 2.0
+        };
+
+        let mut x = 0.0;
+
+        let headers: Vec<_> = headers
+            .into_iter()
+            .map(|(mut label, icon, is_closable)| {
+                let mut width = self.padding.left;
+
+                let icon = match icon {
+                    Some(mut icon) => {
+                        icon.move_to(Point::new(width, center(&icon)));
+                        width += icon.size().width + self.spacing;
+
+                        icon
+                    }
+                    None => layout::Node::new(Size::ZERO),
+                };
+
+                label.move_to(Point::new(width, center(&label)));
+                width += label.size().width;
+
+                if is_closable {
+                    width += self.spacing + close_size;
+                }
+
+                width += self.padding.right;
+
+                let mut header = layout::Node::with_children(
+                    Size::new(width, bar_height),
+                    vec![label, icon],
+                );
+
+                header.move_to(Point::new(x, 0.0));
+                x += width;
+
+                header
+            })
+            .collect();
+
+        let mut content = match self.active() {
+            Some(active) => self.tabs[active]
+                .content
+                .as_widget()
+                .layout(renderer, &limits.shrink(Size::new(0.0, bar_height))),
+            None => layout::Node::new(Size::ZERO),
+        };
+
+        content.move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            bar_height + content.size().height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            headers,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let Some(active) = self.active() else {
+            return;
+        };
+
+        operation.container(Some(&self.id), &mut |operation| {
+            self.tabs[active].content.as_widget().operate(
+                &mut tree.children[active].children[2],
+                layout.children().nth(1).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(active) = self.active() else {
+            return event::Status::Ignored;
+        };
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // Bring a newly activated tab into view
+        if state.active != Some(active) {
+            let bounds = bar.bounds();
+            let header = bar.children().nth(active).unwrap().bounds();
+
+            let left = header.x - bounds.x;
+            let right = left + header.width;
+
+            if left < state.offset {
+                state.offset = left;
+            } else if right > state.offset + bounds.width {
+                state.offset = right - bounds.width;
+            }
+
+            state.active = Some(active);
+        }
+
+        let offset = self.offset(state, bar);
+        state.offset = offset;
+
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest { action, .. },
+        ) = &event
+        {
+            use iced_accessibility::accesskit::Action as A11yAction;
+
+            let index = state
+                .tab_ids
+                .borrow()
+                .iter()
+                .take(self.tabs.len())
+                .position(|id| id == event_id);
+
+            if let Some(index) = index {
+                if matches!(action, A11yAction::Default | A11yAction::Focus) {
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(index));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let bar_cursor = scrolled(cursor, bar.bounds(), offset);
+        let action = state.action;
+
+        let mut status = self.tabs[active].content.as_widget_mut().on_event(
+            &mut tree.children[active].children[2],
+            event.clone(),
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        for ((tab, tree), header) in self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(bar.children())
+        {
+            let mut children = header.children();
+            let label = children.next().unwrap();
+            let icon = children.next().unwrap();
+
+            status = status.merge(tab.label.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                label,
+                bar_cursor,
+                renderer,
+                clipboard,
+                shell,
+            ));
+
+            if let Some(icon_widget) = tab.icon.as_mut() {
+                status = status.merge(icon_widget.as_widget_mut().on_event(
+                    &mut tree.children[1],
+                    event.clone(),
+                    icon,
+                    bar_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                ));
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let headers: Vec<_> =
+            bar.children().map(|header| header.bounds()).collect();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                let Some(position) = bar_cursor.position() else {
+                    return status;
+                };
+
+                let Some(index) =
+                    headers.iter().position(|header| header.contains(position))
+                else {
+                    return event::Status::Captured;
+                };
+
+                let close = self
+                    .close_bounds(headers[index], self.close_size(renderer));
+
+                if self.is_closable(index) && close.contains(position) {
+                    state.action = Action::Closing { index };
+                } else {
+                    if index != active {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(index));
+                        }
+                    }
+
+                    state.action = Action::Pressed {
+                        index,
+                        origin: position,
+                    };
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match action {
+                Action::Pressed { index, origin } => {
+                    let Some(on_drag) = &self.on_drag else {
+                        return status;
+                    };
+
+                    let Some(position) = cursor.position() else {
+                        return status;
+                    };
+
+                    let position = position + Vector::new(offset, 0.0);
+
+                    if position.distance(origin) > DRAG_DEADBAND {
+                        let header = headers[index];
+
+                        state.action = Action::Dragging {
+                            index,
+                            origin: origin - Vector::new(header.x, header.y),
+                        };
+
+                        shell.publish(on_drag(DragEvent::Picked { index }));
+                    }
+
+                    event::Status::Captured
+                }
+                Action::Dragging { .. } => event::Status::Captured,
+                _ => status,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.action = Action::Idle;
+
+                match action {
+                    Action::Idle => status,
+                    Action::Pressed { .. } => event::Status::Captured,
+                    Action::Dragging { index, .. } => {
+                        if let Some(on_drag) = &self.on_drag {
+                            let target = cursor.position().map(|position| {
+                                drop_target(
+                                    &headers,
+                                    index,
+                                    position.x + offset,
+                                )
+                            });
+
+                            shell.publish(on_drag(match target {
+                                Some(target) if target != index => {
+                                    DragEvent::Dropped { index, target }
+                                }
+                                _ => DragEvent::Canceled { index },
+                            }));
+                        }
+
+                        event::Status::Captured
+                    }
+                    Action::Closing { index } => {
+                        let close = self.close_bounds(
+                            headers[index],
+                            self.close_size(renderer),
+                        );
+
+                        if bar_cursor.is_over(close) {
+                            if let Some(on_close) = &self.on_close {
+                                shell.publish(on_close(index));
+                            }
+                        }
+
+                        event::Status::Captured
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if status == event::Status::Ignored
+                    && cursor.is_over(bar.bounds()) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x == 0.0 { y } else { x }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                state.offset -= delta;
+                state.offset = self.offset(state, bar);
+
+                if state.offset == offset {
+                    event::Status::Ignored
+                } else {
+                    event::Status::Captured
+                }
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(active) = self.active() else {
+            return mouse::Interaction::default();
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Action::Dragging { .. } = state.action {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bar_cursor =
+            scrolled(cursor, bar.bounds(), self.offset(state, bar));
+
+        let hovered_tab = self
+            .tabs
+            .iter()
+            .zip(&tree.children)
+            .zip(bar.children())
+            .enumerate()
+            .find(|(_, (_, header))| bar_cursor.is_over(header.bounds()));
+
+        let Some((index, ((tab, tree), header))) = hovered_tab else {
+            return self.tabs[active].content.as_widget().mouse_interaction(
+                &tree.children[active].children[2],
+                content,
+                cursor,
+                viewport,
+                renderer,
+            );
+        };
+
+        let close =
+            self.close_bounds(header.bounds(), self.close_size(renderer));
+
+        if self.is_closable(index) && bar_cursor.is_over(close) {
+            return mouse::Interaction::Pointer;
+        }
+
+        let mut children = header.children();
+        let label = children.next().unwrap();
+        let icon = children.next().unwrap();
+
+        let interaction = tab
+            .label
+            .as_widget()
+            .mouse_interaction(
+                &tree.children[0],
+                label,
+                bar_cursor,
+                viewport,
+                renderer,
+            )
+            .max(
+                tab.icon
+                    .as_ref()
+                    .map(|icon_widget| {
+                        icon_widget.as_widget().mouse_interaction(
+                            &tree.children[1],
+                            icon,
+                            bar_cursor,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .unwrap_or_default(),
+            );
+
+        if interaction == mouse::Interaction::default() {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bounds = bar.bounds();
+        let appearance = theme.bar(&self.style);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        if appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - appearance.border_width,
+                        height: appearance.border_width,
+                        ..bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.border_color,
+            );
+        }
+
+        let offset = self.offset(state, bar);
+        let headers: Vec<_> = bar.children().collect();
+
+        let (picked, bar_cursor) = match state.action {
+            Action::Dragging { index, origin } => {
+                (Some((index, origin)), mouse::Cursor::Unavailable)
+            }
+            _ => (None, scrolled(cursor, bounds, offset)),
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(-offset, 0.0), |renderer| {
+                for (index, header) in headers.iter().enumerate() {
+                    if matches!(picked, Some((picked, _)) if picked == index) {
+                        continue;
+                    }
+
+                    self.draw_tab(
+                        index,
+                        &tree.children[index],
+                        renderer,
+                        theme,
+                        *header,
+                        bar_cursor,
+                    );
+                }
+
+                let Some(((index, _), position)) =
+                    picked.zip(cursor.position())
+                else {
+                    return;
+                };
+
+                let target = drop_target(
+                    &headers.iter().map(Layout::bounds).collect::<Vec<_>>(),
+                    index,
+                    position.x + offset,
+                );
+
+                if target != index {
+                    let mut others = headers
+                        .iter()
+                        .enumerate()
+                        .filter(|(other, _)| *other != index)
+                        .map(|(_, header)| header.bounds());
+
+                    let x = match others.nth(target) {
+                        Some(header) => header.x,
+                        None => {
+                            let last = headers.last().unwrap().bounds();
+
+                            last.x + last.width
+                        }
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x - 1.0,
+                                y: bounds.y,
+                                width: 2.0,
+                                height: bounds.height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.drop_indicator,
+                    );
+                }
+            });
+
+            // Render picked tab last, following the cursor
+            if let Some(((index, origin), position)) =
+                picked.zip(cursor.position())
+            {
+                let header = headers[index];
+
+                renderer.with_translation(
+                    Vector::new(position.x - origin.x - header.bounds().x, 0.0),
+                    |renderer| {
+                        self.draw_tab(
+                            index,
+                            &tree.children[index],
+                            renderer,
+                            theme,
+                            header,
+                            mouse::Cursor::Available(header.bounds().center()),
+                        );
+                    },
+                );
+            }
+        });
+
+        if let Some(active) = self.active() {
+            self.tabs[active].content.as_widget().draw(
+                &tree.children[active].children[2],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let active = self.active()?;
+
+        self.tabs[active].content.as_widget_mut().overlay(
+            &mut tree.children[active].children[2],
+            layout.children().nth(1).unwrap(),
+            renderer,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action as A11yAction, NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let Some(active) = self.active() else {
+            return A11yTree::default();
+        };
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let offset = self.offset(state, bar);
+        let bar_cursor = scrolled(cursor, bar.bounds(), offset);
+
+        let mut tab_ids = state.tab_ids.borrow_mut();
+
+        while tab_ids.len() < self.tabs.len() {
+            tab_ids.push(Id::unique());
+        }
+
+        let tabs = self
+            .tabs
+            .iter()
+            .zip(&tree.children)
+            .zip(bar.children())
+            .zip(tab_ids.iter())
+            .enumerate()
+            .map(|(index, (((tab, tree), header), id))| {
+                let bounds = header.bounds() + Vector::new(-offset, 0.0);
+
+                let mut node = NodeBuilder::new(Role::Tab);
+                node.set_bounds(rect(bounds));
+                node.set_selected(index == active);
+                node.add_action(A11yAction::Default);
+
+                if cursor.is_over(bounds) {
+                    node.set_hovered();
+                }
+
+                A11yTree::node_with_child_tree(
+                    A11yNode::new(node, id.clone()),
+                    tab.label.as_widget().a11y_nodes(
+                        header.children().next().unwrap(),
+                        &tree.children[0],
+                        bar_cursor,
+                    ),
+                )
+            });
+
+        let mut tab_list = NodeBuilder::new(Role::TabList);
+        tab_list.set_bounds(rect(bar.bounds()));
+
+        A11yTree::join(
+            [
+                A11yTree::node_with_child_tree(
+                    A11yNode::new(tab_list, self.id.clone()),
+                    A11yTree::join(tabs),
+                ),
+                self.tabs[active].content.as_widget().a11y_nodes(
+                    content,
+                    &tree.children[active].children[2],
+                    cursor,
+                ),
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tabs: Tabs<'a, Message, Renderer>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+/// An event produced during a drag and drop interaction of a [`Tabs`]
+/// widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragEvent {
+    /// A [`Tab`] was picked for dragging.
+    Picked {
+        /// The index of the picked [`Tab`].
+        index: usize,
+    },
+
+    /// A [`Tab`] was dropped at a new position.
+    Dropped {
+        /// The index of the picked [`Tab`].
+        index: usize,
+
+        /// The index the picked [`Tab`] should be moved to, once removed
+        /// from its current position.
+        target: usize,
+    },
+
+    /// A [`Tab`] was picked and then dropped where it was or outside of the
+    /// tab bar.
+    Canceled {
+        /// The index of the picked [`Tab`].
+        index: usize,
+    },
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug, Default)]
+struct State {
+    action: Action,
+    offset: f32,
+    active: Option<usize>,
+    #[cfg(feature = "a11y")]
+    tab_ids: RefCell<Vec<Id>>,
+}
+
+/// The current interaction of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Action {
+    #[default]
+    Idle,
+    Pressed {
+        index: usize,
+        origin: Point,
+    },
+    Dragging {
+        index: usize,
+        origin: Point,
+    },
+    Closing {
+        index: usize,
+    },
+}
+
+/// Returns a [`mouse::Cursor`] positioned in the scrolled coordinates of the
+/// tab bar, if it is over it.
+fn scrolled(
+    cursor: mouse::Cursor,
+    bounds: Rectangle,
+    offset: f32,
+) -> mouse::Cursor {
+    match cursor.position_over(bounds) {
+        Some(position) => {
+            mouse::Cursor::Available(position + Vector::new(offset, 0.0))
+        }
+        None => mouse::Cursor::Unavailable,
+    }
+}
+
+/// Returns the index a dragged tab would be moved to if dropped at the given
+/// horizontal position.
+fn drop_target(headers: &[Rectangle], index: usize, x: f32) -> usize {
+    headers
+        .iter()
+        .enumerate()
+        .filter(|(other, header)| *other != index && header.center_x() < x)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers() -> Vec<Rectangle> {
+        (0..3)
+            .map(|i| Rectangle {
+                x: i as f32 * 10.0,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn drop_target_moves_forward() {
+        let headers = headers();
+
+        assert_eq!(drop_target(&headers, 0, 12.0), 0);
+        assert_eq!(drop_target(&headers, 0, 16.0), 1);
+        assert_eq!(drop_target(&headers, 0, 29.0), 2);
+    }
+
+    #[test]
+    fn drop_target_moves_backward() {
+        let headers = headers();
+
+        assert_eq!(drop_target(&headers, 2, 1.0), 0);
+        assert_eq!(drop_target(&headers, 2, 14.0), 1);
+        assert_eq!(drop_target(&headers, 2, 26.0), 2);
+    }
+}
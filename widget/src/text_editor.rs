@@ -0,0 +1,948 @@
+//! Display a multi-line text editor.
+//!
+//! A [`TextEditor`] displays a [`Content`] owned by your application and
+//! produces an [`Action`] for every interaction, which you can then
+//! [`perform`] on the [`Content`].
+//!
+//! [`perform`]: Content::perform
+mod content;
+
+pub use content::{Action, Content, Edit, Motion, Position};
+
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse::{self, click};
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, OperationOutputWrapper};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+use crate::runtime::Command;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use iced_style::text_editor::{Appearance, StyleSheet};
+
+/// A multi-line text editor.
+///
+/// # Example
+/// ```no_run
+/// # pub type TextEditor<'a, Message> =
+/// #     iced_widget::TextEditor<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::text_editor;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Edit(text_editor::Action),
+/// }
+///
+/// let content = text_editor::Content::with_text("Some text");
+///
+/// let editor = TextEditor::new(&content)
+///     .on_action(Message::Edit)
+///     .height(200);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    content: &'a Content,
+    placeholder: String,
+    font: Option<Renderer::Font>,
+    size: Option<f32>,
+    line_height: text::LineHeight,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TextEditor`] displaying the given [`Content`].
+    pub fn new(content: &'a Content) -> Self {
+        Self {
+            id: None,
+            content,
+            placeholder: String::new(),
+            font: None,
+            size: None,
+            line_height: text::LineHeight::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(5.0),
+            on_action: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TextEditor`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the placeholder of the [`TextEditor`], displayed when its
+    /// [`Content`] is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the message that should be produced when some [`Action`] is
+    /// performed in the [`TextEditor`].
+    ///
+    /// If this method is not called, the [`TextEditor`] will be disabled.
+    pub fn on_action(
+        mut self,
+        on_action: impl Fn(Action) -> Message + 'a,
+    ) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`TextEditor`].
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// By default, the [`TextEditor`] grows with its [`Content`]; any other
+    /// height makes it scroll.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextEditor`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Unfocus text editor if it becomes disabled
+        if self.on_action.is_none() {
+            state.last_click = None;
+            state.is_focused = None;
+            state.is_dragging = false;
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(Pixels(size)));
+
+        let padding = self.padding.fit(Size::ZERO, limits.max());
+        let limits = limits.width(self.width).height(self.height).pad(padding);
+
+        let text_width =
+            limits.resolve(Size::new(limits.max().width, 0.0)).width;
+        let visual_lines = self.content.wrap(renderer, font, size, text_width);
+
+        let text_bounds = limits.resolve(Size::new(
+            text_width,
+            visual_lines.max(1) as f32 * line_height,
+        ));
+
+        self.content
+            .resize((text_bounds.height / line_height).floor() as usize);
+
+        let mut text = layout::Node::new(text_bounds);
+        text.move_to(Point::new(padding.left, padding.top));
+
+        layout::Node::with_children(text_bounds.pad(padding), vec![text])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let content = self.content;
+
+        let Some(on_action) = self.on_action.as_deref() else {
+            return event::Status::Ignored;
+        };
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(Pixels(size)));
+
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let find_position = |point: Point| {
+            find_position(
+                renderer,
+                content,
+                text_bounds,
+                font,
+                size,
+                line_height,
+                point,
+            )
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                else {
+                    state.is_focused = None;
+                    state.is_dragging = false;
+
+                    return event::Status::Ignored;
+                };
+
+                state.is_focused = state.is_focused.or_else(|| {
+                    let now = Instant::now();
+
+                    Some(Focus {
+                        updated_at: now,
+                        now,
+                    })
+                });
+
+                let position = find_position(cursor_position);
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        if state.keyboard_modifiers.shift() {
+                            shell.publish(on_action(Action::Drag(position)));
+                        } else {
+                            shell.publish(on_action(Action::Click(position)));
+                        }
+
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        shell.publish(on_action(Action::Click(position)));
+                        shell.publish(on_action(Action::SelectWord));
+
+                        state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        shell.publish(on_action(Action::Click(position)));
+                        shell.publish(on_action(Action::SelectLine));
+
+                        state.is_dragging = false;
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if state.is_dragging =>
+            {
+                shell.publish(on_action(Action::Drag(find_position(position))));
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds())
+                    && content.visual_lines().len() as f32 * line_height
+                        > text_bounds.height =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y / line_height,
+                };
+
+                let lines = lines.round() as i32;
+
+                if lines != 0 {
+                    shell.publish(on_action(Action::Scroll { lines }));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                if let Some(focus) = &mut state.is_focused {
+                    if !state.keyboard_modifiers.command() && !c.is_control() {
+                        shell.publish(on_action(Action::Edit(Edit::Insert(c))));
+
+                        focus.updated_at = Instant::now();
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let Some(focus) = &mut state.is_focused else {
+                    return event::Status::Ignored;
+                };
+
+                let modifiers = state.keyboard_modifiers;
+                focus.updated_at = Instant::now();
+
+                let motion = match key_code {
+                    keyboard::KeyCode::Left
+                        if platform::is_jump_modifier_pressed(modifiers) =>
+                    {
+                        Some(Motion::WordLeft)
+                    }
+                    keyboard::KeyCode::Right
+                        if platform::is_jump_modifier_pressed(modifiers) =>
+                    {
+                        Some(Motion::WordRight)
+                    }
+                    keyboard::KeyCode::Home if modifiers.command() => {
+                        Some(Motion::DocumentStart)
+                    }
+                    keyboard::KeyCode::End if modifiers.command() => {
+                        Some(Motion::DocumentEnd)
+                    }
+                    keyboard::KeyCode::Left => Some(Motion::Left),
+                    keyboard::KeyCode::Right => Some(Motion::Right),
+                    keyboard::KeyCode::Up => Some(Motion::Up),
+                    keyboard::KeyCode::Down => Some(Motion::Down),
+                    keyboard::KeyCode::Home => Some(Motion::Home),
+                    keyboard::KeyCode::End => Some(Motion::End),
+                    keyboard::KeyCode::PageUp => Some(Motion::PageUp),
+                    keyboard::KeyCode::PageDown => Some(Motion::PageDown),
+                    _ => None,
+                };
+
+                if let Some(motion) = motion {
+                    let action = if modifiers.shift() {
+                        Action::Select(motion)
+                    } else {
+                        Action::Move(motion)
+                    };
+
+                    shell.publish(on_action(action));
+
+                    return event::Status::Captured;
+                }
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        shell.publish(on_action(Action::Edit(Edit::Enter)));
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && content.selection().is_none()
+                        {
+                            shell.publish(on_action(Action::Select(
+                                Motion::WordLeft,
+                            )));
+                        }
+
+                        shell.publish(on_action(Action::Edit(Edit::Backspace)));
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && content.selection().is_none()
+                        {
+                            shell.publish(on_action(Action::Select(
+                                Motion::WordRight,
+                            )));
+                        }
+
+                        shell.publish(on_action(Action::Edit(Edit::Delete)));
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some(selection) = content.selection() {
+                            clipboard.write(selection);
+                        }
+                    }
+                    keyboard::KeyCode::X if modifiers.command() => {
+                        if let Some(selection) = content.selection() {
+                            clipboard.write(selection);
+
+                            shell
+                                .publish(on_action(Action::Edit(Edit::Delete)));
+                        }
+                    }
+                    keyboard::KeyCode::V if modifiers.command() => {
+                        if let Some(contents) = clipboard.read() {
+                            shell.publish(on_action(Action::Edit(
+                                Edit::Paste(contents),
+                            )));
+                        }
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        shell.publish(on_action(Action::SelectAll));
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = None;
+                        state.is_dragging = false;
+
+                        state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) if state.is_focused.is_some() => {
+                if key_code == keyboard::KeyCode::Tab {
+                    return event::Status::Ignored;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if let Some(focus) = &mut state.is_focused {
+                    focus.now = now;
+
+                    let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
+                        - (now - focus.updated_at).as_millis()
+                            % CURSOR_BLINK_INTERVAL_MILLIS;
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Duration::from_millis(millis_until_redraw as u64),
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            theme,
+            layout,
+            cursor,
+            tree.state.downcast_ref::<State>(),
+            Properties {
+                content: self.content,
+                placeholder: &self.placeholder,
+                size: self.size,
+                line_height: self.line_height,
+                font: self.font,
+                is_disabled: self.on_action.is_none(),
+                style: &self.style,
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            if self.on_action.is_none() {
+                mouse::Interaction::NotAllowed
+            } else {
+                mouse::Interaction::Text
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id.clone()
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = Some(id);
+    }
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TextEditor`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// The properties of a [`TextEditor`] needed to [`draw`] it.
+#[allow(missing_debug_implementations)]
+pub struct Properties<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The [`Content`] of the [`TextEditor`].
+    pub content: &'a Content,
+    /// The text displayed when the [`Content`] is empty.
+    pub placeholder: &'a str,
+    /// The text size of the [`TextEditor`].
+    pub size: Option<f32>,
+    /// The line height of the [`TextEditor`].
+    pub line_height: text::LineHeight,
+    /// The font of the [`TextEditor`].
+    pub font: Option<Renderer::Font>,
+    /// Whether the [`TextEditor`] ignores interactions.
+    pub is_disabled: bool,
+    /// The style of the [`TextEditor`].
+    pub style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// Draws the [`TextEditor`] with the given [`Renderer`].
+///
+/// [`Renderer`]: text::Renderer
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+    properties: Properties<'_, Renderer>,
+) where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let Properties {
+        content,
+        placeholder,
+        size,
+        line_height,
+        font,
+        is_disabled,
+        style,
+    } = properties;
+
+    let bounds = layout.bounds();
+    let text_bounds = layout.children().next().unwrap().bounds();
+
+    let appearance = if is_disabled {
+        theme.disabled(style)
+    } else if state.is_focused() {
+        theme.focused(style)
+    } else if cursor.is_over(bounds) {
+        theme.hovered(style)
+    } else {
+        theme.active(style)
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: appearance.border_radius,
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+        },
+        appearance.background,
+    );
+
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let size = size.unwrap_or_else(|| renderer.default_size());
+    let line_height_absolute = f32::from(line_height.to_absolute(Pixels(size)));
+
+    let measure = |renderer: &Renderer, content: &str| {
+        renderer.measure_width(content, size, font, text::Shaping::Advanced)
+    };
+
+    if content.is_empty() {
+        renderer.fill_text(Text {
+            content: placeholder,
+            color: theme.placeholder_color(style),
+            font,
+            bounds: Rectangle {
+                height: line_height_absolute,
+                ..text_bounds
+            },
+            size,
+            line_height,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+        });
+    }
+
+    let selection = content.selection_range();
+    let cursor_position = content.cursor_position();
+    let cursor_index = content.visual_index(cursor_position);
+
+    let is_cursor_visible = state.is_focused.as_ref().is_some_and(|focus| {
+        selection.is_none()
+            && ((focus.now - focus.updated_at).as_millis()
+                / CURSOR_BLINK_INTERVAL_MILLIS)
+                .is_multiple_of(2)
+    });
+
+    let value_color = if is_disabled {
+        theme.disabled_color(style)
+    } else {
+        theme.value_color(style)
+    };
+
+    let scroll = content.scroll();
+    let visible_lines =
+        (text_bounds.height / line_height_absolute).ceil() as usize + 1;
+
+    renderer.with_layer(text_bounds, |renderer| {
+        let visual_lines = content.visual_lines();
+
+        for (index, visual_line) in visual_lines
+            .iter()
+            .copied()
+            .enumerate()
+            .skip(scroll)
+            .take(visible_lines)
+        {
+            let text = content.visual_text(visual_line);
+            let y =
+                text_bounds.y + (index - scroll) as f32 * line_height_absolute;
+
+            let x_at = |renderer: &Renderer, column: usize| {
+                let column = column.clamp(visual_line.start, visual_line.end);
+                let prefix: String = text
+                    .graphemes(true)
+                    .take(column - visual_line.start)
+                    .collect();
+
+                measure(renderer, &prefix)
+            };
+
+            if let Some((start, end)) = selection {
+                if (start.line..=end.line).contains(&visual_line.line) {
+                    let from = if visual_line.line == start.line {
+                        start.column.max(visual_line.start)
+                    } else {
+                        visual_line.start
+                    };
+
+                    let to = if visual_line.line == end.line {
+                        end.column.min(visual_line.end)
+                    } else {
+                        visual_line.end
+                    };
+
+                    let is_line_break = visual_line.line < end.line
+                        && visual_lines
+                            .get(index + 1)
+                            .is_none_or(|next| next.line != visual_line.line);
+
+                    if from < to || (from == to && is_line_break) {
+                        let left = x_at(renderer, from);
+                        let right = x_at(renderer, to)
+                            + if is_line_break { size / 3.0 } else { 0.0 };
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + left,
+                                    y,
+                                    width: right - left,
+                                    height: line_height_absolute,
+                                },
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            theme.selection_color(style),
+                        );
+                    }
+                }
+            }
+
+            renderer.fill_text(Text {
+                content: text,
+                color: value_color,
+                font,
+                bounds: Rectangle {
+                    x: text_bounds.x,
+                    y,
+                    width: f32::INFINITY,
+                    height: line_height_absolute,
+                },
+                size,
+                line_height,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if is_cursor_visible && index == cursor_index {
+                let x = x_at(renderer, cursor_position.column);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + x,
+                            y,
+                            width: 1.0,
+                            height: line_height_absolute,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    value_color,
+                );
+            }
+        }
+    });
+}
+
+/// The state of a [`TextEditor`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: Option<Focus>,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Focus {
+    updated_at: Instant,
+    now: Instant,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused.is_some()
+    }
+
+    /// Focuses the [`TextEditor`].
+    pub fn focus(&mut self) {
+        let now = Instant::now();
+
+        self.is_focused = Some(Focus {
+            updated_at: now,
+            now,
+        });
+    }
+
+    /// Unfocuses the [`TextEditor`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = None;
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+mod platform {
+    use crate::core::keyboard;
+
+    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt()
+        } else {
+            modifiers.control()
+        }
+    }
+}
+
+/// Computes the [`Position`] in the [`Content`] at the given point of a
+/// [`TextEditor`].
+fn find_position<Renderer>(
+    renderer: &Renderer,
+    content: &Content,
+    text_bounds: Rectangle,
+    font: Renderer::Font,
+    size: f32,
+    line_height: f32,
+    point: Point,
+) -> Position
+where
+    Renderer: text::Renderer,
+{
+    let visual_lines = content.visual_lines();
+
+    let row = ((point.y - text_bounds.y) / line_height).floor().max(0.0);
+    let index = (content.scroll() + row as usize)
+        .min(visual_lines.len().saturating_sub(1));
+
+    let Some(visual_line) = visual_lines.get(index).copied() else {
+        return Position::default();
+    };
+
+    let text = content.visual_text(visual_line);
+    let x = point.x - text_bounds.x;
+
+    let is_last = visual_lines
+        .get(index + 1)
+        .is_none_or(|next| next.line != visual_line.line);
+
+    let offset = if x <= 0.0 {
+        0
+    } else if x
+        >= renderer.measure_width(text, size, font, text::Shaping::Advanced)
+    {
+        visual_line.end
+            - visual_line.start
+            - usize::from(!is_last && visual_line.end > visual_line.start)
+    } else {
+        renderer
+            .hit_test(
+                text,
+                size,
+                text::LineHeight::Absolute(Pixels(line_height)),
+                font,
+                Size::INFINITY,
+                text::Shaping::Advanced,
+                Point::new(x, line_height / 2.0),
+                true,
+            )
+            .map(text::Hit::cursor)
+            .map(|offset| {
+                text[..offset.min(text.len())].graphemes(true).count()
+            })
+            .unwrap_or(0)
+    };
+
+    Position::new(visual_line.line, visual_line.start + offset)
+}
+
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
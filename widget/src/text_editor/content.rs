@@ -0,0 +1,829 @@
+//! Store and edit the contents of a [`TextEditor`].
+//!
+//! [`TextEditor`]: crate::TextEditor
+use crate::core::text;
+use crate::text_input::value::Value;
+
+use std::cell::{Ref, RefCell};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The contents of a [`TextEditor`].
+///
+/// A [`Content`] is owned by your application and changed by performing an
+/// [`Action`] on it, usually produced by the [`TextEditor`] itself.
+///
+/// [`TextEditor`]: crate::TextEditor
+#[derive(Debug)]
+pub struct Content {
+    lines: Vec<String>,
+    cursor: Position,
+    anchor: Option<Position>,
+    preferred_column: Option<usize>,
+    wrapping: RefCell<Wrapping>,
+}
+
+/// A position in a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The index of the grapheme inside the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`] with the given line and column.
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// An interaction with a [`Content`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Move the cursor with the given [`Motion`].
+    Move(Motion),
+    /// Extend the selection with the given [`Motion`].
+    Select(Motion),
+    /// Select the word at the cursor.
+    SelectWord,
+    /// Select the line at the cursor.
+    SelectLine,
+    /// Select all the contents.
+    SelectAll,
+    /// Perform an [`Edit`].
+    Edit(Edit),
+    /// Place the cursor at the given [`Position`].
+    Click(Position),
+    /// Extend the selection up to the given [`Position`].
+    Drag(Position),
+    /// Scroll the visible lines by the given amount.
+    Scroll {
+        /// The amount of lines to scroll; negative values scroll up.
+        lines: i32,
+    },
+}
+
+impl Action {
+    /// Returns whether the [`Action`] changes the text of a [`Content`].
+    pub fn is_edit(&self) -> bool {
+        matches!(self, Self::Edit(_))
+    }
+}
+
+/// A change to the text of a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit {
+    /// Insert a character at the cursor.
+    Insert(char),
+    /// Insert some text at the cursor.
+    Paste(String),
+    /// Break the line at the cursor.
+    Enter,
+    /// Delete the selection or the grapheme before the cursor.
+    Backspace,
+    /// Delete the selection or the grapheme after the cursor.
+    Delete,
+}
+
+/// A movement of the cursor of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// Move one grapheme to the left.
+    Left,
+    /// Move one grapheme to the right.
+    Right,
+    /// Move one visual line up.
+    Up,
+    /// Move one visual line down.
+    Down,
+    /// Move to the previous start of a word.
+    WordLeft,
+    /// Move to the next end of a word.
+    WordRight,
+    /// Move to the start of the line.
+    Home,
+    /// Move to the end of the line.
+    End,
+    /// Move one page up.
+    PageUp,
+    /// Move one page down.
+    PageDown,
+    /// Move to the start of the contents.
+    DocumentStart,
+    /// Move to the end of the contents.
+    DocumentEnd,
+}
+
+/// A line of a [`Content`] as it is displayed after wrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct VisualLine {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Default)]
+struct Wrapping {
+    visual_lines: Vec<VisualLine>,
+    visible_lines: usize,
+    scroll: usize,
+    follow_cursor: bool,
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Content {
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_text("")
+    }
+
+    /// Creates a [`Content`] with the given text.
+    pub fn with_text(text: &str) -> Self {
+        let lines: Vec<String> =
+            normalize(text).split('\n').map(String::from).collect();
+
+        let content = Self {
+            lines,
+            cursor: Position::default(),
+            anchor: None,
+            preferred_column: None,
+            wrapping: RefCell::new(Wrapping::default()),
+        };
+
+        content.invalidate();
+        content
+    }
+
+    /// Returns the full text of the [`Content`].
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns whether the [`Content`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Returns the amount of lines of the [`Content`].
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the line at the given index, if it exists.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.lines.get(index).map(String::as_str)
+    }
+
+    /// Returns an iterator over the lines of the [`Content`].
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the [`Position`] of the cursor.
+    pub fn cursor_position(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selection(&self) -> Option<String> {
+        let (start, end) = self.selection_range()?;
+
+        if start.line == end.line {
+            let line = &self.lines[start.line];
+
+            return Some(String::from(
+                &line[byte_offset(line, start.column)
+                    ..byte_offset(line, end.column)],
+            ));
+        }
+
+        let first = &self.lines[start.line];
+        let last = &self.lines[end.line];
+
+        let mut selection =
+            String::from(&first[byte_offset(first, start.column)..]);
+
+        for line in &self.lines[start.line + 1..end.line] {
+            selection.push('\n');
+            selection.push_str(line);
+        }
+
+        selection.push('\n');
+        selection.push_str(&last[..byte_offset(last, end.column)]);
+
+        Some(selection)
+    }
+
+    /// Performs an [`Action`] on the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        let preferred_column = self.preferred_column.take();
+
+        match action {
+            Action::Move(motion) => {
+                if let (Some((start, end)), Motion::Left | Motion::Right) =
+                    (self.selection_range(), motion)
+                {
+                    self.cursor =
+                        if motion == Motion::Left { start } else { end };
+                } else {
+                    self.move_cursor(motion, preferred_column);
+                }
+
+                self.anchor = None;
+            }
+            Action::Select(motion) => {
+                let anchor = self.anchor.unwrap_or(self.cursor);
+
+                self.move_cursor(motion, preferred_column);
+                self.anchor = (anchor != self.cursor).then_some(anchor);
+            }
+            Action::SelectWord => {
+                let line = Value::new(&self.lines[self.cursor.line]);
+
+                let start = line.previous_start_of_word(self.cursor.column);
+                let end = line.next_end_of_word(self.cursor.column);
+
+                self.select(
+                    Position::new(self.cursor.line, start),
+                    Position::new(self.cursor.line, end),
+                );
+            }
+            Action::SelectLine => {
+                let line = self.cursor.line;
+
+                let end = if line + 1 < self.lines.len() {
+                    Position::new(line + 1, 0)
+                } else {
+                    Position::new(line, graphemes(&self.lines[line]))
+                };
+
+                self.select(Position::new(line, 0), end);
+            }
+            Action::SelectAll => {
+                let last = self.lines.len() - 1;
+
+                self.select(
+                    Position::default(),
+                    Position::new(last, graphemes(&self.lines[last])),
+                );
+            }
+            Action::Edit(edit) => {
+                self.edit(edit);
+                self.invalidate();
+            }
+            Action::Click(position) => {
+                self.cursor = self.clamp(position);
+                self.anchor = None;
+            }
+            Action::Drag(position) => {
+                let anchor = self.anchor.unwrap_or(self.cursor);
+
+                self.cursor = self.clamp(position);
+                self.anchor = (anchor != self.cursor).then_some(anchor);
+            }
+            Action::Scroll { lines } => {
+                let mut wrapping = self.wrapping.borrow_mut();
+
+                let max_scroll = wrapping
+                    .visual_lines
+                    .len()
+                    .saturating_sub(wrapping.visible_lines);
+
+                wrapping.scroll = (wrapping.scroll as i64 + i64::from(lines))
+                    .clamp(0, max_scroll as i64)
+                    as usize;
+
+                return;
+            }
+        }
+
+        self.wrapping.borrow_mut().follow_cursor = true;
+    }
+
+    /// Returns the start and end of the current selection, if any.
+    ///
+    /// `start` is guaranteed to be <= than `end`.
+    pub(crate) fn selection_range(&self) -> Option<(Position, Position)> {
+        let anchor = self.clamp(self.anchor?);
+        let cursor = self.clamp(self.cursor);
+
+        if anchor == cursor {
+            None
+        } else {
+            Some((anchor.min(cursor), anchor.max(cursor)))
+        }
+    }
+
+    /// Returns the visual lines of the [`Content`] as computed by the last
+    /// call to [`Content::wrap`].
+    pub(crate) fn visual_lines(&self) -> Ref<'_, [VisualLine]> {
+        Ref::map(self.wrapping.borrow(), |wrapping| {
+            wrapping.visual_lines.as_slice()
+        })
+    }
+
+    /// Returns the text of the given [`VisualLine`].
+    pub(crate) fn visual_text(&self, visual_line: VisualLine) -> &str {
+        let Some(line) = self.lines.get(visual_line.line) else {
+            return "";
+        };
+
+        &line[byte_offset(line, visual_line.start)
+            ..byte_offset(line, visual_line.end)]
+    }
+
+    /// Returns the index of the first visible [`VisualLine`].
+    pub(crate) fn scroll(&self) -> usize {
+        self.wrapping.borrow().scroll
+    }
+
+    /// Returns the index of the [`VisualLine`] containing the given
+    /// [`Position`].
+    pub(crate) fn visual_index(&self, position: Position) -> usize {
+        visual_index(&self.wrapping.borrow().visual_lines, position)
+    }
+
+    /// Wraps the lines of the [`Content`] to fit in the given width,
+    /// returning the resulting amount of visual lines.
+    pub(crate) fn wrap<Renderer>(
+        &self,
+        renderer: &Renderer,
+        font: Renderer::Font,
+        size: f32,
+        width: f32,
+    ) -> usize
+    where
+        Renderer: text::Renderer,
+    {
+        let mut visual_lines = Vec::with_capacity(self.lines.len());
+
+        for (index, line) in self.lines.iter().enumerate() {
+            wrap_line(
+                renderer,
+                index,
+                line,
+                font,
+                size,
+                width,
+                &mut visual_lines,
+            );
+        }
+
+        let mut wrapping = self.wrapping.borrow_mut();
+        wrapping.visual_lines = visual_lines;
+        wrapping.visual_lines.len()
+    }
+
+    /// Sets the amount of visual lines that fit in the viewport, scrolling
+    /// to the cursor if it has moved since the last call.
+    pub(crate) fn resize(&self, visible_lines: usize) {
+        let cursor = self.visual_index(self.cursor);
+        let mut wrapping = self.wrapping.borrow_mut();
+
+        wrapping.visible_lines = visible_lines.max(1);
+
+        if wrapping.follow_cursor {
+            if cursor < wrapping.scroll {
+                wrapping.scroll = cursor;
+            } else if cursor >= wrapping.scroll + wrapping.visible_lines {
+                wrapping.scroll = cursor + 1 - wrapping.visible_lines;
+            }
+
+            wrapping.follow_cursor = false;
+        }
+
+        wrapping.scroll = wrapping.scroll.min(
+            wrapping
+                .visual_lines
+                .len()
+                .saturating_sub(wrapping.visible_lines),
+        );
+    }
+
+    /// Resets the visual lines to the unwrapped lines of the [`Content`].
+    ///
+    /// This keeps the visual lines consistent with the text until the
+    /// [`TextEditor`] wraps them again during layout.
+    ///
+    /// [`TextEditor`]: crate::TextEditor
+    fn invalidate(&self) {
+        let mut wrapping = self.wrapping.borrow_mut();
+
+        wrapping.visual_lines = self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(line, text)| VisualLine {
+                line,
+                start: 0,
+                end: graphemes(text),
+            })
+            .collect();
+    }
+
+    fn select(&mut self, start: Position, end: Position) {
+        self.anchor = (start != end).then_some(start);
+        self.cursor = end;
+    }
+
+    fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.lines.len() - 1);
+
+        Position::new(line, position.column.min(graphemes(&self.lines[line])))
+    }
+
+    fn move_cursor(&mut self, motion: Motion, preferred_column: Option<usize>) {
+        let Position { line, column } = self.clamp(self.cursor);
+
+        self.cursor = match motion {
+            Motion::Left if column > 0 => Position::new(line, column - 1),
+            Motion::Left if line > 0 => {
+                Position::new(line - 1, graphemes(&self.lines[line - 1]))
+            }
+            Motion::Right if column < graphemes(&self.lines[line]) => {
+                Position::new(line, column + 1)
+            }
+            Motion::Right if line + 1 < self.lines.len() => {
+                Position::new(line + 1, 0)
+            }
+            Motion::Left | Motion::Right => Position::new(line, column),
+            Motion::WordLeft if column > 0 => Position::new(
+                line,
+                Value::new(&self.lines[line]).previous_start_of_word(column),
+            ),
+            Motion::WordRight if column < graphemes(&self.lines[line]) => {
+                Position::new(
+                    line,
+                    Value::new(&self.lines[line]).next_end_of_word(column),
+                )
+            }
+            Motion::WordLeft => {
+                self.cursor = Position::new(line, column);
+                return self.move_cursor(Motion::Left, None);
+            }
+            Motion::WordRight => {
+                self.cursor = Position::new(line, column);
+                return self.move_cursor(Motion::Right, None);
+            }
+            Motion::Home => Position::new(line, 0),
+            Motion::End => Position::new(line, graphemes(&self.lines[line])),
+            Motion::Up => {
+                return self.move_vertically(-1, preferred_column);
+            }
+            Motion::Down => {
+                return self.move_vertically(1, preferred_column);
+            }
+            Motion::PageUp => {
+                let page = self.wrapping.borrow().visible_lines.max(1);
+
+                return self
+                    .move_vertically(-(page as isize), preferred_column);
+            }
+            Motion::PageDown => {
+                let page = self.wrapping.borrow().visible_lines.max(1);
+
+                return self.move_vertically(page as isize, preferred_column);
+            }
+            Motion::DocumentStart => Position::default(),
+            Motion::DocumentEnd => {
+                let last = self.lines.len() - 1;
+
+                Position::new(last, graphemes(&self.lines[last]))
+            }
+        };
+    }
+
+    fn move_vertically(
+        &mut self,
+        lines: isize,
+        preferred_column: Option<usize>,
+    ) {
+        let cursor = self.clamp(self.cursor);
+        let wrapping = self.wrapping.borrow();
+        let visual_lines = &wrapping.visual_lines;
+
+        let current = visual_index(visual_lines, cursor);
+        let offset = preferred_column
+            .unwrap_or(cursor.column - visual_lines[current].start);
+
+        let target = (current as isize + lines)
+            .clamp(0, visual_lines.len() as isize - 1)
+            as usize;
+
+        let position = if target == current {
+            if lines < 0 {
+                Position::new(cursor.line, visual_lines[current].start)
+            } else {
+                Position::new(cursor.line, visual_lines[current].end)
+            }
+        } else {
+            let visual_line = visual_lines[target];
+
+            let is_last = visual_lines
+                .get(target + 1)
+                .is_none_or(|next| next.line != visual_line.line);
+
+            let last_column = if is_last {
+                visual_line.end
+            } else {
+                visual_line.end.saturating_sub(1).max(visual_line.start)
+            };
+
+            Position::new(
+                visual_line.line,
+                (visual_line.start + offset).min(last_column),
+            )
+        };
+
+        drop(wrapping);
+
+        self.cursor = position;
+        self.preferred_column = Some(offset);
+    }
+
+    fn edit(&mut self, edit: Edit) {
+        match edit {
+            Edit::Insert(character) => {
+                let _ = self.delete_selection();
+                self.insert_text(character.encode_utf8(&mut [0; 4]));
+            }
+            Edit::Paste(text) => {
+                let _ = self.delete_selection();
+                self.insert_text(&normalize(&text));
+            }
+            Edit::Enter => {
+                let _ = self.delete_selection();
+                self.insert_text("\n");
+            }
+            Edit::Backspace => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let Position { line, column } = self.clamp(self.cursor);
+
+                if column > 0 {
+                    self.delete(Position::new(line, column - 1), self.cursor);
+                } else if line > 0 {
+                    let previous = graphemes(&self.lines[line - 1]);
+
+                    self.delete(Position::new(line - 1, previous), self.cursor);
+                }
+            }
+            Edit::Delete => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let Position { line, column } = self.clamp(self.cursor);
+
+                if column < graphemes(&self.lines[line]) {
+                    self.delete(self.cursor, Position::new(line, column + 1));
+                } else if line + 1 < self.lines.len() {
+                    self.delete(self.cursor, Position::new(line + 1, 0));
+                }
+            }
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection_range() {
+            Some((start, end)) => {
+                self.delete(start, end);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn delete(&mut self, start: Position, end: Position) {
+        let start = self.clamp(start);
+        let end = self.clamp(end);
+
+        let last = &self.lines[end.line];
+        let suffix = String::from(&last[byte_offset(last, end.column)..]);
+
+        let first = &mut self.lines[start.line];
+        first.truncate(byte_offset(first, start.column));
+        first.push_str(&suffix);
+
+        let _ = self.lines.drain(start.line + 1..=end.line);
+
+        self.cursor = start;
+        self.anchor = None;
+    }
+
+    fn insert_text(&mut self, text: &str) {
+        let Position { line, column } = self.clamp(self.cursor);
+
+        let offset = byte_offset(&self.lines[line], column);
+        let suffix = self.lines[line].split_off(offset);
+
+        let mut current = line;
+
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                current += 1;
+                self.lines.insert(current, String::new());
+            }
+
+            self.lines[current].push_str(part);
+        }
+
+        let column = graphemes(&self.lines[current]);
+        self.lines[current].push_str(&suffix);
+
+        self.cursor = Position::new(current, column);
+        self.anchor = None;
+    }
+}
+
+fn visual_index(visual_lines: &[VisualLine], position: Position) -> usize {
+    let mut index = visual_lines
+        .partition_point(|visual_line| visual_line.line < position.line);
+
+    while visual_lines.get(index + 1).is_some_and(|next| {
+        next.line == position.line && position.column >= next.start
+    }) {
+        index += 1;
+    }
+
+    index.min(visual_lines.len().saturating_sub(1))
+}
+
+fn wrap_line<Renderer>(
+    renderer: &Renderer,
+    index: usize,
+    line: &str,
+    font: Renderer::Font,
+    size: f32,
+    width: f32,
+    visual_lines: &mut Vec<VisualLine>,
+) where
+    Renderer: text::Renderer,
+{
+    let measure = |content: &str| {
+        renderer.measure_width(content, size, font, text::Shaping::Advanced)
+    };
+
+    if line.is_empty() || measure(line) <= width {
+        visual_lines.push(VisualLine {
+            line: index,
+            start: 0,
+            end: graphemes(line),
+        });
+
+        return;
+    }
+
+    let mut start = 0;
+    let mut start_byte = 0;
+    let mut end = 0;
+    let mut end_byte = 0;
+
+    for segment in line.split_word_bounds() {
+        let next_byte = end_byte + segment.len();
+
+        if segment.trim().is_empty()
+            || measure(&line[start_byte..next_byte]) <= width
+        {
+            end += graphemes(segment);
+            end_byte = next_byte;
+            continue;
+        }
+
+        if end > start {
+            visual_lines.push(VisualLine {
+                line: index,
+                start,
+                end,
+            });
+
+            start = end;
+            start_byte = end_byte;
+
+            if measure(segment) <= width {
+                end += graphemes(segment);
+                end_byte = next_byte;
+                continue;
+            }
+        }
+
+        for grapheme in segment.graphemes(true) {
+            let next_byte = end_byte + grapheme.len();
+
+            if end > start && measure(&line[start_byte..next_byte]) > width {
+                visual_lines.push(VisualLine {
+                    line: index,
+                    start,
+                    end,
+                });
+
+                start = end;
+                start_byte = end_byte;
+            }
+
+            end += 1;
+            end_byte = next_byte;
+        }
+    }
+
+    visual_lines.push(VisualLine {
+        line: index,
+        start,
+        end,
+    });
+}
+
+fn normalize(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+fn graphemes(line: &str) -> usize {
+    line.graphemes(true).count()
+}
+
+fn byte_offset(line: &str, column: usize) -> usize {
+    line.grapheme_indices(true)
+        .nth(column)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(text: &str, line: usize, column: usize) -> Content {
+        let mut content = Content::with_text(text);
+        content.perform(Action::Click(Position::new(line, column)));
+        content
+    }
+
+    #[test]
+    fn edits_across_lines() {
+        let mut content = content("hello\nworld", 0, 5);
+
+        content.perform(Action::Edit(Edit::Enter));
+        content.perform(Action::Edit(Edit::Insert('!')));
+        assert_eq!(content.text(), "hello\n!\nworld");
+        assert_eq!(content.cursor_position(), Position::new(1, 1));
+
+        content.perform(Action::Move(Motion::Right));
+        content.perform(Action::Edit(Edit::Backspace));
+        assert_eq!(content.text(), "hello\n!world");
+
+        content.perform(Action::Move(Motion::DocumentStart));
+        content.perform(Action::Move(Motion::End));
+        content.perform(Action::Edit(Edit::Delete));
+        assert_eq!(content.text(), "hello!world");
+        assert_eq!(content.line_count(), 1);
+    }
+
+    #[test]
+    fn selects_and_replaces_across_lines() {
+        let mut content = content("one\ntwo\nthree", 0, 1);
+
+        content.perform(Action::Select(Motion::Down));
+        content.perform(Action::Select(Motion::Down));
+        assert_eq!(content.selection().as_deref(), Some("ne\ntwo\nt"));
+
+        content.perform(Action::Edit(Edit::Paste(String::from("x\r\ny"))));
+        assert_eq!(content.text(), "ox\nyhree");
+        assert_eq!(content.cursor_position(), Position::new(1, 1));
+        assert_eq!(content.selection(), None);
+    }
+
+    #[test]
+    fn moves_vertically_keeping_column() {
+        let mut content = content("long line\n\nanother line", 0, 7);
+
+        content.perform(Action::Move(Motion::Down));
+        assert_eq!(content.cursor_position(), Position::new(1, 0));
+
+        content.perform(Action::Move(Motion::Down));
+        assert_eq!(content.cursor_position(), Position::new(2, 7));
+
+        content.perform(Action::Move(Motion::Down));
+        assert_eq!(content.cursor_position(), Position::new(2, 12));
+    }
+
+    #[test]
+    fn selects_words_and_lines() {
+        let mut content = content("fn main() {}\nbody", 0, 4);
+
+        content.perform(Action::SelectWord);
+        assert_eq!(content.selection().as_deref(), Some("main"));
+
+        content.perform(Action::SelectLine);
+        assert_eq!(content.selection().as_deref(), Some("fn main() {}\n"));
+
+        content.perform(Action::Move(Motion::Left));
+        assert_eq!(content.cursor_position(), Position::new(0, 0));
+    }
+}
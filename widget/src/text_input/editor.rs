@@ -1,13 +1,23 @@
+use crate::text_input::history::{History, Kind};
 use crate::text_input::{Cursor, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    history: &'a mut History,
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        history: &'a mut History,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            history,
+        }
     }
 
     pub fn contents(&self) -> String {
@@ -15,6 +25,12 @@ impl<'a> Editor<'a> {
     }
 
     pub fn insert(&mut self, character: char) {
+        self.record(if self.cursor.selection(self.value).is_some() {
+            Kind::Other
+        } else {
+            Kind::Typing
+        });
+
         if let Some((left, right)) = self.cursor.selection(self.value) {
             self.cursor.move_left(self.value);
             self.value.remove_many(left, right);
@@ -22,9 +38,13 @@ impl<'a> Editor<'a> {
 
         self.value.insert(self.cursor.end(self.value), character);
         self.cursor.move_right(self.value);
+
+        self.history.track(self.value, *self.cursor);
     }
 
     pub fn paste(&mut self, content: Value) {
+        self.record(Kind::Other);
+
         let length = content.len();
         if let Some((left, right)) = self.cursor.selection(self.value) {
             self.cursor.move_left(self.value);
@@ -39,6 +59,8 @@ impl<'a> Editor<'a> {
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
+                self.record(Kind::Other);
+
                 self.cursor.move_left(self.value);
                 self.value.remove_many(start, end);
             }
@@ -46,8 +68,12 @@ impl<'a> Editor<'a> {
                 let start = self.cursor.start(self.value);
 
                 if start > 0 {
+                    self.record(Kind::Deleting);
+
                     self.cursor.move_left(self.value);
                     self.value.remove(start - 1);
+
+                    self.history.track(self.value, *self.cursor);
                 }
             }
         }
@@ -62,9 +88,17 @@ impl<'a> Editor<'a> {
                 let end = self.cursor.end(self.value);
 
                 if end < self.value.len() {
+                    self.record(Kind::Deleting);
+
                     self.value.remove(end);
+
+                    self.history.track(self.value, *self.cursor);
                 }
             }
         }
     }
+
+    fn record(&mut self, kind: Kind) {
+        self.history.record(kind, self.value, *self.cursor);
+    }
 }
@@ -0,0 +1,186 @@
+use crate::text_input::{Cursor, Value};
+
+/// The maximum amount of undo steps kept by a [`History`].
+const LIMIT: usize = 100;
+
+/// The edit history of a text input.
+///
+/// Consecutive edits of the same [`Kind`] are grouped into a single undo step
+/// as long as the cursor stays where the previous edit left it.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    group: Option<(Kind, usize)>,
+}
+
+/// The kind of an edit recorded in a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Characters typed one by one.
+    Typing,
+    /// Graphemes deleted one by one.
+    Deleting,
+    /// Any other edit, which is never grouped with others.
+    Other,
+}
+
+/// An undo or redo request waiting to be applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Request {
+    /// Undo the last edit.
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records the state of a text input right before an edit of the given
+    /// [`Kind`].
+    pub fn record(&mut self, kind: Kind, value: &Value, cursor: Cursor) {
+        self.redo.clear();
+
+        let position = cursor.end(value);
+
+        if kind != Kind::Other && self.group == Some((kind, position)) {
+            return;
+        }
+
+        if self.undo.len() == LIMIT {
+            let _ = self.undo.remove(0);
+        }
+
+        self.undo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        self.group = Some((kind, position));
+    }
+
+    /// Tracks the position of the cursor right after the last recorded edit.
+    pub fn track(&mut self, value: &Value, cursor: Cursor) {
+        if let Some((kind, _)) = self.group {
+            self.group = Some((kind, cursor.end(value)));
+        }
+    }
+
+    /// Returns the previous state of a text input, saving the current one
+    /// so it can be redone.
+    pub fn undo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let entry = self.undo.pop()?;
+
+        self.redo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.group = None;
+
+        Some((entry.value, entry.cursor))
+    }
+
+    /// Returns the last undone state of a text input, saving the current
+    /// one so it can be undone again.
+    pub fn redo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.group = None;
+
+        Some((entry.value, entry.cursor))
+    }
+
+    /// Applies the given [`Request`], returning the resulting state of the
+    /// text input, if any.
+    pub fn apply(
+        &mut self,
+        request: Request,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        match request {
+            Request::Undo => self.undo(value, cursor),
+            Request::Redo => self.redo(value, cursor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_input::editor::Editor;
+
+    #[test]
+    fn groups_consecutive_typing() {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let mut history = History::default();
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        editor.insert('a');
+        editor.insert('b');
+        editor.paste(Value::new("cd"));
+        editor.insert('e');
+        editor.backspace();
+        editor.backspace();
+
+        assert_eq!(value.to_string(), "abc");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "abcde");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "abcd");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "ab");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "");
+        assert!(history.undo(&value, cursor).is_none());
+
+        let (value, _) = history.redo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "ab");
+    }
+
+    #[test]
+    fn breaks_groups_when_the_cursor_moves() {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let mut history = History::default();
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        editor.insert('a');
+        editor.insert('b');
+
+        cursor.move_to(0);
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        editor.insert('c');
+
+        assert_eq!(value.to_string(), "cab");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "ab");
+
+        let (value, _) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "");
+    }
+}
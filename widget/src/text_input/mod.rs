@@ -2,6 +2,8 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 pub(crate) mod editor;
+pub(crate) mod history;
+pub(crate) mod preedit;
 pub(crate) mod value;
 
 pub mod cursor;
@@ -0,0 +1,92 @@
+use crate::text_input::{Cursor, Value};
+
+/// The text being composed by an input method in a text input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preedit {
+    content: String,
+    cursor: Option<(usize, usize)>,
+}
+
+/// A [`Value`] with a [`Preedit`] composed into it.
+#[derive(Debug, Clone)]
+pub struct Composition {
+    /// The composed value.
+    pub value: Value,
+    /// The grapheme index where the [`Preedit`] starts.
+    pub start: usize,
+    /// The grapheme index where the [`Preedit`] ends.
+    pub end: usize,
+    /// The grapheme index of the cursor of the input method, if visible.
+    pub cursor: Option<usize>,
+}
+
+impl Preedit {
+    /// Creates a new [`Preedit`] with the given content and cursor byte
+    /// range, or `None` if the content is empty.
+    pub fn new(
+        content: String,
+        cursor: Option<(usize, usize)>,
+    ) -> Option<Self> {
+        if content.is_empty() {
+            None
+        } else {
+            Some(Self { content, cursor })
+        }
+    }
+
+    /// Composes the [`Preedit`] into the given [`Value`] at the [`Cursor`],
+    /// replacing the current selection, if any.
+    pub fn compose(&self, value: &Value, cursor: Cursor) -> Composition {
+        let mut composed = value.clone();
+
+        let start = match cursor.selection(value) {
+            Some((left, right)) => {
+                composed.remove_many(left, right);
+                left
+            }
+            None => cursor.end(value),
+        };
+
+        let preedit = Value::new(&self.content);
+        let end = start + preedit.len();
+
+        composed.insert_many(start, preedit);
+
+        let cursor = self.cursor.and_then(|(_, end)| {
+            self.content
+                .get(..end)
+                .map(|before| start + Value::new(before).len())
+        });
+
+        Composition {
+            value: composed,
+            start,
+            end,
+            cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composes_into_the_selection() {
+        let value = Value::new("hello world");
+        let mut cursor = Cursor::default();
+        cursor.select_range(6, 11);
+
+        let preedit = Preedit::new(String::from("世界"), Some((3, 3))).unwrap();
+        let composition = preedit.compose(&value, cursor);
+
+        assert_eq!(composition.value.to_string(), "hello 世界");
+        assert_eq!((composition.start, composition.end), (6, 8));
+        assert_eq!(composition.cursor, Some(7));
+    }
+
+    #[test]
+    fn ignores_empty_content() {
+        assert!(Preedit::new(String::new(), None).is_none());
+    }
+}
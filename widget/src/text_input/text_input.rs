@@ -7,6 +7,8 @@ pub use super::value::Value;
 use super::cursor;
 use super::editor;
 use super::editor::Editor;
+use super::history::{self, History};
+use super::preedit::Preedit;
 use super::value;
 use iced_renderer::core::widget::OperationOutputWrapper;
 
@@ -29,6 +31,9 @@ use crate::core::{
 };
 use crate::runtime::Command;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 pub use iced_style::text_input::{Appearance, StyleSheet};
 
 /// A field that can be filled with text.
@@ -59,7 +64,13 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
-    id: Option<Id>,
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     placeholder: String,
     value: Value,
     is_secure: bool,
@@ -88,7 +99,13 @@ where
     /// - the current value
     pub fn new(placeholder: &str, value: &str) -> Self {
         TextInput {
-            id: None,
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
@@ -107,7 +124,7 @@ where
 
     /// Sets the [`Id`] of the [`TextInput`].
     pub fn id(mut self, id: Id) -> Self {
-        self.id = Some(id);
+        self.id = id;
         self
     }
 
@@ -196,6 +213,46 @@ where
         self
     }
 
+    /// Replaces the current value of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`TextInput`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`TextInput`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
+
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
@@ -288,8 +345,17 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
-        operation.focusable(state, self.id.as_ref());
-        operation.text_input(state, self.id.as_ref());
+        if self.on_input.is_some() {
+            state.operated_value = Some(match &state.pending_input {
+                Some((value, _)) => value.clone(),
+                None => self.value.clone(),
+            });
+        }
+
+        operation.focusable(state, Some(&self.id));
+        operation.text_input(state, Some(&self.id));
+
+        state.operated_value = None;
     }
 
     fn on_event(
@@ -302,6 +368,50 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest {
+                action, data, ..
+            },
+        ) = &event
+        {
+            use iced_accessibility::accesskit::{Action, ActionData};
+
+            if *event_id != self.id {
+                return event::Status::Ignored;
+            }
+
+            let state = tree.state.downcast_mut::<State>();
+
+            match (action, data) {
+                (Action::Focus, _) if self.on_input.is_some() => {
+                    state.focus();
+                }
+                (Action::Blur, _) => state.unfocus(),
+                (Action::SetValue, Some(ActionData::Value(new_value))) => {
+                    let Some(on_input) = self.on_input.as_deref() else {
+                        return event::Status::Ignored;
+                    };
+
+                    state.select_all();
+
+                    let mut editor = Editor::new(
+                        &mut self.value,
+                        &mut state.cursor,
+                        &mut state.history,
+                    );
+
+                    editor.paste(Value::new(new_value));
+
+                    shell.publish((on_input)(editor.contents()));
+                }
+                _ => return event::Status::Ignored,
+            }
+
+            return event::Status::Captured;
+        }
+
         update(
             event,
             layout,
@@ -359,6 +469,91 @@ where
     ) -> mouse::Interaction {
         mouse_interaction(layout, cursor, self.on_input.is_none())
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_hovered = cursor_position.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::TextField);
+        node.set_bounds(bounds);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        if self.is_secure {
+            node.set_protected();
+            node.set_value(self.value.secure().to_string());
+        } else {
+            node.set_value(self.value.to_string());
+        }
+
+        if !self.placeholder.is_empty() {
+            node.set_placeholder(self.placeholder.clone());
+        }
+
+        if self.on_input.is_some() {
+            node.add_action(if state.is_focused() {
+                Action::Blur
+            } else {
+                Action::Focus
+            });
+            node.add_action(Action::SetValue);
+        } else {
+            node.set_read_only();
+        }
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Renderer> From<TextInput<'a, Message, Renderer>>
@@ -430,6 +625,22 @@ pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
     Command::widget(operation::text_input::select_all(id))
 }
 
+/// Produces a [`Command`] that undoes the last edit of the [`TextInput`] with the given [`Id`].
+///
+/// The resulting value is produced as an input message when the [`TextInput`]
+/// is redrawn right after the [`Command`] runs.
+pub fn undo<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::undo(id))
+}
+
+/// Produces a [`Command`] that redoes the last undone edit of the [`TextInput`] with the given [`Id`].
+///
+/// The resulting value is produced as an input message when the [`TextInput`]
+/// is redrawn right after the [`Command`] runs.
+pub fn redo<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::redo(id))
+}
+
 /// Computes the layout of a [`TextInput`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -663,13 +874,19 @@ where
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
-                let Some(on_input) = on_input else { return event::Status::Ignored };
+                let Some(on_input) = on_input else {
+                    return event::Status::Ignored;
+                };
 
                 if state.is_pasting.is_none()
                     && !state.keyboard_modifiers.command()
                     && !c.is_control()
                 {
-                    let mut editor = Editor::new(value, &mut state.cursor);
+                    let mut editor = Editor::new(
+                        value,
+                        &mut state.cursor,
+                        &mut state.history,
+                    );
 
                     editor.insert(c);
 
@@ -682,11 +899,49 @@ where
                 }
             }
         }
+        Event::Keyboard(keyboard::Event::Ime(ime)) => {
+            let state = state();
+
+            if let Some(focus) = &mut state.is_focused {
+                let Some(on_input) = on_input else {
+                    return event::Status::Ignored;
+                };
+
+                match ime {
+                    keyboard::Ime::Preedit { content, cursor } => {
+                        state.preedit = Preedit::new(content, cursor);
+                    }
+                    keyboard::Ime::Commit(content) => {
+                        state.preedit = None;
+
+                        let mut editor = Editor::new(
+                            value,
+                            &mut state.cursor,
+                            &mut state.history,
+                        );
+
+                        editor.paste(Value::new(&content));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+                    }
+                    keyboard::Ime::Enabled | keyboard::Ime::Disabled => {
+                        state.preedit = None;
+                    }
+                }
+
+                focus.updated_at = Instant::now();
+
+                return event::Status::Captured;
+            }
+        }
         Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
-                let Some(on_input) = on_input else { return event::Status::Ignored };
+                let Some(on_input) = on_input else {
+                    return event::Status::Ignored;
+                };
 
                 let modifiers = state.keyboard_modifiers;
                 focus.updated_at = Instant::now();
@@ -710,7 +965,11 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
+                        let mut editor = Editor::new(
+                            value,
+                            &mut state.cursor,
+                            &mut state.history,
+                        );
                         editor.backspace();
 
                         let message = (on_input)(editor.contents());
@@ -730,7 +989,11 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
+                        let mut editor = Editor::new(
+                            value,
+                            &mut state.cursor,
+                            &mut state.history,
+                        );
                         editor.delete();
 
                         let message = (on_input)(editor.contents());
@@ -805,7 +1068,11 @@ where
                                 .write(value.select(start, end).to_string());
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
+                        let mut editor = Editor::new(
+                            value,
+                            &mut state.cursor,
+                            &mut state.history,
+                        );
                         editor.delete();
 
                         let message = (on_input)(editor.contents());
@@ -827,8 +1094,11 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                value,
+                                &mut state.cursor,
+                                &mut state.history,
+                            );
 
                             editor.paste(content.clone());
 
@@ -844,6 +1114,28 @@ where
                             state.is_pasting = None;
                         }
                     }
+                    keyboard::KeyCode::Z
+                        if state.keyboard_modifiers.command() =>
+                    {
+                        let request = if modifiers.shift() {
+                            history::Request::Redo
+                        } else {
+                            history::Request::Undo
+                        };
+
+                        apply_history(request, value, state, on_input, shell);
+                    }
+                    keyboard::KeyCode::Y
+                        if state.keyboard_modifiers.command() =>
+                    {
+                        apply_history(
+                            history::Request::Redo,
+                            value,
+                            state,
+                            on_input,
+                            shell,
+                        );
+                    }
                     keyboard::KeyCode::A
                         if state.keyboard_modifiers.command() =>
                     {
@@ -897,6 +1189,16 @@ where
         Event::Window(_, window::Event::RedrawRequested(now)) => {
             let state = state();
 
+            // Publish the result of an undo or redo operation
+            if let Some((new_value, cursor)) = state.pending_input.take() {
+                if let Some(on_input) = on_input {
+                    *value = new_value;
+                    state.cursor = cursor;
+
+                    shell.publish((on_input)(value.to_string()));
+                }
+            }
+
             if let Some(focus) = &mut state.is_focused {
                 focus.now = now;
 
@@ -908,6 +1210,12 @@ where
                     now + Duration::from_millis(millis_until_redraw as u64),
                 ));
             }
+
+            if state.is_focused() && on_input.is_some() && !is_secure {
+                shell.request_input_method(input_method(
+                    renderer, layout, state, value, size, font,
+                ));
+            }
         }
         _ => {}
     }
@@ -941,6 +1249,16 @@ pub fn draw<Renderer>(
     let secure_value = is_secure.then(|| value.secure());
     let value = secure_value.as_ref().unwrap_or(value);
 
+    let composition = state
+        .preedit
+        .as_ref()
+        .filter(|_| state.is_focused())
+        .map(|preedit| preedit.compose(value, state.cursor));
+    let value = composition
+        .as_ref()
+        .map(|composition| &composition.value)
+        .unwrap_or(value);
+
     let bounds = layout.bounds();
 
     let mut children_layout = layout.children();
@@ -991,8 +1309,15 @@ pub fn draw<Renderer>(
     let font = font.unwrap_or_else(|| renderer.default_font());
     let size = size.unwrap_or_else(|| renderer.default_size());
 
+    let cursor_state = match &composition {
+        Some(composition) => {
+            cursor::State::Index(composition.cursor.unwrap_or(composition.end))
+        }
+        None => state.cursor.state(value),
+    };
+
     let (cursor, offset) = if let Some(focus) = &state.is_focused {
-        match state.cursor.state(value) {
+        match cursor_state {
             cursor::State::Index(position) => {
                 let (text_value_width, offset) =
                     measure_cursor_and_scroll_offset(
@@ -1007,8 +1332,10 @@ pub fn draw<Renderer>(
                 let is_cursor_visible = ((focus.now - focus.updated_at)
                     .as_millis()
                     / CURSOR_BLINK_INTERVAL_MILLIS)
-                    % 2
-                    == 0;
+                    .is_multiple_of(2)
+                    && composition
+                        .as_ref()
+                        .is_none_or(|composition| composition.cursor.is_some());
 
                 let cursor = if is_cursor_visible {
                     Some((
@@ -1084,6 +1411,37 @@ pub fn draw<Renderer>(
         (None, 0.0)
     };
 
+    let underline = composition.as_ref().map(|composition| {
+        let (start, _) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            value,
+            size,
+            composition.start,
+            font,
+        );
+        let (end, _) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            value,
+            size,
+            composition.end,
+            font,
+        );
+
+        renderer::Quad {
+            bounds: Rectangle {
+                x: text_bounds.x + start,
+                y: text_bounds.y + text_bounds.height - 1.0,
+                width: end - start,
+                height: 1.0,
+            },
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    });
+
     let text_width = renderer.measure_width(
         if text.is_empty() { placeholder } else { &text },
         size,
@@ -1098,6 +1456,10 @@ pub fn draw<Renderer>(
             renderer.with_translation(Vector::ZERO, |_| {});
         }
 
+        if let Some(underline) = underline {
+            renderer.fill_quad(underline, theme.value_color(style));
+        }
+
         renderer.fill_text(Text {
             content: if text.is_empty() { placeholder } else { &text },
             color: if text.is_empty() {
@@ -1156,6 +1518,10 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    history: History,
+    operated_value: Option<Value>,
+    pending_input: Option<(Value, Cursor)>,
+    preedit: Option<Preedit>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1180,6 +1546,10 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            history: History::default(),
+            operated_value: None,
+            pending_input: None,
+            preedit: None,
         }
     }
 
@@ -1208,6 +1578,7 @@ impl State {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1229,6 +1600,29 @@ impl State {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Applies an undo or redo step to the [`TextInput`] being operated on.
+    ///
+    /// The resulting value is produced as an input message the next time the
+    /// [`TextInput`] is redrawn, which the shells do right after running the
+    /// operation.
+    fn step_history(&mut self, request: history::Request) {
+        let Some(value) = &self.operated_value else {
+            return;
+        };
+
+        let cursor = self
+            .pending_input
+            .as_ref()
+            .map_or(self.cursor, |(_, cursor)| *cursor);
+
+        if let Some((value, cursor)) =
+            self.history.apply(request, value, cursor)
+        {
+            self.operated_value = Some(value.clone());
+            self.pending_input = Some((value, cursor));
+        }
+    }
 }
 
 impl operation::Focusable for State {
@@ -1261,6 +1655,35 @@ impl operation::TextInput for State {
     fn select_all(&mut self) {
         State::select_all(self)
     }
+
+    fn undo(&mut self) {
+        self.step_history(history::Request::Undo);
+    }
+
+    fn redo(&mut self) {
+        self.step_history(history::Request::Redo);
+    }
+}
+
+/// Applies the given history [`Request`] to the [`Value`] of a [`TextInput`],
+/// producing an input message if it changed.
+///
+/// [`Request`]: history::Request
+fn apply_history<Message>(
+    request: history::Request,
+    value: &mut Value,
+    state: &mut State,
+    on_input: &dyn Fn(String) -> Message,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some((new_value, cursor)) =
+        state.history.apply(request, value, state.cursor)
+    {
+        *value = new_value;
+        state.cursor = cursor;
+
+        shell.publish((on_input)(value.to_string()));
+    }
 }
 
 mod platform {
@@ -1309,6 +1732,73 @@ where
     }
 }
 
+/// Computes the [`InputMethod`] requested by a focused [`TextInput`], placing
+/// the candidate window of the input method right below its text cursor.
+///
+/// [`InputMethod`]: keyboard::InputMethod
+fn input_method<Renderer>(
+    renderer: &Renderer,
+    layout: Layout<'_>,
+    state: &State,
+    value: &Value,
+    size: Option<f32>,
+    font: Option<Renderer::Font>,
+) -> keyboard::InputMethod
+where
+    Renderer: text::Renderer,
+{
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let font = font.unwrap_or_else(|| renderer.default_font());
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    let composition = state
+        .preedit
+        .as_ref()
+        .map(|preedit| preedit.compose(value, state.cursor));
+
+    let (value, position) = match &composition {
+        Some(composition) => (&composition.value, composition.start),
+        None => (
+            value,
+            state
+                .cursor
+                .selection(value)
+                .map_or(state.cursor.end(value), |(left, _)| left),
+        ),
+    };
+
+    let (text_value_width, offset) = measure_cursor_and_scroll_offset(
+        renderer,
+        text_bounds,
+        value,
+        size,
+        position,
+        font,
+    );
+
+    let text_width = renderer.measure_width(
+        &value.to_string(),
+        size,
+        font,
+        text::Shaping::Advanced,
+    );
+
+    let x = if text_width > text_bounds.width {
+        text_value_width - offset
+    } else {
+        text_value_width
+    };
+
+    keyboard::InputMethod::Enabled {
+        cursor: Rectangle {
+            x: text_bounds.x + x.max(0.0).min(text_bounds.width),
+            y: text_bounds.y,
+            width: 1.0,
+            height: text_bounds.height,
+        },
+    }
+}
+
 fn measure_cursor_and_scroll_offset<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
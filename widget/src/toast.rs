@@ -0,0 +1,791 @@
+//! Show notifications over some content that go away on their own.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, OperationOutputWrapper};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::style::toast::{Appearance, Severity, StyleSheet};
+
+#[cfg(feature = "a11y")]
+use crate::core::widget::Id;
+#[cfg(feature = "a11y")]
+use std::cell::RefCell;
+
+/// The default time a [`Toast`] is shown before it is closed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The width of the accent stripe along the left side of a [`Toast`].
+const ACCENT_WIDTH: f32 = 4.0;
+
+/// A notification shown by a [`Manager`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Toast {
+    /// The title of the [`Toast`].
+    pub title: String,
+    /// The body of the [`Toast`].
+    pub body: String,
+    /// The [`Severity`] of the [`Toast`].
+    pub severity: Severity,
+}
+
+impl Toast {
+    /// Creates a new [`Toast`] with the given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            severity: Severity::default(),
+        }
+    }
+
+    /// Sets the [`Severity`] of the [`Toast`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+/// A widget that shows a stack of [`Toast`]s over some content.
+///
+/// Every [`Toast`] is closed once its timeout elapses, unless the cursor is
+/// over it. The [`Manager`] produces a message with the index of the
+/// [`Toast`] to close, and the application is expected to remove it.
+///
+/// # Example
+/// ```no_run
+/// # type Manager<'a, Message> =
+/// #     iced_widget::toast::Manager<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Text<'a> = iced_widget::Text<'a, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::toast::{Severity, Toast};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseToast(usize),
+/// }
+///
+/// let toasts = vec![
+///     Toast::new("Saved", "Your changes have been saved."),
+///     Toast::new("Offline", "Reconnecting…").severity(Severity::Warning),
+/// ];
+///
+/// let manager: Manager<'_, Message> =
+///     Manager::new(Text::new("Content"), &toasts, Message::CloseToast);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Manager<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    toasts: &'a [Toast],
+    on_close: Box<dyn Fn(usize) -> Message + 'a>,
+    timeout: Option<Duration>,
+    width: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Manager<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default width of a [`Toast`].
+    pub const DEFAULT_WIDTH: f32 = 300.0;
+
+    /// Creates a new [`Manager`] showing the given [`Toast`]s over some
+    /// content.
+    ///
+    /// It expects a function that produces a message with the index of a
+    /// [`Toast`] to close.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        toasts: &'a [Toast],
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            on_close: Box::new(on_close),
+            timeout: Some(DEFAULT_TIMEOUT),
+            width: Self::DEFAULT_WIDTH,
+            spacing: 10.0,
+            padding: Padding::from([10, 12]),
+            text_size: None,
+            font: None,
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Bottom,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the time a [`Toast`] is shown before it is closed.
+    ///
+    /// If the timeout is `None`, the [`Toast`]s stay until they are closed
+    /// by the user.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Sets the width of the [`Toast`]s.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the spacing between the [`Toast`]s, and between them and the
+    /// edges of the [`Manager`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Toast`]s.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Toast`]s.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Toast`]s.
+    ///
+    /// [`Font`]: crate::core::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`Toast`]s.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Toast`]s.
+    ///
+    /// The [`Toast`]s stack from the chosen edge.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`Manager`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Manager`].
+#[derive(Debug, Default)]
+struct State {
+    /// When each [`Toast`] was shown, or `None` once it has been closed.
+    shown_at: Vec<Option<Instant>>,
+    #[cfg(feature = "a11y")]
+    ids: RefCell<Vec<Id>>,
+    /// The bounds of each [`Toast`] in the last frame, used to describe
+    /// them to assistive technologies.
+    #[cfg(feature = "a11y")]
+    bounds: RefCell<Vec<Rectangle>>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Manager<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            shown_at: vec![Some(Instant::now()); self.toasts.len()],
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Closed toasts are expected to be removed by the application, so
+        // the remaining instants still match their toasts
+        state.shown_at.retain(Option::is_some);
+        state
+            .shown_at
+            .resize(self.toasts.len(), Some(Instant::now()));
+
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        );
+
+        let toasts = (!self.toasts.is_empty()).then(|| {
+            overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    toasts: self.toasts,
+                    state: tree.state.downcast_mut::<State>(),
+                    on_close: &self.on_close,
+                    timeout: self.timeout,
+                    size: layout.bounds().size(),
+                    width: self.width,
+                    spacing: self.spacing,
+                    padding: self.padding,
+                    text_size: self.text_size,
+                    font: self.font,
+                    horizontal_alignment: self.horizontal_alignment,
+                    vertical_alignment: self.vertical_alignment,
+                    style: &self.style,
+                }),
+            )
+        });
+
+        let overlays = content.into_iter().chain(toasts).collect::<Vec<_>>();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Live, NodeBuilder, Rect, Role},
+            A11yTree,
+        };
+
+        let content = self.content.as_widget().a11y_nodes(
+            layout,
+            &tree.children[0],
+            cursor,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = state.bounds.borrow();
+        let mut ids = state.ids.borrow_mut();
+
+        while ids.len() < self.toasts.len() {
+            ids.push(Id::unique());
+        }
+
+        let toasts = self.toasts.iter().zip(bounds.iter()).zip(ids.iter()).map(
+            |((toast, bounds), id)| {
+                let mut node = NodeBuilder::new(Role::Alert);
+                node.set_name(toast.title.clone());
+                node.set_description(toast.body.clone());
+                node.set_live(match toast.severity {
+                    Severity::Info | Severity::Success => Live::Polite,
+                    Severity::Warning | Severity::Error => Live::Assertive,
+                });
+                node.set_bounds(Rect::new(
+                    bounds.x as f64,
+                    bounds.y as f64,
+                    (bounds.x + bounds.width) as f64,
+                    (bounds.y + bounds.height) as f64,
+                ));
+
+                A11yTree::leaf(node, id.clone())
+            },
+        );
+
+        A11yTree::join(std::iter::once(content).chain(toasts))
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    toasts: &'a [Toast],
+    state: &'b mut State,
+    on_close: &'b dyn Fn(usize) -> Message,
+    timeout: Option<Duration>,
+    size: Size,
+    width: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Lays out a single [`Toast`] with the given width.
+    ///
+    /// The children of the resulting [`layout::Node`] are the title, the
+    /// close button and the body of the [`Toast`].
+    fn layout_toast(
+        &self,
+        renderer: &Renderer,
+        toast: &Toast,
+        width: f32,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let gap = text_size / 2.0;
+
+        let measure = |content: &str, width: f32| {
+            if content.is_empty() {
+                return 0.0;
+            }
+
+            let (_, height) = renderer.measure(
+                content,
+                text_size,
+                LineHeight::default(),
+                font,
+                Size::new(width, f32::INFINITY),
+                Shaping::Advanced,
+            );
+
+            height
+        };
+
+        let content_width = (width - self.padding.horizontal()).max(0.0);
+        let title_width = (content_width - text_size - gap).max(0.0);
+
+        let header_height = measure(&toast.title, title_width).max(text_size);
+        let body_height = measure(&toast.body, content_width);
+
+        let title =
+            layout::Node::new(Size::new(title_width, header_height)).translate(
+                crate::core::Vector::new(self.padding.left, self.padding.top),
+            );
+
+        let close = layout::Node::new(Size::new(text_size, text_size))
+            .translate(crate::core::Vector::new(
+                width - self.padding.right - text_size,
+                self.padding.top + (header_height - text_size) / 2.0,
+            ));
+
+        let body_y = self.padding.top
+            + header_height
+            + if body_height > 0.0 { gap } else { 0.0 };
+
+        let body = layout::Node::new(Size::new(content_width, body_height))
+            .translate(crate::core::Vector::new(self.padding.left, body_y));
+
+        layout::Node::with_children(
+            Size::new(width, body_y + body_height + self.padding.bottom),
+            vec![title, close, body],
+        )
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let width = self.width.min(self.size.width - 2.0 * self.spacing);
+
+        let mut toasts: Vec<_> = self
+            .toasts
+            .iter()
+            .map(|toast| self.layout_toast(renderer, toast, width))
+            .collect();
+
+        let height =
+            toasts.iter().map(|toast| toast.size().height).sum::<f32>()
+                + self.spacing * toasts.len().saturating_sub(1) as f32;
+
+        let x = match self.horizontal_alignment {
+            alignment::Horizontal::Left => self.spacing,
+            alignment::Horizontal::Center => (self.size.width - width) / 2.0,
+            alignment::Horizontal::Right => {
+                self.size.width - self.spacing - width
+            }
+        };
+
+        let mut y = match self.vertical_alignment {
+            alignment::Vertical::Top => self.spacing,
+            alignment::Vertical::Center => (self.size.height - height) / 2.0,
+            alignment::Vertical::Bottom => {
+                self.size.height - self.spacing - height
+            }
+        };
+
+        for toast in &mut toasts {
+            toast.move_to(Point::new(x, y));
+
+            y += toast.size().height + self.spacing;
+        }
+
+        #[cfg(feature = "a11y")]
+        {
+            *self.state.bounds.borrow_mut() = toasts
+                .iter()
+                .map(|toast| {
+                    toast.bounds()
+                        + crate::core::Vector::new(position.x, position.y)
+                })
+                .collect();
+        }
+
+        let mut node = layout::Node::with_children(self.size, toasts);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<_> = layout.children().collect();
+
+        match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let Some(timeout) = self.timeout else {
+                    return event::Status::Ignored;
+                };
+
+                // Toasts are closed from the last one, so the indices of
+                // the others stay valid
+                for (index, (shown_at, layout)) in self
+                    .state
+                    .shown_at
+                    .iter_mut()
+                    .zip(layouts)
+                    .enumerate()
+                    .rev()
+                {
+                    let Some(instant) = shown_at else {
+                        continue;
+                    };
+
+                    // Hovering a toast keeps it open
+                    if cursor.is_over(layout.bounds()) {
+                        *instant = now;
+                    }
+
+                    let deadline = *instant + timeout;
+
+                    if now >= deadline {
+                        *shown_at = None;
+
+                        shell.publish((self.on_close)(index));
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            deadline,
+                        ));
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((index, layout)) = layouts
+                    .into_iter()
+                    .enumerate()
+                    .find(|(_, layout)| cursor.is_over(layout.bounds()))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let close = layout.children().nth(1).unwrap();
+
+                if cursor.is_over(close.bounds()) {
+                    if let Some(shown_at) = self.state.shown_at.get_mut(index) {
+                        if shown_at.take().is_some() {
+                            shell.publish((self.on_close)(index));
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            let bounds = layout.bounds();
+
+            let appearance = if cursor.is_over(bounds) {
+                theme.hovered(self.style, toast.severity)
+            } else {
+                theme.active(self.style, toast.severity)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            let [top_left, _, _, bottom_left] =
+                <[f32; 4]>::from(appearance.border_radius);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: ACCENT_WIDTH,
+                        ..bounds
+                    },
+                    border_radius: [top_left, 0.0, 0.0, bottom_left].into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.accent_color,
+            );
+
+            let mut children = layout.children();
+            let title = children.next().unwrap().bounds();
+            let close = children.next().unwrap().bounds();
+            let body = children.next().unwrap().bounds();
+
+            for (content, bounds) in
+                [(&toast.title, title), (&toast.body, body)]
+            {
+                renderer.fill_text(text::Text {
+                    content,
+                    bounds,
+                    size: text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: Shaping::Advanced,
+                });
+            }
+
+            renderer.fill_text(text::Text {
+                content: "×",
+                bounds: Rectangle {
+                    x: close.center_x(),
+                    y: close.center_y(),
+                    ..close
+                },
+                size: text_size,
+                line_height: LineHeight::default(),
+                color: appearance.close_color,
+                font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+            });
+        }
+    }
+
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_close = layout.children().any(|toast| {
+            cursor.is_over(toast.children().nth(1).unwrap().bounds())
+        });
+
+        if is_over_close {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|layout| layout.bounds().contains(cursor_position))
+    }
+}
+
+impl<'a, Message, Renderer> From<Manager<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(manager: Manager<'a, Message, Renderer>) -> Self {
+        Element::new(manager)
+    }
+}
@@ -2,13 +2,16 @@
 use std::borrow::Cow;
 
 use crate::core::alignment;
+use crate::core::animation::{Animation, Curve, Interpolate};
 use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
+use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Id;
-use crate::core::widget::Tree;
+use crate::core::window;
 use crate::core::{
     id, Alignment, Clipboard, Element, Event, Layout, Length, Pixels, Point,
     Rectangle, Shell, Widget,
@@ -58,6 +61,7 @@ where
     text_shaping: text::Shaping,
     spacing: f32,
     font: Option<Renderer::Font>,
+    transition: Option<Curve>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -107,6 +111,7 @@ where
             text_shaping: text::Shaping::Advanced,
             spacing: 0.0,
             font: None,
+            transition: None,
             style: Default::default(),
         }
     }
@@ -164,6 +169,14 @@ where
         self
     }
 
+    /// Sets the [`Curve`] used to slide the [`Toggler`] between its states.
+    ///
+    /// By default, the [`Toggler`] changes its state instantly.
+    pub fn transition(mut self, curve: impl Into<Curve>) -> Self {
+        self.transition = Some(curve.into());
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     pub fn style(
         mut self,
@@ -215,6 +228,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + crate::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -255,7 +276,7 @@ where
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -264,6 +285,18 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                if let Some(curve) = self.transition {
+                    tree.state.downcast_mut::<State>().animate(
+                        curve,
+                        self.is_toggled,
+                        now,
+                        shell,
+                    );
+                }
+
+                event::Status::Ignored
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = cursor.is_over(layout.bounds());
 
@@ -296,7 +329,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -336,10 +369,23 @@ where
 
         let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_toggled)
-        } else {
-            theme.active(&self.style, self.is_toggled)
+        let appearance = |is_toggled| {
+            if is_mouse_over {
+                theme.hovered(&self.style, is_toggled)
+            } else {
+                theme.active(&self.style, is_toggled)
+            }
+        };
+
+        let progress = self
+            .transition
+            .and(tree.state.downcast_ref::<State>().progress());
+
+        let style = match progress {
+            Some(progress) => {
+                appearance(false).interpolate(&appearance(true), progress)
+            }
+            None => appearance(self.is_toggled),
         };
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
@@ -364,13 +410,13 @@ where
             style.background,
         );
 
+        let toggler_foreground_offset = (2.0 * space).interpolate(
+            &(bounds.width - 2.0 * space - (bounds.height - (4.0 * space))),
+            progress.unwrap_or(if self.is_toggled { 1.0 } else { 0.0 }),
+        );
+
         let toggler_foreground_bounds = Rectangle {
-            x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+            x: bounds.x + toggler_foreground_offset,
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -492,6 +538,41 @@ where
     }
 }
 
+/// The local state of a [`Toggler`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    transition: Option<(Animation<f32>, Instant)>,
+}
+
+impl State {
+    /// Moves the knob of the [`Toggler`] towards its current state.
+    fn animate<Message>(
+        &mut self,
+        curve: Curve,
+        is_toggled: bool,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let target = if is_toggled { 1.0 } else { 0.0 };
+
+        let (progress, last_frame) = self
+            .transition
+            .get_or_insert_with(|| (Animation::new(target).curve(curve), now));
+
+        progress.go(target, now);
+        progress.request_redraw(now, shell);
+
+        *last_frame = now;
+    }
+
+    /// Returns how far the knob of the [`Toggler`] is from its untoggled
+    /// position at the last frame, if it has been animated.
+    fn progress(&self) -> Option<f32> {
+        self.transition
+            .map(|(progress, last_frame)| progress.value(last_frame))
+    }
+}
+
 impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
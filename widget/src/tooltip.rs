@@ -7,7 +7,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size,
     Vector, Widget,
@@ -23,8 +23,11 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: container::StyleSheet + crate::text::StyleSheet,
 {
+    id: Id,
     content: Element<'a, Message, Renderer>,
     tooltip: Text<'a, Renderer>,
+    #[cfg(feature = "a11y")]
+    text: Cow<'a, str>,
     position: Position,
     gap: f32,
     padding: f32,
@@ -48,8 +51,13 @@ where
         tooltip: impl Into<Cow<'a, str>>,
         position: Position,
     ) -> Self {
+        let tooltip = tooltip.into();
+
         Tooltip {
+            id: Id::unique(),
             content: content.into(),
+            #[cfg(feature = "a11y")]
+            text: tooltip.clone(),
             tooltip: Text::new(tooltip),
             position,
             gap: 0.0,
@@ -233,6 +241,61 @@ where
             renderer,
         )
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, Rect, Role},
+            A11yId, A11yTree,
+        };
+
+        let mut content = self.content.as_widget().a11y_nodes(
+            layout,
+            &state.children[0],
+            cursor_position,
+        );
+
+        // The tooltip describes every root node of its content
+        for node in content.root_mut() {
+            let node = node.node_mut();
+
+            node.set_tooltip(self.text.clone());
+            node.push_described_by(A11yId::from(self.id.clone()).into());
+        }
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::Tooltip);
+        node.set_bounds(bounds);
+        node.set_name(self.text.clone());
+
+        A11yTree::join(
+            [content, A11yTree::leaf(node, self.id.clone())].into_iter(),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
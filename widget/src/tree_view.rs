@@ -0,0 +1,1004 @@
+//! Display hierarchical data with nodes that can be expanded and collapsed.
+//!
+//! Like a [`Table`], a [`TreeView`] does not own its data. Expanding,
+//! collapsing and selecting a node produce messages, and it is up to your
+//! application to update its state accordingly. This also allows the
+//! children of a node to be loaded lazily, the first time it is expanded.
+//!
+//! [`Table`]: crate::Table
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, OperationOutputWrapper};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+#[cfg(feature = "a11y")]
+use std::cell::RefCell;
+
+pub use crate::style::tree_view::{Appearance, StyleSheet};
+
+/// A widget that displays a hierarchy of nodes, which can be expanded and
+/// collapsed.
+///
+/// # Example
+/// ```no_run
+/// # type Renderer = iced_widget::renderer::Renderer<iced_widget::style::Theme>;
+/// # type TreeView<'a, Message> = iced_widget::TreeView<'a, usize, Message, Renderer>;
+/// # type Node<'a, Message> = iced_widget::tree_view::Node<'a, usize, Message, Renderer>;
+/// # type Text<'a> = iced_widget::Text<'a, Renderer>;
+/// struct Entry {
+///     name: String,
+///     children: Vec<usize>,
+///     is_expanded: bool,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle(usize, bool),
+///     Select(usize),
+/// }
+///
+/// fn node<'a>(entries: &'a [Entry], index: usize) -> Node<'a, Message> {
+///     let entry = &entries[index];
+///
+///     Node::new(index, Text::new(&entry.name))
+///         .expanded(entry.is_expanded)
+///         .children(entry.children.iter().map(|&child| node(entries, child)))
+/// }
+///
+/// fn view(entries: &[Entry], selected: Option<usize>) -> TreeView<'_, Message> {
+///     TreeView::new([node(entries, 0)])
+///         .selected(selected)
+///         .on_toggle(Message::Toggle)
+///         .on_select(Message::Select)
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Key, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    rows: Vec<Row<Key>>,
+    labels: Vec<Element<'a, Message, Renderer>>,
+    selected: Option<Key>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Key, Message, Renderer = crate::Renderer> {
+    key: Key,
+    label: Element<'a, Message, Renderer>,
+    children: Vec<Node<'a, Key, Message, Renderer>>,
+    is_expanded: bool,
+    is_expandable: bool,
+}
+
+impl<'a, Key, Message, Renderer> Node<'a, Key, Message, Renderer> {
+    /// Creates a new [`Node`] with the given key and label.
+    ///
+    /// The key identifies the [`Node`] in the messages of the [`TreeView`].
+    pub fn new(
+        key: Key,
+        label: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            children: Vec::new(),
+            is_expanded: false,
+            is_expandable: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Node<'a, Key, Message, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds the given children to the [`Node`].
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = Node<'a, Key, Message, Renderer>>,
+    ) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded, showing its children.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded even if it has no children.
+    ///
+    /// This can be used to load the children of a [`Node`] lazily, once the
+    /// [`TreeView`] asks for it to be expanded.
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+}
+
+/// A visible node of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+struct Row<Key> {
+    key: Key,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+impl<'a, Key, Message, Renderer> TreeView<'a, Key, Message, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(
+        roots: impl IntoIterator<Item = Node<'a, Key, Message, Renderer>>,
+    ) -> Self {
+        fn flatten<'a, Key, Message, Renderer>(
+            node: Node<'a, Key, Message, Renderer>,
+            depth: usize,
+            parent: Option<usize>,
+            rows: &mut Vec<Row<Key>>,
+            labels: &mut Vec<Element<'a, Message, Renderer>>,
+        ) {
+            let index = rows.len();
+            let is_expandable = node.is_expandable || !node.children.is_empty();
+            let is_expanded = is_expandable && node.is_expanded;
+
+            rows.push(Row {
+                key: node.key,
+                depth,
+                parent,
+                is_expandable,
+                is_expanded,
+            });
+            labels.push(node.label);
+
+            if is_expanded {
+                for child in node.children {
+                    flatten(child, depth + 1, Some(index), rows, labels);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut labels = Vec::new();
+
+        for root in roots {
+            flatten(root, 0, None, &mut rows, &mut labels);
+        }
+
+        Self {
+            id: Id::unique(),
+            rows,
+            labels,
+            selected: None,
+            width: Length::Fill,
+            indent: 20.0,
+            padding: Padding::from([2, 5]),
+            on_toggle: None,
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the key of the selected node of the [`TreeView`].
+    pub fn selected(mut self, key: Option<Key>) -> Self {
+        self.selected = key;
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    ///
+    /// The disclosure arrows of the nodes use the same amount of space.
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the [`Padding`] of the label of every node.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a node is expanded or
+    /// collapsed.
+    ///
+    /// The message receives the key of the node and whether it should be
+    /// expanded.
+    pub fn on_toggle(mut self, f: impl Fn(Key, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a node is selected.
+    pub fn on_select(mut self, f: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn selected_row(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.rows.iter().position(|row| row.key == *selected)
+    }
+
+    fn arrow_bounds(&self, row: &Row<Key>, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.indent * row.depth as f32,
+            width: self.indent,
+            ..bounds
+        }
+    }
+
+    fn apply(&self, action: Action, shell: &mut Shell<'_, Message>) {
+        match action {
+            Action::Select(index) => {
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(self.rows[index].key.clone()));
+                }
+            }
+            Action::Toggle(index, is_expanded) => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(
+                        self.rows[index].key.clone(),
+                        is_expanded,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, Key, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Key, Message, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.labels.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.labels);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+
+        let labels: Vec<_> = self
+            .rows
+            .iter()
+            .zip(&self.labels)
+            .map(|(row, label)| {
+                let offset = self.indent * (row.depth + 1) as f32;
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (max_width - offset - self.padding.horizontal())
+                            .max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+
+                label.as_widget().layout(renderer, &limits)
+            })
+            .collect();
+
+        let content_width = self
+            .rows
+            .iter()
+            .zip(&labels)
+            .map(|(row, label)| {
+                self.indent * (row.depth + 1) as f32
+                    + label.size().width
+                    + self.padding.horizontal()
+            })
+            .fold(0.0, f32::max);
+
+        let min_height = renderer.default_size();
+        let mut rows = Vec::with_capacity(labels.len());
+        let mut y = 0.0;
+
+        for (row, mut label) in self.rows.iter().zip(labels) {
+            let height = label.size().height.max(min_height);
+
+            label.move_to(Point::new(
+                self.indent * (row.depth + 1) as f32 + self.padding.left,
+                self.padding.top + (height - label.size().height) / 2.0,
+            ));
+
+            rows.push((label, y, height + self.padding.vertical()));
+
+            y += height + self.padding.vertical();
+        }
+
+        let size = limits.resolve(Size::new(content_width, y));
+
+        layout::Node::with_children(
+            size,
+            rows.into_iter()
+                .map(|(label, y, height)| {
+                    let mut node = layout::Node::with_children(
+                        Size::new(size.width, height),
+                        vec![label],
+                    );
+
+                    node.move_to(Point::new(0.0, y));
+                    node
+                })
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, Some(&self.id));
+
+        operation.container(Some(&self.id), &mut |operation| {
+            self.labels
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((label, state), row)| {
+                    label.as_widget().operate(
+                        state,
+                        row.children().next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest { action, .. },
+        ) = &event
+        {
+            use iced_accessibility::accesskit::Action as A11yAction;
+
+            let state = tree.state.downcast_mut::<State>();
+
+            if *event_id == self.id {
+                match action {
+                    A11yAction::Focus => state.is_focused = true,
+                    A11yAction::Blur => state.is_focused = false,
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+
+            let Some(index) = state
+                .item_ids
+                .borrow()
+                .iter()
+                .take(self.rows.len())
+                .position(|id| id == event_id)
+            else {
+                return event::Status::Ignored;
+            };
+
+            let row = &self.rows[index];
+
+            let action = match action {
+                A11yAction::Expand if row.is_expandable => {
+                    Action::Toggle(index, true)
+                }
+                A11yAction::Collapse if row.is_expandable => {
+                    Action::Toggle(index, false)
+                }
+                A11yAction::Default | A11yAction::Focus => {
+                    Action::Select(index)
+                }
+                _ => return event::Status::Ignored,
+            };
+
+            self.apply(action, shell);
+
+            return event::Status::Captured;
+        }
+
+        let status = self
+            .labels
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((label, state), row)| {
+                label.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    row.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    state.is_focused = false;
+
+                    return status;
+                };
+
+                state.is_focused = true;
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                let Some((index, bounds)) = layout
+                    .children()
+                    .map(|row| row.bounds())
+                    .enumerate()
+                    .find(|(_, bounds)| bounds.contains(position))
+                else {
+                    return event::Status::Captured;
+                };
+
+                let row = &self.rows[index];
+
+                let action = if row.is_expandable
+                    && self.arrow_bounds(row, bounds).contains(position)
+                {
+                    Action::Toggle(index, !row.is_expanded)
+                } else {
+                    Action::Select(index)
+                };
+
+                self.apply(action, shell);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused && status == event::Status::Ignored => {
+                match navigate(&self.rows, self.selected_row(), key_code) {
+                    Some(action) => {
+                        self.apply(action, shell);
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .labels
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((label, state), row)| {
+                label.as_widget().mouse_interaction(
+                    state,
+                    row.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        let is_over_arrow =
+            self.rows
+                .iter()
+                .zip(layout.children())
+                .any(|(row, layout)| {
+                    row.is_expandable
+                        && cursor
+                            .is_over(self.arrow_bounds(row, layout.bounds()))
+                });
+
+        if interaction == mouse::Interaction::default() && is_over_arrow {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let selected = self.selected_row();
+
+        let appearance = if state.is_focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        for (index, ((row, (label, state)), layout)) in self
+            .rows
+            .iter()
+            .zip(self.labels.iter().zip(&tree.children))
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = selected == Some(index);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if cursor.is_over(bounds) {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + self.indent * row.depth as f32,
+                            width: bounds.width
+                                - self.indent * row.depth as f32,
+                            ..bounds
+                        },
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            if let Some(guide_color) = appearance.guide_color {
+                for level in 0..row.depth {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x
+                                    + self.indent * (level as f32 + 0.5)
+                                    - appearance.guide_width / 2.0,
+                                y: bounds.y,
+                                width: appearance.guide_width,
+                                height: bounds.height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        guide_color,
+                    );
+                }
+            }
+
+            let text_color = if is_selected {
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            if row.is_expandable {
+                let arrow = self.arrow_bounds(row, bounds);
+
+                renderer.fill_text(text::Text {
+                    content: if row.is_expanded { "▼" } else { "▶" },
+                    bounds: Rectangle {
+                        x: arrow.center_x(),
+                        y: arrow.center_y(),
+                        ..arrow
+                    },
+                    size: renderer.default_size() * 0.6,
+                    line_height: LineHeight::default(),
+                    color: if is_selected {
+                        text_color
+                    } else {
+                        appearance.arrow_color
+                    },
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: Shaping::Advanced,
+                });
+            }
+
+            label.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .labels
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((label, state), row)| {
+                label.as_widget_mut().overlay(
+                    state,
+                    row.children().next().unwrap(),
+                    renderer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action as A11yAction, NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let selected = self.selected_row();
+
+        let mut item_ids = state.item_ids.borrow_mut();
+
+        while item_ids.len() < self.rows.len() {
+            item_ids.push(Id::unique());
+        }
+
+        let items = self
+            .rows
+            .iter()
+            .zip(self.labels.iter().zip(&tree.children))
+            .zip(layout.children())
+            .zip(item_ids.iter())
+            .enumerate()
+            .map(|(index, (((row, (label, state)), layout), id))| {
+                let bounds = layout.bounds();
+
+                let mut node = NodeBuilder::new(Role::TreeItem);
+                node.set_bounds(rect(bounds));
+                node.set_hierarchical_level(row.depth + 1);
+                node.set_selected(selected == Some(index));
+                node.add_action(A11yAction::Default);
+
+                if row.is_expandable {
+                    node.set_expanded(row.is_expanded);
+                    node.add_action(if row.is_expanded {
+                        A11yAction::Collapse
+                    } else {
+                        A11yAction::Expand
+                    });
+                }
+
+                if cursor.is_over(bounds) {
+                    node.set_hovered();
+                }
+
+                A11yTree::node_with_child_tree(
+                    A11yNode::new(node, id.clone()),
+                    label.as_widget().a11y_nodes(
+                        layout.children().next().unwrap(),
+                        state,
+                        cursor,
+                    ),
+                )
+            });
+
+        let mut node = NodeBuilder::new(Role::Tree);
+        node.set_bounds(rect(layout.bounds()));
+        node.add_action(A11yAction::Focus);
+        node.add_action(A11yAction::Blur);
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::join(items),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+impl<'a, Key, Message, Renderer> From<TreeView<'a, Key, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Key: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+    #[cfg(feature = "a11y")]
+    item_ids: RefCell<Vec<Id>>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// An interaction with a node of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Select(usize),
+    Toggle(usize, bool),
+}
+
+/// Returns the [`Action`] that a key press performs, given the visible rows
+/// and the selected one.
+fn navigate<Key>(
+    rows: &[Row<Key>],
+    selected: Option<usize>,
+    key_code: keyboard::KeyCode,
+) -> Option<Action> {
+    use keyboard::KeyCode;
+
+    let last = rows.len().checked_sub(1)?;
+
+    let Some(index) = selected else {
+        return match key_code {
+            KeyCode::Down | KeyCode::Home => Some(Action::Select(0)),
+            KeyCode::Up | KeyCode::End => Some(Action::Select(last)),
+            _ => None,
+        };
+    };
+
+    let row = &rows[index];
+
+    match key_code {
+        KeyCode::Up => Some(Action::Select(index.saturating_sub(1))),
+        KeyCode::Down => Some(Action::Select((index + 1).min(last))),
+        KeyCode::Home => Some(Action::Select(0)),
+        KeyCode::End => Some(Action::Select(last)),
+        KeyCode::Right if row.is_expandable && !row.is_expanded => {
+            Some(Action::Toggle(index, true))
+        }
+        KeyCode::Right => rows
+            .get(index + 1)
+            .filter(|next| next.parent == Some(index))
+            .map(|_| Action::Select(index + 1)),
+        KeyCode::Left if row.is_expanded => Some(Action::Toggle(index, false)),
+        KeyCode::Left => row.parent.map(Action::Select),
+        KeyCode::Space | KeyCode::Enter | KeyCode::NumpadEnter
+            if row.is_expandable =>
+        {
+            Some(Action::Toggle(index, !row.is_expanded))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyboard::KeyCode;
+
+    fn rows() -> Vec<Row<&'static str>> {
+        let row = |key, depth, parent, is_expandable, is_expanded| Row {
+            key,
+            depth,
+            parent,
+            is_expandable,
+            is_expanded,
+        };
+
+        vec![
+            row("src", 0, None, true, true),
+            row("main.rs", 1, Some(0), false, false),
+            row("widget", 1, Some(0), true, false),
+            row("Cargo.toml", 0, None, false, false),
+        ]
+    }
+
+    #[test]
+    fn navigate_up_and_down() {
+        let rows = rows();
+
+        assert_eq!(
+            navigate(&rows, None, KeyCode::Down),
+            Some(Action::Select(0))
+        );
+        assert_eq!(
+            navigate(&rows, Some(1), KeyCode::Down),
+            Some(Action::Select(2))
+        );
+        assert_eq!(
+            navigate(&rows, Some(3), KeyCode::Down),
+            Some(Action::Select(3))
+        );
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Up),
+            Some(Action::Select(0))
+        );
+    }
+
+    #[test]
+    fn right_expands_or_moves_to_first_child() {
+        let rows = rows();
+
+        assert_eq!(
+            navigate(&rows, Some(2), KeyCode::Right),
+            Some(Action::Toggle(2, true))
+        );
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Right),
+            Some(Action::Select(1))
+        );
+        assert_eq!(navigate(&rows, Some(3), KeyCode::Right), None);
+    }
+
+    #[test]
+    fn left_collapses_or_moves_to_parent() {
+        let rows = rows();
+
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Left),
+            Some(Action::Toggle(0, false))
+        );
+        assert_eq!(
+            navigate(&rows, Some(2), KeyCode::Left),
+            Some(Action::Select(0))
+        );
+        assert_eq!(navigate(&rows, Some(3), KeyCode::Left), None);
+    }
+}
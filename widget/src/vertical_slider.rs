@@ -1,6 +1,8 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`VerticalSlider`] has some local [`State`].
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
 use std::ops::RangeInclusive;
 
 pub use crate::style::slider::{Appearance, Handle, HandleShape, StyleSheet};
@@ -12,6 +14,7 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Clipboard, Color, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
@@ -45,6 +48,13 @@ where
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     range: RangeInclusive<T>,
     step: T,
     value: T,
@@ -90,6 +100,13 @@ where
         };
 
         VerticalSlider {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             value,
             range,
             step: T::from(1),
@@ -138,6 +155,41 @@ where
         self.step = step;
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`VerticalSlider`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`VerticalSlider`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`VerticalSlider`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`VerticalSlider`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -185,6 +237,37 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        #[cfg(feature = "a11y")]
+        if let Event::A11y(
+            event_id,
+            iced_accessibility::accesskit::ActionRequest {
+                action, data, ..
+            },
+        ) = &event
+        {
+            if *event_id != self.id {
+                return event::Status::Ignored;
+            }
+
+            let new_value = a11y_value(
+                *action,
+                data.as_ref(),
+                self.value,
+                &self.range,
+                self.step,
+            );
+
+            if let Some(new_value) = new_value {
+                if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
+                    shell.publish((self.on_change)(new_value));
+
+                    self.value = new_value;
+                }
+            }
+
+            return event::Status::Captured;
+        }
+
         update(
             event,
             layout,
@@ -231,6 +314,80 @@ where
     ) -> mouse::Interaction {
         mouse_interaction(layout, cursor, tree.state.downcast_ref::<State>())
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor_position: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, Live, NodeBuilder, NodeId, Orientation, Rect, Role,
+            },
+            A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let is_hovered = cursor_position.is_over(bounds);
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+        let bounds = Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        );
+        let mut node = NodeBuilder::new(Role::Slider);
+        node.set_bounds(bounds);
+        node.set_orientation(Orientation::Vertical);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::SetValue);
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if is_hovered {
+            node.set_hovered();
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        node.set_min_numeric_value((*self.range.start()).into());
+        node.set_max_numeric_value((*self.range.end()).into());
+        node.set_numeric_value(self.value.into());
+        node.set_numeric_value_step(self.step.into());
+        node.set_live(Live::Polite);
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, T, Message, Renderer> From<VerticalSlider<'a, T, Message, Renderer>>
@@ -338,6 +495,37 @@ where
     event::Status::Ignored
 }
 
+/// Computes the new value of a [`VerticalSlider`] after an accessibility
+/// action request, if the action changes it.
+#[cfg(feature = "a11y")]
+fn a11y_value<T>(
+    action: iced_accessibility::accesskit::Action,
+    data: Option<&iced_accessibility::accesskit::ActionData>,
+    value: T,
+    range: &RangeInclusive<T>,
+    step: T,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    use iced_accessibility::accesskit::{Action, ActionData};
+
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+    let step = step.into();
+
+    let new_value = match (action, data) {
+        (Action::Increment, _) => value.into() + step,
+        (Action::Decrement, _) => value.into() - step,
+        (Action::SetValue, Some(ActionData::NumericValue(new_value))) => {
+            ((new_value - start) / step).round() * step + start
+        }
+        _ => return None,
+    };
+
+    T::from_f64(new_value.clamp(start, end))
+}
+
 /// Draws a [`VerticalSlider`].
 pub fn draw<T, R>(
     renderer: &mut R,
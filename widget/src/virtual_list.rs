@@ -0,0 +1,946 @@
+//! Display a very large list of rows, building only the visible ones.
+//!
+//! A [`VirtualList`] behaves like a [`Column`] inside a [`Scrollable`], but
+//! it only creates, lays out and draws the rows that intersect its viewport.
+//! This keeps lists of hundreds of thousands of rows responsive.
+//!
+//! [`Column`]: crate::Column
+//! [`Scrollable`]: crate::Scrollable
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation, OperationOutputWrapper};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::scrollable::{self, Properties, StyleSheet, Viewport};
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// The maximum amount of rows outside of the viewport of a [`VirtualList`]
+/// whose widget state is kept.
+const CACHED_ROWS: usize = 256;
+
+/// A scrollable list that only builds the rows inside of its viewport.
+///
+/// The [`VirtualList`] can be scrolled programmatically with
+/// [`scrollable::scroll_to`] and [`scrollable::snap_to`] using its [`Id`].
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Id,
+    count: usize,
+    row_height: RowHeight,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    width: Length,
+    height: Length,
+    vertical: Properties,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    heights: Rc<RefCell<Heights>>,
+    rows: RefCell<Vec<Row<'a, Message, Renderer>>>,
+}
+
+/// The height policy of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row has the same height.
+    Fixed(f32),
+
+    /// Every row is measured once it becomes visible, and its height is
+    /// cached.
+    ///
+    /// The given height is used as an estimate for the rows that have not
+    /// been measured yet.
+    Measured(f32),
+}
+
+impl From<f32> for RowHeight {
+    fn from(height: f32) -> Self {
+        RowHeight::Fixed(height)
+    }
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VirtualList`] with the given amount of rows, the
+    /// [`RowHeight`] policy and a closure that produces the row at an index.
+    pub fn new(
+        count: usize,
+        row_height: impl Into<RowHeight>,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        VirtualList {
+            id: Id::unique(),
+            count,
+            row_height: row_height.into(),
+            view: Box::new(view),
+            key: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            vertical: Properties::default(),
+            on_scroll: None,
+            style: Default::default(),
+            heights: Rc::new(RefCell::new(Heights::default())),
+            rows: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`VirtualList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the closure that produces the key of the row at an index.
+    ///
+    /// The widget state of a row follows its key, instead of its index. This
+    /// keeps the state of the rows in place when the underlying data changes.
+    ///
+    /// By default, the index of a row is its key.
+    pub fn key(mut self, key: impl Fn(usize) -> u64 + 'a) -> Self {
+        self.key = Some(Box::new(key));
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Configures the vertical scrollbar of the [`VirtualList`].
+    pub fn vertical_scroll(mut self, properties: Properties) -> Self {
+        self.vertical = properties;
+        self
+    }
+
+    /// Sets a function to call when the [`VirtualList`] is scrolled.
+    ///
+    /// The function takes the [`Viewport`] of the [`VirtualList`].
+    pub fn on_scroll(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn key_of(&self, index: usize) -> u64 {
+        self.key.as_ref().map_or(index as u64, |key| key(index))
+    }
+
+    /// Returns the cached height of the row at the given index, if it has
+    /// been measured.
+    fn height_of(&self, heights: &Heights, index: usize) -> Option<f32> {
+        heights.rows.get(&self.key_of(index)).copied()
+    }
+
+    fn row_limits(&self, width: f32) -> layout::Limits {
+        let height = match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(_) => f32::INFINITY,
+        };
+
+        layout::Limits::new(Size::ZERO, Size::new(width, height))
+    }
+
+    /// Measures the rows that are in the last known viewport of the
+    /// [`VirtualList`] and have not been measured yet.
+    ///
+    /// The viewport is assumed to keep its offset, while its height is the
+    /// given one, if finite.
+    fn measure_visible(
+        &self,
+        heights: &mut Heights,
+        renderer: &Renderer,
+        width: f32,
+        viewport_height: f32,
+    ) {
+        let (offset, last_height) = heights.viewport;
+        let bottom = offset
+            + if viewport_height.is_finite() {
+                viewport_height
+            } else {
+                last_height
+            };
+
+        let (mut index, mut y) =
+            first_visible(self.row_height, self.count, offset, |index| {
+                self.height_of(heights, index)
+            });
+
+        while index < self.count && y < bottom {
+            let key = self.key_of(index);
+
+            y += match heights.rows.get(&key) {
+                Some(height) => *height,
+                None => {
+                    let mut element = (self.view)(index);
+                    let mut tree = Tree::new(&element);
+                    tree.diff(&mut element);
+
+                    let height = element
+                        .as_widget()
+                        .layout(renderer, &self.row_limits(width))
+                        .size()
+                        .height;
+
+                    let _ = heights.rows.insert(key, height);
+
+                    height
+                }
+            };
+
+            index += 1;
+        }
+    }
+
+    /// Builds the rows that intersect the viewport of the [`VirtualList`],
+    /// reusing the rows that are already built.
+    ///
+    /// The [`State`] is marked as outdated if the height of any measured row
+    /// has changed.
+    fn resolve(&self, state: &State, renderer: &Renderer, layout: Layout<'_>) {
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = state.scrollable.offset(bounds, content_bounds);
+        let bottom = offset.y + bounds.height;
+
+        let mut heights = self.heights.borrow_mut();
+        let mut trees = state.trees.borrow_mut();
+        let mut rows = self.rows.borrow_mut();
+
+        heights.fit(content_bounds.width);
+        heights.viewport = (offset.y, bounds.height);
+
+        let mut old_rows = std::mem::take(&mut *rows);
+
+        trees.tick += 1;
+
+        let (mut index, mut y) =
+            first_visible(self.row_height, self.count, offset.y, |index| {
+                self.height_of(&heights, index)
+            });
+
+        while index < self.count && y < bottom {
+            let mut row =
+                match old_rows.iter().position(|row| row.index == index) {
+                    Some(position) => {
+                        let row = old_rows.swap_remove(position);
+                        trees.touch(row.key);
+
+                        row
+                    }
+                    None => {
+                        let key = self.key_of(index);
+                        let mut element = (self.view)(index);
+
+                        trees.diff(key, &mut element);
+
+                        let node = element.as_widget().layout(
+                            renderer,
+                            &self.row_limits(content_bounds.width),
+                        );
+
+                        Row {
+                            index,
+                            key,
+                            element,
+                            node,
+                            y,
+                        }
+                    }
+                };
+
+            row.y = y;
+
+            y += match self.row_height {
+                RowHeight::Fixed(height) => height,
+                RowHeight::Measured(_) => {
+                    let height = row.node.size().height;
+
+                    if heights.rows.insert(row.key, height) != Some(height) {
+                        state.is_outdated.set(true);
+                    }
+
+                    height
+                }
+            };
+
+            index += 1;
+            rows.push(row);
+        }
+
+        trees.trim();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            scrollable: scrollable::State::new(),
+            trees: RefCell::new(Trees::default()),
+            heights: self.heights.clone(),
+            is_outdated: Cell::new(false),
+        })
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let trees = state.trees.get_mut();
+
+        self.heights = state.heights.clone();
+        self.rows.get_mut().clear();
+
+        // The state of the rows that are no longer in the data is discarded
+        match &self.key {
+            Some(key) => {
+                let mut present = HashSet::new();
+
+                for index in 0..self.count {
+                    if present.len() == trees.entries.len() {
+                        break;
+                    }
+
+                    let key = key(index);
+
+                    if trees.entries.contains_key(&key) {
+                        let _ = present.insert(key);
+                    }
+                }
+
+                trees.entries.retain(|key, _| present.contains(key));
+            }
+            None => {
+                let count = self.count as u64;
+
+                trees.entries.retain(|key, _| *key < count);
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Rows are laid out again the next time they are resolved
+        self.rows.borrow_mut().clear();
+
+        let viewport_height =
+            limits.width(self.width).height(self.height).max().height;
+
+        scrollable::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            false,
+            |_renderer, limits| {
+                let width = limits.max().width;
+
+                let height = match self.row_height {
+                    RowHeight::Fixed(height) => height * self.count as f32,
+                    RowHeight::Measured(estimate) => {
+                        let mut heights = self.heights.borrow_mut();
+
+                        heights.fit(width);
+
+                        // Drawing right after this layout must not find any
+                        // new rows, since it cannot request a relayout
+                        self.measure_visible(
+                            &mut heights,
+                            renderer,
+                            width,
+                            viewport_height,
+                        );
+
+                        let mut measured = 0;
+                        let mut total = 0.0;
+
+                        for index in 0..self.count {
+                            total += match self.height_of(&heights, index) {
+                                Some(height) => {
+                                    measured += 1;
+                                    height
+                                }
+                                None => estimate,
+                            };
+                        }
+
+                        // Some measured rows are no longer in the data
+                        if measured < heights.rows.len() {
+                            heights
+                                .retain(self.count, |index| self.key_of(index));
+                        }
+
+                        total
+                    }
+                };
+
+                layout::Node::new(Size::new(width, height))
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        self.resolve(state, renderer, layout);
+
+        operation.scrollable(&mut state.scrollable, Some(&self.id));
+
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let trees = state.trees.get_mut();
+        let rows = self.rows.borrow();
+
+        operation.container(Some(&self.id), &mut |operation| {
+            for row in rows.iter() {
+                if let Some(tree) = trees.get_mut(&row.key) {
+                    row.element.as_widget().operate(
+                        tree,
+                        row.layout(content_bounds),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        self.resolve(state, renderer, layout);
+
+        let trees = state.trees.get_mut();
+        let rows = self.rows.get_mut();
+
+        let status = scrollable::update(
+            &mut state.scrollable,
+            event,
+            layout,
+            cursor,
+            clipboard,
+            shell,
+            &self.vertical,
+            None,
+            &self.on_scroll,
+            |event, layout, cursor, clipboard, shell| {
+                let content_bounds = layout.bounds();
+
+                rows.iter_mut()
+                    .filter_map(|row| {
+                        let tree = trees.get_mut(&row.key)?;
+                        let layout = Layout::with_offset(
+                            Vector::new(
+                                content_bounds.x,
+                                content_bounds.y + row.y,
+                            ),
+                            &row.node,
+                        );
+
+                        Some(row.element.as_widget_mut().on_event(
+                            tree,
+                            event.clone(),
+                            layout,
+                            cursor,
+                            renderer,
+                            clipboard,
+                            shell,
+                        ))
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        // Rows scrolled into view are measured before they are drawn
+        self.resolve(state, renderer, layout);
+
+        if state.is_outdated.take() {
+            shell.invalidate_layout();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        self.resolve(state, renderer, layout);
+
+        let trees = state.trees.borrow();
+        let rows = self.rows.borrow();
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            layout,
+            cursor,
+            &self.vertical,
+            None,
+            |layout, cursor, viewport| {
+                let content_bounds = layout.bounds();
+
+                rows.iter()
+                    .filter_map(|row| {
+                        let tree = trees.get(&row.key)?;
+
+                        Some(row.element.as_widget().mouse_interaction(
+                            tree,
+                            row.layout(content_bounds),
+                            cursor,
+                            viewport,
+                            renderer,
+                        ))
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        // Layout measures the visible rows beforehand; any row measured here
+        // marks the state as outdated until the next event is processed
+        self.resolve(state, renderer, layout);
+
+        let trees = state.trees.borrow();
+        let rows = self.rows.borrow();
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            layout,
+            cursor,
+            &self.vertical,
+            None,
+            &self.style,
+            |renderer, layout, cursor, viewport| {
+                let content_bounds = layout.bounds();
+
+                for row in rows.iter() {
+                    if let Some(tree) = trees.get(&row.key) {
+                        row.element.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            style,
+                            row.layout(content_bounds),
+                            cursor,
+                            viewport,
+                        );
+                    }
+                }
+            },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        self.resolve(state, renderer, layout);
+
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = state.scrollable.offset(bounds, content_bounds);
+
+        let mut trees: HashMap<u64, &mut Tree> = state
+            .trees
+            .get_mut()
+            .entries
+            .iter_mut()
+            .map(|(key, entry)| (*key, &mut entry.tree))
+            .collect();
+
+        let children = self
+            .rows
+            .get_mut()
+            .iter_mut()
+            .filter_map(|row| {
+                let tree = trees.remove(&row.key)?;
+                let layout = Layout::with_offset(
+                    Vector::new(content_bounds.x, content_bounds.y + row.y),
+                    &row.node,
+                );
+
+                row.element.as_widget_mut().overlay(tree, layout, renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| {
+            overlay::Group::with_children(children)
+                .overlay()
+                .translate(Vector::new(-offset.x, -offset.y))
+        })
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        tree: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+
+        let state = tree.state.downcast_ref::<State>();
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        let trees = state.trees.borrow();
+        let rows = self.rows.borrow();
+
+        A11yTree::join(rows.iter().filter_map(|row| {
+            let tree = trees.get(&row.key)?;
+
+            Some(row.element.as_widget().a11y_nodes(
+                row.layout(content_bounds),
+                tree,
+                cursor,
+            ))
+        }))
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(virtual_list: VirtualList<'a, Message, Renderer>) -> Self {
+        Element::new(virtual_list)
+    }
+}
+
+/// The local state of a [`VirtualList`].
+struct State {
+    scrollable: scrollable::State,
+    trees: RefCell<Trees>,
+    heights: Rc<RefCell<Heights>>,
+    is_outdated: Cell<bool>,
+}
+
+/// The widget state of the rows of a [`VirtualList`], by key.
+///
+/// The state of the rows that leave the viewport is kept, so they are
+/// restored when scrolled back into view. Only the [`CACHED_ROWS`] most
+/// recently visible of them are kept.
+#[derive(Default)]
+struct Trees {
+    entries: HashMap<u64, Entry>,
+    tick: u64,
+}
+
+/// The widget state of a row and the last time it was visible.
+struct Entry {
+    tree: Tree,
+    last_visible: u64,
+}
+
+impl Trees {
+    fn get(&self, key: &u64) -> Option<&Tree> {
+        self.entries.get(key).map(|entry| &entry.tree)
+    }
+
+    fn get_mut(&mut self, key: &u64) -> Option<&mut Tree> {
+        self.entries.get_mut(key).map(|entry| &mut entry.tree)
+    }
+
+    /// Marks the row with the given key as visible.
+    fn touch(&mut self, key: u64) {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_visible = self.tick;
+        }
+    }
+
+    /// Reconciles the state of the row with the given key with a newly built
+    /// element, marking the row as visible.
+    fn diff<Message, Renderer>(
+        &mut self,
+        key: u64,
+        element: &mut Element<'_, Message, Renderer>,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        match self.entries.get_mut(&key) {
+            Some(entry) => {
+                entry.tree.diff(&mut *element);
+                entry.last_visible = self.tick;
+            }
+            None => {
+                let _ = self.entries.insert(
+                    key,
+                    Entry {
+                        tree: Tree::new(&*element),
+                        last_visible: self.tick,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Discards the state of the least recently visible rows outside of the
+    /// viewport, until at most [`CACHED_ROWS`] of them are left.
+    fn trim(&mut self) {
+        if self.entries.len() <= CACHED_ROWS {
+            return;
+        }
+
+        let mut hidden: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.last_visible != self.tick)
+            .map(|(key, entry)| (entry.last_visible, *key))
+            .collect();
+
+        if hidden.len() <= CACHED_ROWS {
+            return;
+        }
+
+        hidden.sort_unstable();
+
+        for (_, key) in &hidden[..hidden.len() - CACHED_ROWS] {
+            let _ = self.entries.remove(key);
+        }
+    }
+}
+
+/// The cached heights of the measured rows of a [`VirtualList`], by key.
+#[derive(Debug, Default)]
+struct Heights {
+    width: f32,
+    rows: HashMap<u64, f32>,
+    /// The vertical offset and the height of the viewport the last time the
+    /// rows were resolved.
+    viewport: (f32, f32),
+}
+
+impl Heights {
+    /// Discards every measurement if the width of the rows has changed.
+    fn fit(&mut self, width: f32) {
+        if self.width != width {
+            self.width = width;
+            self.rows.clear();
+        }
+    }
+
+    /// Discards the measurements of the rows that are no longer in the data,
+    /// given its amount of rows and the key of the row at an index.
+    fn retain(&mut self, count: usize, key_of: impl Fn(usize) -> u64) {
+        let present: HashSet<u64> = (0..count).map(key_of).collect();
+
+        self.rows.retain(|key, _| present.contains(key));
+    }
+}
+
+/// A row of a [`VirtualList`] that has been built and laid out.
+struct Row<'a, Message, Renderer> {
+    index: usize,
+    key: u64,
+    element: Element<'a, Message, Renderer>,
+    node: layout::Node,
+    y: f32,
+}
+
+impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
+    fn layout(&self, content_bounds: Rectangle) -> Layout<'_> {
+        Layout::with_offset(
+            Vector::new(content_bounds.x, content_bounds.y + self.y),
+            &self.node,
+        )
+    }
+}
+
+/// Returns the index and the vertical position of the first row that is
+/// visible at the given scrolling offset.
+fn first_visible(
+    row_height: RowHeight,
+    count: usize,
+    offset: f32,
+    height_of: impl Fn(usize) -> Option<f32>,
+) -> (usize, f32) {
+    match row_height {
+        RowHeight::Fixed(height) if height > 0.0 => {
+            let index = (offset / height).floor().max(0.0) as usize;
+
+            (index, index as f32 * height)
+        }
+        RowHeight::Fixed(_) => (0, 0.0),
+        RowHeight::Measured(estimate) => {
+            let mut y = 0.0;
+
+            for index in 0..count {
+                let height = height_of(index).unwrap_or(estimate);
+
+                if y + height > offset {
+                    return (index, y);
+                }
+
+                y += height;
+            }
+
+            (count, y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_visible_fixed_row() {
+        let fixed = |offset| {
+            first_visible(RowHeight::Fixed(20.0), 10, offset, |_| None)
+        };
+
+        assert_eq!(fixed(0.0), (0, 0.0));
+        assert_eq!(fixed(45.0), (2, 40.0));
+    }
+
+    #[test]
+    fn first_visible_measured_row() {
+        let heights = [Some(10.0), None, Some(50.0), None];
+        let measured = |offset| {
+            first_visible(RowHeight::Measured(30.0), 4, offset, |index| {
+                heights[index]
+            })
+        };
+
+        assert_eq!(measured(35.0), (1, 10.0));
+        assert_eq!(measured(40.0), (2, 40.0));
+        assert_eq!(measured(1000.0), (4, 120.0));
+    }
+
+    #[test]
+    fn heights_discard_rows_no_longer_in_the_data() {
+        let keys = [7, 3, 5];
+
+        let mut heights = Heights::default();
+        heights.fit(100.0);
+
+        let _ = heights.rows.insert(5, 10.0);
+        let _ = heights.rows.insert(9, 20.0);
+
+        heights.retain(keys.len(), |index| keys[index]);
+
+        assert_eq!(heights.rows.get(&5), Some(&10.0));
+        assert_eq!(heights.rows.get(&9), None);
+
+        heights.fit(200.0);
+
+        assert!(heights.rows.is_empty());
+    }
+
+    #[test]
+    fn trim_keeps_the_most_recently_visible_rows() {
+        use crate::core::renderer::Null;
+        use crate::core::widget::Text;
+
+        let mut trees = Trees::default();
+
+        for key in 0..CACHED_ROWS as u64 + 10 {
+            let mut element: Element<'_, (), Null> = Text::new("row").into();
+
+            trees.tick += 1;
+            trees.diff(key, &mut element);
+        }
+
+        trees.tick += 1;
+        trees.touch(0);
+        trees.trim();
+
+        assert_eq!(trees.entries.len(), CACHED_ROWS + 1);
+        assert!(trees.get(&0).is_some());
+        assert!(trees.get(&1).is_none());
+        assert!(trees.get(&10).is_some());
+    }
+}
@@ -0,0 +1,469 @@
+//! Distribute content in lines that wrap when they run out of space.
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, OperationOutputWrapper, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+/// The direction in which a [`Wrap`] places its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Children are placed from left to right, and lines flow from top to
+    /// bottom.
+    #[default]
+    Horizontal,
+    /// Children are placed from top to bottom, and lines flow from left to
+    /// right.
+    Vertical,
+}
+
+impl Direction {
+    fn main(self, size: Size) -> f32 {
+        match self {
+            Direction::Horizontal => size.width,
+            Direction::Vertical => size.height,
+        }
+    }
+
+    fn cross(self, size: Size) -> f32 {
+        match self {
+            Direction::Horizontal => size.height,
+            Direction::Vertical => size.width,
+        }
+    }
+
+    fn pack(self, main: f32, cross: f32) -> Size {
+        match self {
+            Direction::Horizontal => Size::new(main, cross),
+            Direction::Vertical => Size::new(cross, main),
+        }
+    }
+}
+
+/// A container that distributes its contents in lines, starting a new line
+/// whenever the next child does not fit in the current one.
+///
+/// Unlike a [`Row`] or a [`Column`], a [`Wrap`] only takes the space its
+/// lines need along the cross axis, so it can be placed inside a
+/// [`Scrollable`].
+///
+/// Children that fill the main axis take a whole line on their own.
+///
+/// [`Row`]: crate::Row
+/// [`Column`]: crate::Column
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer = crate::Renderer> {
+    direction: Direction,
+    spacing: f32,
+    line_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align_items: Alignment,
+    line_alignment: Alignment,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            direction: Direction::Horizontal,
+            spacing: 0.0,
+            line_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align_items: Alignment::Start,
+            line_alignment: Alignment::Start,
+            children,
+        }
+    }
+
+    /// Sets the [`Direction`] of the [`Wrap`].
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the spacing _between_ elements of the same line.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the spacing _between_ lines.
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Wrap`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the alignment of the contents of each line along the cross
+    /// axis.
+    pub fn align_items(mut self, align: Alignment) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets the alignment of each line along the main axis.
+    pub fn line_alignment(mut self, align: Alignment) -> Self {
+        self.line_alignment = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Wrap`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A line of a [`Wrap`], holding the children in `start..end`.
+struct Line {
+    start: usize,
+    end: usize,
+    main: f32,
+    cross: f32,
+}
+
+/// Offsets some content of the given length within the available space.
+fn align(alignment: Alignment, available: f32, length: f32) -> f32 {
+    match alignment {
+        Alignment::Start => 0.0,
+        Alignment::Center => (available - length) / 2.0,
+        Alignment::End => available - length,
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.children)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let direction = self.direction;
+
+        let limits = limits.width(self.width).height(self.height);
+        let content_limits = limits.pad(self.padding).loose();
+        let max_main = direction.main(content_limits.max());
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .map(|child| child.as_widget().layout(renderer, &content_limits))
+            .collect();
+
+        let mut lines: Vec<Line> = Vec::new();
+
+        for (index, node) in nodes.iter().enumerate() {
+            let main = direction.main(node.size());
+            let cross = direction.cross(node.size());
+
+            match lines.last_mut() {
+                Some(line) if line.main + self.spacing + main <= max_main => {
+                    line.end = index + 1;
+                    line.main += self.spacing + main;
+                    line.cross = line.cross.max(cross);
+                }
+                _ => lines.push(Line {
+                    start: index,
+                    end: index + 1,
+                    main,
+                    cross,
+                }),
+            }
+        }
+
+        let intrinsic_main =
+            lines.iter().map(|line| line.main).fold(0.0, f32::max);
+        let intrinsic_cross = lines.iter().map(|line| line.cross).sum::<f32>()
+            + self.line_spacing * lines.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(
+            direction
+                .pack(intrinsic_main, intrinsic_cross)
+                .pad(self.padding),
+        );
+
+        let available_main =
+            direction.main(size) - direction.main(Size::ZERO.pad(self.padding));
+
+        let mut line_offset = 0.0;
+
+        for line in &lines {
+            let mut main_offset =
+                align(self.line_alignment, available_main, line.main);
+
+            for node in &mut nodes[line.start..line.end] {
+                let main = direction.main(node.size());
+                let cross = direction.cross(node.size());
+
+                let offset = direction.pack(
+                    main_offset,
+                    line_offset + align(self.align_items, line.cross, cross),
+                );
+
+                node.move_to(Point::new(
+                    self.padding.left + offset.width,
+                    self.padding.top + offset.height,
+                ));
+
+                main_offset += main + self.spacing;
+            }
+
+            line_offset += line.cross + self.line_spacing;
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| {
+                    c.as_widget().a11y_nodes(c_layout, state, p)
+                }),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(wrap: Wrap<'a, Message, Renderer>) -> Self {
+        Self::new(wrap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    fn positions(node: &layout::Node) -> Vec<(f32, f32)> {
+        node.children()
+            .iter()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect()
+    }
+
+    #[test]
+    fn children_flow_to_new_lines() {
+        let wrap: Wrap<'_, (), Null> = Wrap::new()
+            .spacing(10)
+            .line_spacing(5)
+            .align_items(Alignment::Center)
+            .line_alignment(Alignment::End)
+            .push(Space::new(40.0, 10.0))
+            .push(Space::new(40.0, 20.0))
+            .push(Space::new(40.0, 10.0));
+
+        let node = wrap.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        );
+
+        assert_eq!(node.size(), Size::new(90.0, 35.0));
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 5.0), (50.0, 0.0), (50.0, 25.0)]
+        );
+    }
+
+    #[test]
+    fn vertical_lines_flow_to_the_right() {
+        let wrap: Wrap<'_, (), Null> = Wrap::new()
+            .direction(Direction::Vertical)
+            .spacing(10)
+            .padding(5)
+            .push(Space::new(20.0, 40.0))
+            .push(Space::new(30.0, 40.0))
+            .push(Space::new(20.0, 40.0));
+
+        let node = wrap.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, 100.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(60.0, 100.0));
+        assert_eq!(
+            positions(&node),
+            vec![(5.0, 5.0), (5.0, 55.0), (35.0, 5.0)]
+        );
+    }
+}
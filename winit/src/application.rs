@@ -11,6 +11,7 @@ pub use state::State;
 
 use crate::conversion;
 use crate::core;
+use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::time::Instant;
@@ -340,6 +341,8 @@ async fn run_instance<A, E, C>(
 
     run_command(
         &application,
+        &mut compositor,
+        &mut surface,
         &mut cache,
         &state,
         &mut renderer,
@@ -350,7 +353,6 @@ async fn run_instance<A, E, C>(
         &mut proxy,
         &mut debug,
         &window,
-        || compositor.fetch_information(),
     );
     runtime.track(
         application
@@ -368,6 +370,7 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = keyboard::InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
@@ -452,6 +455,8 @@ async fn run_instance<A, E, C>(
                     // Update application
                     update(
                         &mut application,
+                        &mut compositor,
+                        &mut surface,
                         &mut cache,
                         &state,
                         &mut renderer,
@@ -462,7 +467,6 @@ async fn run_instance<A, E, C>(
                         &mut debug,
                         &mut messages,
                         &window,
-                        || compositor.fetch_information(),
                     );
 
                     // Update window
@@ -487,7 +491,7 @@ async fn run_instance<A, E, C>(
                 // Then, we can use the `interface_state` here to decide if a redraw
                 // is needed right away, or simply wait until a specific time.
                 let redraw_event = Event::Window(
-                    window::Id::default(),
+                    window::Id::MAIN,
                     window::Event::RedrawRequested(Instant::now()),
                 );
 
@@ -518,12 +522,30 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    if new_input_method != input_method {
+                        update_input_method(
+                            &window,
+                            input_method,
+                            new_input_method,
+                            state.scale_factor(),
+                        );
+
+                        input_method = new_input_method;
+                    }
+                }
+
                 window.request_redraw();
                 runtime.broadcast(redraw_event, core::event::Status::Ignored);
 
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => ControlFlow::Poll,
                         window::RedrawRequest::At(at) => {
@@ -730,6 +752,7 @@ async fn run_instance<A, E, C>(
                 state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
@@ -769,6 +792,33 @@ pub fn requests_exit(
     }
 }
 
+/// Updates the input method of the window with the [`InputMethod`] requested
+/// by the widgets of an [`Application`].
+///
+/// [`InputMethod`]: keyboard::InputMethod
+pub fn update_input_method(
+    window: &winit::window::Window,
+    current: keyboard::InputMethod,
+    requested: keyboard::InputMethod,
+    scale_factor: f64,
+) {
+    match requested {
+        keyboard::InputMethod::Enabled { cursor } => {
+            if !current.is_enabled() {
+                window.set_ime_allowed(true);
+            }
+
+            window.set_ime_position(conversion::ime_position(
+                cursor,
+                scale_factor,
+            ));
+        }
+        keyboard::InputMethod::Disabled => {
+            window.set_ime_allowed(false);
+        }
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -785,7 +835,7 @@ where
     let view_span = info_span!("Application", "VIEW").entered();
 
     debug.view_started();
-    let mut view = application.view(Default::default());
+    let mut view = application.view(window::Id::MAIN);
     view.as_widget_mut().diff(&mut core::widget::Tree::empty());
 
     #[cfg(feature = "trace")]
@@ -817,8 +867,10 @@ where
 
 /// Updates an [`Application`] by feeding it the provided messages, spawning any
 /// resulting [`Command`], and tracking its [`Subscription`].
-pub fn update<A: Application, E: Executor>(
+pub fn update<A: Application, C, E: Executor>(
     application: &mut A,
+    compositor: &mut C,
+    surface: &mut C::Surface,
     cache: &mut user_interface::Cache,
     state: &State<A>,
     renderer: &mut A::Renderer,
@@ -833,8 +885,8 @@ pub fn update<A: Application, E: Executor>(
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
-    graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
+    C: Compositor<Renderer = A::Renderer> + 'static,
     <A::Renderer as core::Renderer>::Theme: StyleSheet,
 {
     for message in messages.drain(..) {
@@ -852,6 +904,8 @@ pub fn update<A: Application, E: Executor>(
 
         run_command(
             application,
+            compositor,
+            surface,
             cache,
             state,
             renderer,
@@ -862,7 +916,6 @@ pub fn update<A: Application, E: Executor>(
             proxy,
             debug,
             window,
-            graphics_info,
         );
     }
 
@@ -875,8 +928,10 @@ pub fn update<A: Application, E: Executor>(
 }
 
 /// Runs the actions of a [`Command`].
-pub fn run_command<A, E>(
+pub fn run_command<A, C, E>(
     application: &A,
+    compositor: &mut C,
+    surface: &mut C::Surface,
     cache: &mut user_interface::Cache,
     state: &State<A>,
     renderer: &mut A::Renderer,
@@ -891,9 +946,9 @@ pub fn run_command<A, E>(
     proxy: &mut winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
     debug: &mut Debug,
     window: &winit::window::Window,
-    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     A: Application,
+    C: Compositor<Renderer = A::Renderer> + 'static,
     E: Executor,
     <A::Renderer as core::Renderer>::Theme: StyleSheet,
 {
@@ -920,7 +975,16 @@ pub fn run_command<A, E>(
                     clipboard.write(contents);
                 }
             },
-            command::Action::Window(action) => match action {
+            command::Action::Window(id, action) if id != window::Id::MAIN => {
+                log::warn!(
+                    "Ignoring {action:?}, since window {id:?} does not exist. \
+                    Opening more windows requires the `multi-window` feature."
+                );
+            }
+            command::Action::Window(_, action) => match action {
+                window::Action::Spawn(_) => {
+                    log::warn!("The main window is already open");
+                }
                 window::Action::Close => {
                     *should_exit = true;
                 }
@@ -990,12 +1054,30 @@ pub fn run_command<A, E>(
                             .into())))
                         .expect("Send message to event loop");
                 }
+                window::Action::Screenshot(tag) => {
+                    let bytes = compositor.screenshot(
+                        renderer,
+                        surface,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            window::Screenshot::new(
+                                bytes,
+                                state.physical_size(),
+                            ),
+                        )))
+                        .expect("Send message to event loop");
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
                     #[cfg(feature = "system")]
                     {
-                        let graphics_info = _graphics_info();
+                        let graphics_info = compositor.fetch_information();
                         let proxy = proxy.clone();
 
                         let _ = std::thread::spawn(move || {
@@ -1070,7 +1152,7 @@ pub fn run_command<A, E>(
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         mut event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -1092,7 +1174,7 @@ mod platform {
 }
 
 #[cfg(target_arch = "wasm32")]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -6,11 +6,12 @@ use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point};
+use crate::core::{Event, Point, Rectangle};
 use crate::Position;
 
 /// Converts a winit window event into an iced event.
 pub fn window_event(
+    id: window::Id,
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
     modifiers: winit::event::ModifiersState,
@@ -22,7 +23,7 @@ pub fn window_event(
             let logical_size = new_size.to_logical(scale_factor);
 
             Some(Event::Window(
-                window::Id::default(),
+                id,
                 window::Event::Resized {
                     width: logical_size.width,
                     height: logical_size.height,
@@ -33,17 +34,16 @@ pub fn window_event(
             let logical_size = new_inner_size.to_logical(scale_factor);
 
             Some(Event::Window(
-                window::Id::default(),
+                id,
                 window::Event::Resized {
                     width: logical_size.width,
                     height: logical_size.height,
                 },
             ))
         }
-        WindowEvent::CloseRequested => Some(Event::Window(
-            window::Id::default(),
-            window::Event::CloseRequested,
-        )),
+        WindowEvent::CloseRequested => {
+            Some(Event::Window(id, window::Event::CloseRequested))
+        }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -120,26 +120,26 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(self::modifiers(*new_modifiers)),
         )),
+        WindowEvent::Ime(ime) => {
+            Some(Event::Keyboard(keyboard::Event::Ime(self::ime(ime))))
+        }
         WindowEvent::Focused(focused) => Some(Event::Window(
-            window::Id::default(),
+            id,
             if *focused {
                 window::Event::Focused
             } else {
                 window::Event::Unfocused
             },
         )),
-        WindowEvent::HoveredFile(path) => Some(Event::Window(
-            window::Id::default(),
-            window::Event::FileHovered(path.clone()),
-        )),
-        WindowEvent::DroppedFile(path) => Some(Event::Window(
-            window::Id::default(),
-            window::Event::FileDropped(path.clone()),
-        )),
-        WindowEvent::HoveredFileCancelled => Some(Event::Window(
-            window::Id::default(),
-            window::Event::FilesHoveredLeft,
-        )),
+        WindowEvent::HoveredFile(path) => {
+            Some(Event::Window(id, window::Event::FileHovered(path.clone())))
+        }
+        WindowEvent::DroppedFile(path) => {
+            Some(Event::Window(id, window::Event::FileDropped(path.clone())))
+        }
+        WindowEvent::HoveredFileCancelled => {
+            Some(Event::Window(id, window::Event::FilesHoveredLeft))
+        }
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(*touch, scale_factor)))
         }
@@ -147,10 +147,7 @@ pub fn window_event(
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
 
-            Some(Event::Window(
-                window::Id::default(),
-                window::Event::Moved { x, y },
-            ))
+            Some(Event::Window(id, window::Event::Moved { x, y }))
         }
         _ => None,
     }
@@ -342,6 +339,39 @@ pub fn touch_event(
     }
 }
 
+/// Converts an `Ime` event from [`winit`] to an [`iced_native`] input method
+/// event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/iced-rs/iced/tree/0.9/native
+pub fn ime(ime: &winit::event::Ime) -> keyboard::Ime {
+    match ime {
+        winit::event::Ime::Enabled => keyboard::Ime::Enabled,
+        winit::event::Ime::Preedit(content, cursor) => keyboard::Ime::Preedit {
+            content: content.clone(),
+            cursor: *cursor,
+        },
+        winit::event::Ime::Commit(content) => {
+            keyboard::Ime::Commit(content.clone())
+        }
+        winit::event::Ime::Disabled => keyboard::Ime::Disabled,
+    }
+}
+
+/// Converts the logical text cursor of an [`InputMethod`] to the physical
+/// position where the candidate window of the input method should be placed.
+///
+/// [`InputMethod`]: keyboard::InputMethod
+pub fn ime_position(
+    cursor: Rectangle,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::PhysicalPosition {
+        x: f64::from(cursor.x) * scale_factor,
+        y: f64::from(cursor.y + cursor.height) * scale_factor,
+    }
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -44,11 +44,13 @@ pub mod clipboard;
 pub mod conversion;
 pub mod settings;
 
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
+
 #[cfg(feature = "system")]
 pub mod system;
 
 mod error;
-mod position;
 mod proxy;
 
 #[cfg(feature = "application")]
@@ -57,8 +59,8 @@ pub use application::Application;
 pub use application::Profiler;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use position::Position;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
+pub use crate::core::window::Position;
 pub use iced_graphics::Viewport;
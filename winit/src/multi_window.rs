@@ -0,0 +1,1245 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+mod window_manager;
+
+pub use state::State;
+
+use crate::application::{
+    requests_exit, update_input_method, UserEventWrapper,
+};
+use crate::conversion;
+use crate::core;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::widget::operation::{self, OperationWrapper};
+use crate::core::window;
+use crate::core::Size;
+use crate::futures::futures;
+use crate::futures::{Executor, Runtime, Subscription};
+use crate::graphics::compositor::{self, Compositor};
+use crate::runtime::clipboard;
+use crate::runtime::program::Program;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::{Command, Debug};
+use crate::settings;
+use crate::style::application::StyleSheet;
+use crate::{Clipboard, Error, Proxy, Settings};
+
+use window_manager::WindowManager;
+
+use futures::channel::mpsc;
+
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+
+#[cfg(feature = "trace")]
+use crate::application::Profiler;
+#[cfg(feature = "trace")]
+use tracing::{info_span, instrument::Instrument};
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// The [`Program::view`] of a multi-window [`Application`] is called once for
+/// every open window, with its [`window::Id`]. The window created at startup
+/// is identified by [`window::Id::MAIN`], and more windows can be opened with
+/// [`window::spawn`].
+///
+/// An [`Application`] can execute asynchronous actions by returning a
+/// [`Command`] in some of its methods.
+///
+/// When using an [`Application`] with the `debug` feature enabled, a debug view
+/// can be toggled by pressing `F12`.
+///
+/// [`window::spawn`]: crate::runtime::window::spawn
+pub trait Application: Program
+where
+    <Self::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup. This is useful if you want to
+    /// load state from a file, perform an initial HTTP request, etc.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of the window when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Returns the current `Theme` of the window with the given
+    /// [`window::Id`].
+    fn theme(
+        &self,
+        window: window::Id,
+    ) -> <Self::Renderer as core::Renderer>::Theme;
+
+    /// Returns the `Style` variation of the `Theme`.
+    fn style(
+        &self,
+    ) -> <<Self::Renderer as core::Renderer>::Theme as StyleSheet>::Style {
+        Default::default()
+    }
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// The messages produced by the `Subscription` will be handled by
+    /// [`update`](#tymethod.update).
+    ///
+    /// A `Subscription` will be kept alive as long as you keep returning it!
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// It can be used to dynamically control the size of the UI at runtime
+    /// (i.e. zooming).
+    ///
+    /// For instance, a scale factor of `2.0` will make widgets twice as big,
+    /// while a scale factor of `0.5` will shrink them to half their size.
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+}
+
+/// An event sent to the instance of a multi-window [`Application`].
+enum Event<Message> {
+    EventLoopAwakened(winit::event::Event<'static, UserEventWrapper<Message>>),
+    WindowCreated {
+        id: window::Id,
+        window: winit::window::Window,
+    },
+}
+
+/// A request of the instance of a multi-window [`Application`] to its event
+/// loop.
+enum Control {
+    ChangeFlow(winit::event_loop::ControlFlow),
+    CreateWindow {
+        id: window::Id,
+        settings: settings::Window,
+        title: String,
+    },
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The window settings are used for the main window. When
+/// `exit_on_close_request` is enabled, a window is closed as soon as the user
+/// requests it, and the [`Application`] exits once all of its windows are
+/// closed.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoopBuilder;
+
+    #[cfg(feature = "trace")]
+    let _guard = Profiler::init();
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    #[cfg(feature = "trace")]
+    let _ = info_span!("Application", "RUN").entered();
+
+    let event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let should_be_visible = settings.window.visible;
+    let builder = settings
+        .window
+        .into_builder(
+            &application.title(window::Id::MAIN),
+            event_loop.primary_monitor(),
+            settings.id,
+        )
+        .with_visible(false);
+
+    log::debug!("Window builder: {:#?}", builder);
+
+    let main_window = builder
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let (compositor, renderer) =
+        C::new(compositor_settings, Some(&main_window))?;
+
+    let (mut event_sender, event_receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin({
+        let run_instance = run_instance::<A, E, C>(
+            application,
+            compositor,
+            renderer,
+            runtime,
+            proxy,
+            debug,
+            event_receiver,
+            control_sender,
+            init_command,
+            main_window,
+            should_be_visible,
+            settings.exit_on_close_request,
+        );
+
+        #[cfg(feature = "trace")]
+        let run_instance =
+            run_instance.instrument(info_span!("Application", "LOOP"));
+
+        run_instance
+    });
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    crate::application::platform::run(
+        event_loop,
+        move |event, event_loop, control_flow| {
+            use winit::event_loop::ControlFlow;
+
+            if let ControlFlow::ExitWithCode(_) = control_flow {
+                return;
+            }
+
+            let event = match event {
+                winit::event::Event::WindowEvent {
+                    event:
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            new_inner_size,
+                            ..
+                        },
+                    window_id,
+                } => Some(winit::event::Event::WindowEvent {
+                    event: winit::event::WindowEvent::Resized(*new_inner_size),
+                    window_id,
+                }),
+                _ => event.to_static(),
+            };
+
+            let Some(event) = event else {
+                return;
+            };
+
+            event_sender
+                .start_send(Event::EventLoopAwakened(event))
+                .expect("Send event");
+
+            loop {
+                match instance.as_mut().poll(&mut context) {
+                    task::Poll::Pending => match control_receiver.try_next() {
+                        Ok(Some(Control::ChangeFlow(flow))) => {
+                            *control_flow = flow;
+                        }
+                        Ok(Some(Control::CreateWindow {
+                            id,
+                            settings,
+                            title,
+                        })) => {
+                            let builder = settings.into_builder(
+                                &title,
+                                event_loop.primary_monitor(),
+                                None,
+                            );
+
+                            match builder.build(event_loop) {
+                                Ok(window) => {
+                                    event_sender
+                                        .start_send(Event::WindowCreated {
+                                            id,
+                                            window,
+                                        })
+                                        .expect("Send event");
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "Failed to create window {id:?}: \
+                                        {error}"
+                                    );
+                                }
+                            }
+                        }
+                        _ => break,
+                    },
+                    task::Poll::Ready(_) => {
+                        *control_flow = ControlFlow::Exit;
+                        break;
+                    }
+                }
+            }
+        },
+    )
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<
+        E,
+        Proxy<UserEventWrapper<A::Message>>,
+        UserEventWrapper<A::Message>,
+    >,
+    mut proxy: winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
+    mut debug: Debug,
+    mut event_receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    main_window: winit::window::Window,
+    should_be_visible: bool,
+    exit_on_close_request: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use futures::stream::StreamExt;
+    use winit::event;
+    use winit::event_loop::ControlFlow;
+
+    let mut clipboard = Clipboard::connect(&main_window);
+    let mut window_manager = WindowManager::default();
+
+    let window = window_manager.insert(
+        window::Id::MAIN,
+        main_window,
+        &application,
+        &mut compositor,
+        &proxy,
+    );
+
+    if should_be_visible {
+        window.raw.set_visible(true);
+    }
+
+    let mut caches = HashMap::new();
+    let mut should_exit = false;
+
+    run_command(
+        &application,
+        &mut compositor,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut should_exit,
+        &mut proxy,
+        &mut debug,
+        &mut window_manager,
+        &mut caches,
+        &mut control_sender,
+    );
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEventWrapper::Message)
+            .into_recipes(),
+    );
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        &mut renderer,
+        &mut debug,
+        &window_manager,
+        caches,
+    ));
+
+    let mut events: Vec<(window::Id, core::Event)> = Vec::new();
+    let mut messages = Vec::new();
+    let mut redraw_pending = false;
+
+    #[cfg(feature = "a11y")]
+    let mut a11y_enabled = false;
+
+    debug.startup_finished();
+
+    while let Some(event) = event_receiver.next().await {
+        let event = match event {
+            Event::WindowCreated { id, window } => {
+                let window = window_manager.insert(
+                    id,
+                    window,
+                    &application,
+                    &mut compositor,
+                    &proxy,
+                );
+
+                let user_interface = build_user_interface(
+                    &application,
+                    user_interface::Cache::default(),
+                    &mut renderer,
+                    window.state.logical_size(),
+                    &mut debug,
+                    id,
+                );
+
+                let _ = user_interfaces.insert(id, user_interface);
+
+                continue;
+            }
+            Event::EventLoopAwakened(event) => event,
+        };
+
+        match event {
+            event::Event::NewEvents(start_cause) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                );
+            }
+            event::Event::MainEventsCleared => {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut uis_stale = false;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let Some(window) = window_manager.get_mut(*id) else {
+                        continue;
+                    };
+
+                    let mut window_events = Vec::new();
+
+                    events.retain(|(window_id, event)| {
+                        if window_id == id {
+                            window_events.push(event.clone());
+                            false
+                        } else {
+                            true
+                        }
+                    });
+
+                    let (interface_state, statuses) = user_interface.update(
+                        &window_events,
+                        window.state.cursor(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    if matches!(
+                        interface_state,
+                        user_interface::State::Outdated
+                    ) {
+                        uis_stale = true;
+                    }
+
+                    for (event, status) in
+                        window_events.into_iter().zip(statuses.into_iter())
+                    {
+                        runtime.broadcast(event, status);
+                    }
+                }
+
+                debug.event_processing_finished();
+
+                // Events of windows without an interface are only broadcast
+                for (_, event) in events.drain(..) {
+                    runtime.broadcast(event, core::event::Status::Ignored);
+                }
+
+                if !messages.is_empty() || uis_stale {
+                    let mut caches: HashMap<_, _> =
+                        ManuallyDrop::into_inner(user_interfaces)
+                            .drain()
+                            .map(|(id, user_interface)| {
+                                (id, user_interface.into_cache())
+                            })
+                            .collect();
+
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut compositor,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut should_exit,
+                        &mut proxy,
+                        &mut debug,
+                        &mut messages,
+                        &mut window_manager,
+                        &mut caches,
+                        &mut control_sender,
+                    );
+
+                    // Update windows
+                    for (id, window) in window_manager.iter_mut() {
+                        window.state.synchronize(&application, id, &window.raw);
+                    }
+
+                    user_interfaces = ManuallyDrop::new(build_user_interfaces(
+                        &application,
+                        &mut renderer,
+                        &mut debug,
+                        &window_manager,
+                        caches,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                let mut control_flow = ControlFlow::Wait;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let Some(window) = window_manager.get_mut(*id) else {
+                        continue;
+                    };
+
+                    // TODO: Avoid redrawing all the time by forcing widgets to
+                    // request redraws on state changes
+                    let redraw_event = core::Event::Window(
+                        *id,
+                        window::Event::RedrawRequested(Instant::now()),
+                    );
+
+                    let (interface_state, _) = user_interface.update(
+                        &[redraw_event.clone()],
+                        window.state.cursor(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    if let user_interface::State::Updated {
+                        input_method,
+                        redraw_request,
+                    } = interface_state
+                    {
+                        if input_method != window.input_method {
+                            update_input_method(
+                                &window.raw,
+                                window.input_method,
+                                input_method,
+                                window.state.scale_factor(),
+                            );
+
+                            window.input_method = input_method;
+                        }
+
+                        // The most urgent request of all windows wins
+                        control_flow = match (control_flow, redraw_request) {
+                            (_, None) => control_flow,
+                            (ControlFlow::Poll, _)
+                            | (_, Some(window::RedrawRequest::NextFrame)) => {
+                                ControlFlow::Poll
+                            }
+                            (
+                                ControlFlow::WaitUntil(current),
+                                Some(window::RedrawRequest::At(at)),
+                            ) => ControlFlow::WaitUntil(current.min(at)),
+                            (_, Some(window::RedrawRequest::At(at))) => {
+                                ControlFlow::WaitUntil(at)
+                            }
+                        };
+                    }
+
+                    window.raw.request_redraw();
+                    runtime
+                        .broadcast(redraw_event, core::event::Status::Ignored);
+                }
+
+                let _ = control_sender
+                    .start_send(Control::ChangeFlow(control_flow));
+
+                redraw_pending = false;
+            }
+            event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(url),
+            )) => {
+                use crate::core::event;
+
+                events.push((
+                    window::Id::MAIN,
+                    core::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ),
+                ));
+            }
+            event::Event::UserEvent(message) => match message {
+                UserEventWrapper::Message(message) => messages.push(message),
+                #[cfg(feature = "a11y")]
+                UserEventWrapper::A11y(request) => {
+                    let Some((id, _)) =
+                        window_manager.get_mut_alias(request.window_id)
+                    else {
+                        continue;
+                    };
+
+                    if let iced_accessibility::accesskit::Action::Focus =
+                        request.request.action
+                    {
+                        if let Some(user_interface) =
+                            user_interfaces.get_mut(&id)
+                        {
+                            user_interface.operate(
+                                &renderer,
+                                &mut operation::focusable::focus(
+                                    core::widget::Id::from(u128::from(
+                                        request.request.target.0,
+                                    )
+                                        as u64),
+                                ),
+                            );
+                        }
+                    }
+
+                    events.push((id, conversion::a11y(request.request)));
+                }
+                #[cfg(feature = "a11y")]
+                UserEventWrapper::A11yEnabled => a11y_enabled = true,
+            },
+            event::Event::RedrawRequested(window_id) => {
+                #[cfg(feature = "trace")]
+                let _ = info_span!("Application", "FRAME").entered();
+
+                let Some((id, window)) =
+                    window_manager.get_mut_alias(window_id)
+                else {
+                    continue;
+                };
+
+                let Some(mut user_interface) = user_interfaces.remove(&id)
+                else {
+                    continue;
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    let _ = user_interfaces.insert(id, user_interface);
+
+                    continue;
+                }
+
+                #[cfg(feature = "a11y")]
+                if a11y_enabled {
+                    update_a11y_tree(&mut user_interface, &renderer, window);
+                }
+
+                debug.render_started();
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    let logical_size = window.state.logical_size();
+
+                    debug.layout_started();
+                    user_interface =
+                        user_interface.relayout(logical_size, &mut renderer);
+                    debug.layout_finished();
+
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                // The renderer is shared by all windows, so the window must
+                // be drawn right before presenting it
+                debug.draw_started();
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    window.state.theme(),
+                    &renderer::Style {
+                        text_color: window.state.text_color(),
+                    },
+                    window.state.cursor(),
+                );
+                debug.draw_finished();
+
+                if new_mouse_interaction != window.mouse_interaction {
+                    window.raw.set_cursor_icon(conversion::mouse_interaction(
+                        new_mouse_interaction,
+                    ));
+
+                    window.mouse_interaction = new_mouse_interaction;
+                }
+
+                let _ = user_interfaces.insert(id, user_interface);
+
+                match compositor.present(
+                    &mut renderer,
+                    &mut window.surface,
+                    window.state.viewport(),
+                    window.state.background_color(),
+                    &debug.overlay(),
+                ) {
+                    Ok(()) => {
+                        debug.render_finished();
+                    }
+                    Err(error) => match error {
+                        // This is an unrecoverable error.
+                        compositor::SurfaceError::OutOfMemory => {
+                            panic!("{error:?}");
+                        }
+                        _ => {
+                            debug.render_finished();
+
+                            // Try rendering again next frame.
+                            window.raw.request_redraw();
+                        }
+                    },
+                }
+            }
+            event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            } => {
+                let Some((id, window)) =
+                    window_manager.get_mut_alias(window_id)
+                else {
+                    continue;
+                };
+
+                if requests_exit(&window_event, window.state.modifiers())
+                    && exit_on_close_request
+                {
+                    let _ = user_interfaces.remove(&id);
+                    let _ = window_manager.remove(id);
+
+                    runtime.broadcast(
+                        core::Event::Window(id, window::Event::Closed),
+                        core::event::Status::Ignored,
+                    );
+
+                    if window_manager.is_empty() {
+                        break;
+                    }
+
+                    continue;
+                }
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                if let Some(event) = conversion::window_event(
+                    id,
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                ) {
+                    events.push((id, event));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+}
+
+/// Builds a [`UserInterface`] for the window of an [`Application`] with the
+/// given [`window::Id`], logging [`struct@Debug`] information accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a A,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+    id: window::Id,
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    #[cfg(feature = "trace")]
+    let view_span = info_span!("Application", "VIEW").entered();
+
+    debug.view_started();
+    let mut view = application.view(id);
+    view.as_widget_mut().diff(&mut core::widget::Tree::empty());
+
+    #[cfg(feature = "trace")]
+    let _ = view_span.exit();
+    debug.view_finished();
+
+    #[cfg(feature = "trace")]
+    let layout_span = info_span!("Application", "LAYOUT").entered();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+
+    #[cfg(feature = "trace")]
+    let _ = layout_span.exit();
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Builds the [`UserInterface`] of every window, reusing the provided caches.
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+    window_manager: &WindowManager<A, C>,
+    mut caches: HashMap<window::Id, user_interface::Cache>,
+) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    window_manager
+        .iter()
+        .map(|(id, window)| {
+            let cache = caches.remove(&id).unwrap_or_default();
+
+            (
+                id,
+                build_user_interface(
+                    application,
+                    cache,
+                    renderer,
+                    window.state.logical_size(),
+                    debug,
+                    id,
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Updates a multi-window [`Application`] by feeding it the provided messages,
+/// spawning any resulting [`Command`], and tracking its [`Subscription`].
+fn update<A, C, E>(
+    application: &mut A,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEventWrapper<A::Message>>,
+        UserEventWrapper<A::Message>,
+    >,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    window_manager: &mut WindowManager<A, C>,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    E: Executor,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    for message in messages.drain(..) {
+        #[cfg(feature = "trace")]
+        let update_span = info_span!("Application", "UPDATE").entered();
+
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+
+        #[cfg(feature = "trace")]
+        let _ = update_span.exit();
+        debug.update_finished();
+
+        run_command(
+            application,
+            compositor,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            should_exit,
+            proxy,
+            debug,
+            window_manager,
+            caches,
+            control_sender,
+        );
+    }
+
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEventWrapper::Message)
+            .into_recipes(),
+    );
+}
+
+/// Runs the actions of a [`Command`], routing window actions to the window
+/// with the matching [`window::Id`].
+fn run_command<A, C, E>(
+    application: &A,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEventWrapper<A::Message>>,
+        UserEventWrapper<A::Message>,
+    >,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
+    debug: &mut Debug,
+    window_manager: &mut WindowManager<A, C>,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    E: Executor,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use crate::futures::futures::FutureExt;
+    use crate::runtime::command;
+    use crate::runtime::system;
+    use crate::runtime::window;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(Box::pin(future.map(UserEventWrapper::Message)));
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(message))
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Window(id, window::Action::Spawn(settings)) => {
+                control_sender
+                    .start_send(Control::CreateWindow {
+                        id,
+                        settings: settings.into(),
+                        title: application.title(id),
+                    })
+                    .expect("Send request to event loop");
+            }
+            command::Action::Window(id, window::Action::Close) => {
+                let _ = caches.remove(&id);
+
+                if window_manager.remove(id).is_some() {
+                    runtime.broadcast(
+                        core::Event::Window(id, core::window::Event::Closed),
+                        core::event::Status::Ignored,
+                    );
+                }
+
+                if window_manager.is_empty() {
+                    *should_exit = true;
+                }
+            }
+            command::Action::Window(id, action) => {
+                let Some(window) = window_manager.get_mut(id) else {
+                    log::warn!(
+                        "Ignoring {action:?}, since window {id:?} does not \
+                        exist"
+                    );
+
+                    continue;
+                };
+
+                match action {
+                    // Handled above, as they do not need an open window
+                    window::Action::Spawn(_) | window::Action::Close => {}
+                    window::Action::Drag => {
+                        let _res = window.raw.drag_window();
+                    }
+                    window::Action::Resize { width, height } => {
+                        window.raw.set_inner_size(winit::dpi::LogicalSize {
+                            width,
+                            height,
+                        });
+                    }
+                    window::Action::Maximize(maximized) => {
+                        window.raw.set_maximized(maximized);
+                    }
+                    window::Action::Minimize(minimized) => {
+                        window.raw.set_minimized(minimized);
+                    }
+                    window::Action::Move { x, y } => {
+                        window.raw.set_outer_position(
+                            winit::dpi::LogicalPosition { x, y },
+                        );
+                    }
+                    window::Action::ChangeMode(mode) => {
+                        window.raw.set_visible(conversion::visible(mode));
+                        window.raw.set_fullscreen(conversion::fullscreen(
+                            window.raw.current_monitor(),
+                            mode,
+                        ));
+                    }
+                    window::Action::ChangeIcon(icon) => {
+                        window.raw.set_window_icon(conversion::icon(icon))
+                    }
+                    window::Action::FetchMode(tag) => {
+                        let mode = if window.raw.is_visible().unwrap_or(true) {
+                            conversion::mode(window.raw.fullscreen())
+                        } else {
+                            core::window::Mode::Hidden
+                        };
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(mode)))
+                            .expect("Send message to event loop");
+                    }
+                    window::Action::ToggleMaximize => {
+                        window.raw.set_maximized(!window.raw.is_maximized())
+                    }
+                    window::Action::ToggleDecorations => {
+                        window.raw.set_decorations(!window.raw.is_decorated());
+                    }
+                    window::Action::RequestUserAttention(user_attention) => {
+                        window.raw.request_user_attention(
+                            user_attention.map(conversion::user_attention),
+                        );
+                    }
+                    window::Action::GainFocus => {
+                        window.raw.focus_window();
+                    }
+                    window::Action::ChangeLevel(_level) => {
+                        // TODO: Window levels are not supported by our `winit`
+                        // fork yet, just like in `application`
+                    }
+                    window::Action::FetchId(tag) => {
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(window
+                                .raw
+                                .id()
+                                .into())))
+                            .expect("Send message to event loop");
+                    }
+                    window::Action::Screenshot(tag) => {
+                        // The renderer is shared by all windows, so it may
+                        // hold the primitives of another one
+                        let mut user_interface = build_user_interface(
+                            application,
+                            caches.remove(&id).unwrap_or_default(),
+                            renderer,
+                            window.state.logical_size(),
+                            debug,
+                            id,
+                        );
+
+                        let _ = user_interface.draw(
+                            renderer,
+                            window.state.theme(),
+                            &renderer::Style {
+                                text_color: window.state.text_color(),
+                            },
+                            window.state.cursor(),
+                        );
+
+                        let _ = caches.insert(id, user_interface.into_cache());
+
+                        let bytes = compositor.screenshot(
+                            renderer,
+                            &mut window.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        );
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                window::Screenshot::new(
+                                    bytes,
+                                    window.state.physical_size(),
+                                ),
+                            )))
+                            .expect("Send message to event loop");
+                    }
+                }
+            }
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = compositor.fetch_information();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(UserEventWrapper::Message(message))
+                                .expect("Send message to event loop")
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation =
+                    Some(Box::new(OperationWrapper::Message(action)));
+
+                let mut user_interfaces = build_user_interfaces(
+                    application,
+                    renderer,
+                    debug,
+                    window_manager,
+                    std::mem::take(caches),
+                );
+
+                while let Some(mut operation) = current_operation.take() {
+                    for user_interface in user_interfaces.values_mut() {
+                        user_interface.operate(renderer, operation.as_mut());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(
+                            operation::OperationOutputWrapper::Message(message),
+                        ) => {
+                            proxy
+                                .send_event(UserEventWrapper::Message(message))
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Some(
+                            operation::OperationOutputWrapper::Id(_),
+                        ) => {}
+                        operation::Outcome::Chain(next) => {
+                            current_operation =
+                                Some(Box::new(OperationWrapper::Wrapper(next)));
+                        }
+                    }
+                }
+
+                *caches = user_interfaces
+                    .drain()
+                    .map(|(id, user_interface)| {
+                        (id, user_interface.into_cache())
+                    })
+                    .collect();
+            }
+            command::Action::LoadFont { bytes, tagger } => {
+                use crate::core::text::Renderer;
+
+                // TODO: Error handling (?)
+                renderer.load_font(bytes);
+
+                proxy
+                    .send_event(UserEventWrapper::Message(tagger(Ok(()))))
+                    .expect("Send message to event loop");
+            }
+            command::Action::PlatformSpecific(_) => {
+                log::warn!("Platform specific actions are not supported");
+            }
+        }
+    }
+}
+
+/// Sends the accessibility tree of the [`UserInterface`] of a window to its
+/// adapter.
+#[cfg(feature = "a11y")]
+fn update_a11y_tree<A, C>(
+    user_interface: &mut UserInterface<'_, A::Message, A::Renderer>,
+    renderer: &A::Renderer,
+    window: &window_manager::Window<A, C>,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use iced_accessibility::accesskit::{
+        NodeBuilder, NodeId, Role, Tree, TreeUpdate,
+    };
+    use iced_accessibility::{A11yId, A11yNode, A11yTree};
+
+    let child_tree = user_interface.a11y_nodes(window.state.cursor());
+    let mut root = NodeBuilder::new(Role::Window);
+    root.set_name(window.state.title());
+
+    let window_tree = A11yTree::node_with_child_tree(
+        A11yNode::new(root, window.a11y_id),
+        child_tree,
+    );
+
+    let mut current_operation = Some(Box::new(OperationWrapper::Id(Box::new(
+        operation::focusable::find_focused(),
+    ))));
+    let mut focus = None;
+
+    while let Some(mut operation) = current_operation.take() {
+        user_interface.operate(renderer, operation.as_mut());
+
+        match operation.finish() {
+            operation::Outcome::None => {}
+            operation::Outcome::Some(
+                operation::OperationOutputWrapper::Message(_),
+            ) => {}
+            operation::Outcome::Some(
+                operation::OperationOutputWrapper::Id(id),
+            ) => {
+                focus = Some(A11yId::from(id));
+            }
+            operation::Outcome::Chain(next) => {
+                current_operation =
+                    Some(Box::new(OperationWrapper::Wrapper(next)));
+            }
+        }
+    }
+
+    let focus = focus
+        .filter(|id| window_tree.contains(id))
+        .map(|id| id.into());
+
+    window.adapter.update(TreeUpdate {
+        nodes: window_tree.into(),
+        tree: Some(Tree::new(NodeId(window.a11y_id))),
+        focus,
+    });
+}
@@ -0,0 +1,176 @@
+use crate::application::UserEventWrapper;
+use crate::core::{keyboard, mouse, window};
+use crate::graphics::Compositor;
+use crate::multi_window::{Application, State};
+use crate::style::application::StyleSheet;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// The windows of a multi-window [`Application`], indexed by their
+/// [`window::Id`].
+pub struct WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::core::Renderer>::Theme: StyleSheet,
+{
+    aliases: HashMap<winit::window::WindowId, window::Id>,
+    entries: BTreeMap<window::Id, Window<A, C>>,
+}
+
+impl<A, C> WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::core::Renderer>::Theme: StyleSheet,
+{
+    /// Starts managing the given `winit` window with the provided
+    /// [`window::Id`], creating its [`State`] and surface.
+    pub fn insert(
+        &mut self,
+        id: window::Id,
+        window: winit::window::Window,
+        application: &A,
+        compositor: &mut C,
+        _proxy: &winit::event_loop::EventLoopProxy<
+            UserEventWrapper<A::Message>,
+        >,
+    ) -> &mut Window<A, C> {
+        let state = State::new(application, id, &window);
+        let physical_size = state.physical_size();
+        let surface = compositor.create_surface(
+            &window,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        #[cfg(feature = "a11y")]
+        let (a11y_id, adapter) = {
+            use iced_accessibility::accesskit::{
+                NodeBuilder, NodeClassSet, NodeId, Role, Tree, TreeUpdate,
+            };
+            use iced_accessibility::accesskit_winit::Adapter;
+
+            let node_id = crate::core::id::window_node_id();
+            let title = state.title().to_string();
+            let proxy = _proxy.clone();
+
+            (
+                node_id,
+                Adapter::new(
+                    &window,
+                    move || {
+                        let _ = proxy.send_event(UserEventWrapper::A11yEnabled);
+                        let mut node = NodeBuilder::new(Role::Window);
+                        node.set_name(title.clone());
+                        let node = node.build(&mut NodeClassSet::lock_global());
+                        TreeUpdate {
+                            nodes: vec![(NodeId(node_id), node)],
+                            tree: Some(Tree::new(NodeId(node_id))),
+                            focus: None,
+                        }
+                    },
+                    _proxy.clone(),
+                ),
+            )
+        };
+
+        let _ = self.aliases.insert(window.id(), id);
+        let _ = self.entries.insert(
+            id,
+            Window {
+                surface,
+                raw: window,
+                viewport_version: state.viewport_version(),
+                state,
+                mouse_interaction: mouse::Interaction::default(),
+                input_method: keyboard::InputMethod::Disabled,
+                #[cfg(feature = "a11y")]
+                a11y_id,
+                #[cfg(feature = "a11y")]
+                adapter,
+            },
+        );
+
+        self.entries
+            .get_mut(&id)
+            .expect("Get window that was just inserted")
+    }
+
+    /// Returns true if there are no windows left.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over all the windows and their identifiers.
+    pub fn iter(&self) -> impl Iterator<Item = (window::Id, &Window<A, C>)> {
+        self.entries.iter().map(|(id, window)| (*id, window))
+    }
+
+    /// Returns a mutable iterator over all the windows and their identifiers.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (window::Id, &mut Window<A, C>)> {
+        self.entries.iter_mut().map(|(id, window)| (*id, window))
+    }
+
+    /// Returns the window with the given [`window::Id`], if it exists.
+    pub fn get_mut(&mut self, id: window::Id) -> Option<&mut Window<A, C>> {
+        self.entries.get_mut(&id)
+    }
+
+    /// Returns the window with the given `winit` identifier and its
+    /// [`window::Id`], if it exists.
+    pub fn get_mut_alias(
+        &mut self,
+        id: winit::window::WindowId,
+    ) -> Option<(window::Id, &mut Window<A, C>)> {
+        let id = *self.aliases.get(&id)?;
+
+        self.entries.get_mut(&id).map(|window| (id, window))
+    }
+
+    /// Stops managing the window with the given [`window::Id`], returning it.
+    ///
+    /// The window is closed once the returned value is dropped.
+    pub fn remove(&mut self, id: window::Id) -> Option<Window<A, C>> {
+        let window = self.entries.remove(&id)?;
+        let _ = self.aliases.remove(&window.raw.id());
+
+        Some(window)
+    }
+}
+
+impl<A, C> Default for WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::core::Renderer>::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self {
+            aliases: HashMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+/// A window of a multi-window [`Application`].
+pub struct Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::core::Renderer>::Theme: StyleSheet,
+{
+    // The surface must be dropped before the window it presents to
+    pub surface: C::Surface,
+    pub raw: winit::window::Window,
+    pub state: State<A>,
+    pub viewport_version: usize,
+    pub mouse_interaction: mouse::Interaction,
+    pub input_method: keyboard::InputMethod,
+    #[cfg(feature = "a11y")]
+    pub a11y_id: std::num::NonZeroU128,
+    #[cfg(feature = "a11y")]
+    pub adapter: iced_accessibility::accesskit_winit::Adapter,
+}
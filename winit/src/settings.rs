@@ -22,7 +22,7 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
-use crate::core::window::{Icon, Level};
+use crate::core::window::{self, Icon, Level};
 use crate::Position;
 
 use winit::monitor::MonitorHandle;
@@ -213,3 +213,21 @@ impl Default for Window {
         }
     }
 }
+
+impl From<window::Settings> for Window {
+    fn from(settings: window::Settings) -> Self {
+        Self {
+            size: settings.size,
+            position: settings.position,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            level: settings.level,
+            icon: settings.icon,
+            platform_specific: Default::default(),
+        }
+    }
+}